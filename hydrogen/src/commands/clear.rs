@@ -0,0 +1,61 @@
+//! '/clear' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{CommandInteraction, Context, CreateCommand};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, serenity_command_description, serenity_command_name, t_vars},
+    utils,
+};
+
+/// Executes the `/clear` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.clear_queue(guild_id).await {
+                Ok(removed) => t_vars(&interaction.locale, "clear.done", [removed]),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot clear the queue");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/clear` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("clear");
+
+    command = serenity_command_name("clear.name", command);
+    command = serenity_command_description("clear.description", command);
+
+    command
+        .description("Remove all the songs from the queue, except the song playing now.")
+        .dm_permission(false)
+}
@@ -0,0 +1,167 @@
+//! '/debug' command registration and execution.
+
+use std::num::NonZeroU64;
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption, GuildId,
+    Permissions, UserId,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t, t_all, t_plural,
+        t_vars,
+    },
+};
+
+/// Executes the `/debug` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    if !is_owner(context, interaction.user.id).await {
+        return Cow::borrowed(t(&interaction.locale, "debug.not_owner"));
+    }
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    // Pausing or resuming all the players isn't related to a single player, so the other options are ignored.
+    if let Some(action) = interaction
+        .data
+        .options
+        .iter()
+        .find(|v| v.name == "players")
+        .and_then(|v| v.value.as_str())
+    {
+        let (result, key) = if action == "pause" {
+            (manager.pause_all().await, "debug.paused_all")
+        } else {
+            (manager.resume_all().await, "debug.resumed_all")
+        };
+
+        return match result {
+            Ok(count) => t_plural(&interaction.locale, key, count as u64, [count]),
+            Err(e) => {
+                event!(Level::ERROR, error = ?e, "cannot set the pause state of all the players");
+                e.localize(&interaction.locale)
+            }
+        };
+    }
+
+    let guild_id = match interaction
+        .data
+        .options
+        .iter()
+        .find(|v| v.name == "guild")
+        .and_then(|v| v.value.as_str())
+    {
+        Some(guild_id) => match guild_id.trim().parse::<NonZeroU64>() {
+            Ok(v) => GuildId::from(v),
+            Err(_) => return Cow::borrowed(t(&interaction.locale, "debug.invalid_guild_id")),
+        },
+        None => match interaction.guild_id {
+            Some(v) => v,
+            None => return err(&interaction.locale, "error.not_in_guild"),
+        },
+    };
+
+    let refresh = interaction
+        .data
+        .options
+        .iter()
+        .find(|v| v.name == "refresh")
+        .and_then(|v| v.value.as_bool())
+        .unwrap_or(false);
+
+    if refresh {
+        if let Err(e) = manager.refresh_from_node(guild_id).await {
+            event!(Level::ERROR, error = ?e, "cannot refresh the player from the node");
+            return e.localize(&interaction.locale);
+        }
+    }
+
+    let Some(dump) = manager.debug_dump(guild_id).await else {
+        return err(&interaction.locale, "error.player_not_exists");
+    };
+
+    match serde_json::to_string_pretty(&dump) {
+        Ok(dump) => t_vars(&interaction.locale, "debug.result", [dump]),
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, "cannot serialize the player dump");
+            err(&interaction.locale, "error.unknown")
+        }
+    }
+}
+
+/// Check if the user is one of the owners of the application.
+async fn is_owner(context: &Context, user_id: UserId) -> bool {
+    match context.http.get_current_application_info().await {
+        Ok(info) => {
+            info.owner.is_some_and(|o| o.id == user_id)
+                || info
+                    .team
+                    .is_some_and(|t| t.members.iter().any(|m| m.user.id == user_id))
+        }
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, "cannot get the application info");
+            false
+        }
+    }
+}
+
+/// Creates the `/debug` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("debug");
+
+    command = serenity_command_name("debug.name", command);
+    command = serenity_command_description("debug.description", command);
+
+    command
+        .description("Dump the internal state of a player, only available for the bot owners.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::String,
+                "guild",
+                "The ID of the server, the current one is used if not provided.",
+            )
+            .required(false);
+
+            option = serenity_command_option_name("debug.guild_name", option);
+            option = serenity_command_option_description("debug.guild_description", option);
+
+            option
+        })
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "refresh",
+                "Update the player with the state from Lavalink before showing it.",
+            )
+            .required(false);
+
+            option = serenity_command_option_name("debug.refresh_name", option);
+            option = serenity_command_option_description("debug.refresh_description", option);
+
+            option
+        })
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::String,
+                "players",
+                "Pause or resume all the players instead of dumping one, ignoring the other options.",
+            )
+            .required(false)
+            .add_string_choice_localized("Pause", "pause", t_all("debug.players_pause"))
+            .add_string_choice_localized("Resume", "resume", t_all("debug.players_resume"));
+
+            option = serenity_command_option_name("debug.players_name", option);
+            option = serenity_command_option_description("debug.players_description", option);
+
+            option
+        })
+        .default_member_permissions(Permissions::ADMINISTRATOR)
+}
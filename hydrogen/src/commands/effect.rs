@@ -0,0 +1,117 @@
+//! '/effect' command registration and execution.
+
+use beef::lean::Cow;
+use hydrolink::{Filters, Rotation, Timescale};
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t, t_all,
+    },
+    utils,
+};
+
+/// Executes the `/effect` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(filters) = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_str())
+        .and_then(preset_filters)
+    else {
+        event!(Level::WARN, "no valid preset provided");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.set_filters(guild_id, filters).await {
+                Ok(()) => Cow::borrowed(t(&interaction.locale, "effect.applied")),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot set the filters");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Get the filters for a preset.
+fn preset_filters(preset: &str) -> Option<Filters> {
+    Some(match preset {
+        "bassboost" => Filters::bass_boost(0.2),
+        "nightcore" => Filters {
+            timescale: Some(Timescale::new(1.2, 1.2, 1.0)),
+            ..Default::default()
+        },
+        "vaporwave" => Filters {
+            timescale: Some(Timescale::new(0.8, 0.8, 1.0)),
+            ..Default::default()
+        },
+        "8d" => Filters {
+            rotation: Some(Rotation::new(0.2)),
+            ..Default::default()
+        },
+        "off" => Filters::default(),
+        _ => return None,
+    })
+}
+
+/// Creates the `/effect` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("effect");
+
+    command = serenity_command_name("effect.name", command);
+    command = serenity_command_description("effect.description", command);
+
+    command
+        .description("Apply an effect to the player.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::String,
+                "preset",
+                "The effect to apply.",
+            )
+            .required(true)
+            .add_string_choice_localized("Bass Boost", "bassboost", t_all("effect.bassboost"))
+            .add_string_choice_localized("Nightcore", "nightcore", t_all("effect.nightcore"))
+            .add_string_choice_localized("Vaporwave", "vaporwave", t_all("effect.vaporwave"))
+            .add_string_choice_localized("8D", "8d", t_all("effect.8d"))
+            .add_string_choice_localized("Off", "off", t_all("effect.off"));
+
+            option = serenity_command_option_name("effect.preset_name", option);
+            option = serenity_command_option_description("effect.preset_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
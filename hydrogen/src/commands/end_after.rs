@@ -0,0 +1,90 @@
+//! '/endafter' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t,
+    },
+    utils,
+};
+
+/// Executes the `/endafter` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(enabled) = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_bool())
+    else {
+        event!(Level::WARN, "enabled option is missing");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.set_stop_after_current(guild_id, enabled) {
+                Ok(()) if enabled => Cow::borrowed(t(&interaction.locale, "endafter.enabled")),
+                Ok(()) => Cow::borrowed(t(&interaction.locale, "endafter.disabled")),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot set the stop after the current song");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/endafter` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("endafter");
+
+    command = serenity_command_name("endafter.name", command);
+    command = serenity_command_description("endafter.description", command);
+
+    command
+        .description("Choose if the player stops when the current song ends.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "enabled",
+                "If the player stops when the current song ends.",
+            )
+            .required(true);
+
+            option = serenity_command_option_name("endafter.enabled_name", option);
+            option = serenity_command_option_description("endafter.enabled_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
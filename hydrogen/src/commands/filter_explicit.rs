@@ -0,0 +1,62 @@
+//! '/filterexplicit' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{CommandInteraction, Context, CreateCommand};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, serenity_command_description, serenity_command_name, t},
+    utils,
+};
+
+/// Executes the `/filterexplicit` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.toggle_block_explicit(guild_id) {
+                Ok(true) => Cow::borrowed(t(&interaction.locale, "filterexplicit.enabled")),
+                Ok(false) => Cow::borrowed(t(&interaction.locale, "filterexplicit.disabled")),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot toggle the explicit content filter");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/filterexplicit` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("filterexplicit");
+
+    command = serenity_command_name("filterexplicit.name", command);
+    command = serenity_command_description("filterexplicit.description", command);
+
+    command
+        .description("Choose if songs with explicit content can be added to the queue.")
+        .dm_permission(false)
+}
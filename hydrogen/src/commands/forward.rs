@@ -0,0 +1,56 @@
+//! '/forward' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+
+use crate::{
+    i18n::{
+        serenity_command_description, serenity_command_name, serenity_command_option_description,
+        serenity_command_option_name,
+    },
+    shared::{self, SharedInteraction},
+};
+
+/// Executes the `/forward` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let time = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_str());
+
+    shared::seek::execute(
+        context,
+        &SharedInteraction::Command(interaction),
+        time,
+        true,
+    )
+    .await
+}
+
+/// Creates the `/forward` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("forward");
+
+    command = serenity_command_name("forward.name", command);
+    command = serenity_command_description("forward.description", command);
+
+    command
+        .description("Seek the current song forward.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::String,
+                "time",
+                "How much to seek forward, in seconds or a supported syntax.",
+            )
+            .required(true);
+
+            option = serenity_command_option_name("forward.time_name", option);
+            option = serenity_command_option_description("forward.time_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
@@ -5,7 +5,9 @@ use serenity::all::{CommandOptionType, CreateCommandOption};
 use serenity::{all::CommandInteraction, builder::CreateCommand, client::Context};
 use tracing::{Level, event};
 
-use crate::i18n::{serenity_command_option_description, serenity_command_option_name, t, t_all};
+use crate::i18n::{
+    LocalizeError, err, serenity_command_option_description, serenity_command_option_name, t, t_all,
+};
 use crate::music::PlayerTemplate;
 use crate::{
     LOADED_COMMANDS, PLAYER_MANAGER,
@@ -17,17 +19,17 @@ use crate::{
 pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(&interaction.locale, "error.not_in_guild"));
+        return err(&interaction.locale, "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+        return err(&interaction.locale, "error.unknown");
     };
 
     if manager.contains_player(guild_id) {
         event!(Level::INFO, "player already exists");
-        return Cow::borrowed(t(&interaction.locale, "error.player_exists"));
+        return err(&interaction.locale, "error.player_exists");
     }
 
     let template_option = interaction
@@ -59,7 +61,7 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
 
     if let Err(e) = voice_manager.join_gateway(guild_id, voice_channel_id).await {
         event!(Level::INFO, voice_channel_id = %voice_channel_id, error = %e, "cannot join the voice channel");
-        return Cow::borrowed(t(&interaction.locale, "error.cant_connect"));
+        return err(&interaction.locale, "error.cant_connect");
     }
 
     // Initialize the player.
@@ -76,7 +78,7 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
         .await
     {
         event!(Level::ERROR, error = %e, "cannot initialize the player");
-        return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+        return e.localize(&interaction.locale);
     }
 
     let template_name = match template {
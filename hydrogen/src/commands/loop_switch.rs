@@ -7,7 +7,7 @@ use serenity::all::{
 use tracing::{Level, event};
 
 use crate::i18n::{
-    serenity_command_description, serenity_command_name, serenity_command_option_description,
+    err, serenity_command_description, serenity_command_name, serenity_command_option_description,
     serenity_command_option_name, t_all,
 };
 use crate::shared::SharedInteraction;
@@ -18,12 +18,12 @@ use crate::{PLAYER_MANAGER, i18n::t, music::LoopMode, utils};
 pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(&interaction.locale, "error.not_in_guild"));
+        return err(&interaction.locale, "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+        return err(&interaction.locale, "error.unknown");
     };
 
     let loop_option = interaction
@@ -63,12 +63,12 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
 
             Cow::borrowed(t(&interaction.locale, loop_type_translation_key))
         } else {
-            Cow::borrowed(t(&interaction.locale, "error.not_in_voice_channel"))
+            err(&interaction.locale, "error.not_in_voice_chat")
         }
     } else {
         delete_player_message(context, &SharedInteraction::from(interaction)).await;
 
-        Cow::borrowed(t(&interaction.locale, "error.player_not_exists"))
+        err(&interaction.locale, "error.player_not_exists")
     }
 }
 
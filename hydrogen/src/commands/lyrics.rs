@@ -0,0 +1,122 @@
+//! '/lyrics' command registration and execution.
+
+use beef::lean::Cow;
+use hydrolink::Lyrics;
+use serenity::all::{
+    CommandInteraction, Context, CreateCommand, CreateEmbed, CreateEmbedFooter,
+    EditInteractionResponse,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, serenity_command_description, serenity_command_name, t, t_vars},
+    utils::{
+        self,
+        constants::{DISCORD_EMBED_DESCRIPTION_LIMIT, HYDROGEN_PRIMARY_COLOR},
+    },
+};
+
+/// Executes the `/lyrics` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let Some(my_channel_id) = manager.get_voice_channel_id(guild_id).await else {
+        return err(&interaction.locale, "error.player_not_exists");
+    };
+
+    if my_channel_id != voice_channel_id {
+        return err(&interaction.locale, "error.not_in_voice_chat");
+    }
+
+    let Some(track) = manager.get_current_track(guild_id) else {
+        return err(&interaction.locale, "error.empty_queue");
+    };
+
+    let lyrics = match manager.lyrics(guild_id).await {
+        Ok(Some(v)) => v,
+        Ok(None) => return Cow::borrowed(t(&interaction.locale, "lyrics.not_found")),
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, "cannot get the lyrics");
+            return e.localize(&interaction.locale);
+        }
+    };
+
+    let Some(description) = generate_description(&lyrics) else {
+        return Cow::borrowed(t(&interaction.locale, "lyrics.not_found"));
+    };
+
+    let embed = CreateEmbed::new()
+        .title(track.title)
+        .description(description)
+        .color(HYDROGEN_PRIMARY_COLOR)
+        .footer(CreateEmbedFooter::new(
+            t_vars(&interaction.locale, "lyrics.source", [lyrics.provider]).into_owned(),
+        ));
+
+    if let Err(e) = interaction
+        .edit_response(&context.http, EditInteractionResponse::new().embed(embed))
+        .await
+    {
+        event!(Level::ERROR, error = %e, "cannot send the lyrics message");
+        return err(&interaction.locale, "error.discord");
+    }
+
+    Cow::borrowed("")
+}
+
+/// Generates the lyrics text, preferring the plain lyrics and truncating it to fit in the embed.
+fn generate_description(lyrics: &Lyrics) -> Option<String> {
+    let text = match &lyrics.text {
+        Some(text) if !text.trim().is_empty() => text.clone(),
+        _ => lyrics
+            .lines
+            .iter()
+            .map(|line| line.line.as_str())
+            .collect::<Vec<_>>()
+            .join("\n"),
+    };
+
+    if text.trim().is_empty() {
+        return None;
+    }
+
+    if text.chars().count() <= DISCORD_EMBED_DESCRIPTION_LIMIT {
+        return Some(text);
+    }
+
+    let mut truncated = text
+        .chars()
+        .take(DISCORD_EMBED_DESCRIPTION_LIMIT - 1)
+        .collect::<String>();
+    truncated.push('…');
+
+    Some(truncated)
+}
+
+/// Creates the `/lyrics` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("lyrics");
+
+    command = serenity_command_name("lyrics.name", command);
+    command = serenity_command_description("lyrics.description", command);
+
+    command
+        .description("Shows the lyrics of the current song.")
+        .dm_permission(false)
+}
@@ -1,18 +1,42 @@
 //! Controls the command execution flow.
 
 use beef::lean::Cow;
-use serenity::all::{CommandInteraction, Context, CreateCommand};
+use serenity::all::{CommandInteraction, Context, CreateAutocompleteResponse, CreateCommand};
 use tracing::{Level, event};
 
+mod clear;
+mod debug;
+mod effect;
+mod end_after;
+mod filter_explicit;
+mod forward;
 mod join;
+mod jump;
 mod loop_switch;
+mod lyrics;
+mod move_track;
+mod now_playing;
 mod pause;
+mod ping;
 mod play;
+mod play_to;
+mod playlist;
 mod prev;
+mod queue;
+mod remove;
+mod remove_range;
+mod replay;
+mod rewind;
+mod search;
 mod shuffle;
 mod skip;
+mod split_view;
+mod stay;
+mod sticky_filters;
 mod stop;
+mod summon;
 mod time;
+mod volume;
 
 pub async fn execute<'a>(context: &Context, command: &CommandInteraction) -> Option<Cow<'a, str>> {
     Some(match command.data.name.as_str() {
@@ -25,6 +49,30 @@ pub async fn execute<'a>(context: &Context, command: &CommandInteraction) -> Opt
         "stop" => stop::execute(context, command).await,
         "join" => join::execute(context, command).await,
         "time" => time::execute(context, command).await,
+        "splitview" => split_view::execute(context, command).await,
+        "stickyfilters" => sticky_filters::execute(context, command).await,
+        "summon" => summon::execute(context, command).await,
+        "nowplaying" => now_playing::execute(context, command).await,
+        "debug" => debug::execute(context, command).await,
+        "filterexplicit" => filter_explicit::execute(context, command).await,
+        "volume" => volume::execute(context, command).await,
+        "effect" => effect::execute(context, command).await,
+        "queue" => queue::execute(context, command).await,
+        "remove" => remove::execute(context, command).await,
+        "move" => move_track::execute(context, command).await,
+        "jump" => jump::execute(context, command).await,
+        "clear" => clear::execute(context, command).await,
+        "forward" => forward::execute(context, command).await,
+        "rewind" => rewind::execute(context, command).await,
+        "247" => stay::execute(context, command).await,
+        "lyrics" => lyrics::execute(context, command).await,
+        "playlist" => playlist::execute(context, command).await,
+        "ping" => ping::execute(context, command).await,
+        "endafter" => end_after::execute(context, command).await,
+        "search" => search::execute(context, command).await,
+        "removerange" => remove_range::execute(context, command).await,
+        "playto" => play_to::execute(context, command).await,
+        "replay" => replay::execute(context, command).await,
         _ => {
             event!(Level::ERROR, "unknown command");
             return None;
@@ -32,7 +80,21 @@ pub async fn execute<'a>(context: &Context, command: &CommandInteraction) -> Opt
     })
 }
 
-pub fn all_create_commands() -> [CreateCommand; 9] {
+/// Gets the autocomplete choices for the focused option of a command.
+pub async fn autocomplete(
+    context: &Context,
+    command: &CommandInteraction,
+) -> Option<CreateAutocompleteResponse> {
+    match command.data.name.as_str() {
+        "play" => Some(play::autocomplete(context, command).await),
+        _ => {
+            event!(Level::ERROR, "unknown autocomplete command");
+            None
+        }
+    }
+}
+
+pub fn all_create_commands() -> [CreateCommand; 33] {
     [
         skip::create_command(),
         pause::create_command(),
@@ -43,5 +105,29 @@ pub fn all_create_commands() -> [CreateCommand; 9] {
         stop::create_command(),
         join::create_command(),
         time::create_command(),
+        split_view::create_command(),
+        sticky_filters::create_command(),
+        summon::create_command(),
+        now_playing::create_command(),
+        debug::create_command(),
+        filter_explicit::create_command(),
+        volume::create_command(),
+        effect::create_command(),
+        queue::create_command(),
+        remove::create_command(),
+        move_track::create_command(),
+        jump::create_command(),
+        clear::create_command(),
+        forward::create_command(),
+        rewind::create_command(),
+        stay::create_command(),
+        lyrics::create_command(),
+        playlist::create_command(),
+        ping::create_command(),
+        end_after::create_command(),
+        search::create_command(),
+        remove_range::create_command(),
+        play_to::create_command(),
+        replay::create_command(),
     ]
 }
@@ -0,0 +1,112 @@
+//! '/move' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t_vars,
+    },
+    utils,
+};
+
+/// Executes the `/move` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let position = |name: &str| {
+        interaction
+            .data
+            .options
+            .iter()
+            .find(|v| v.name == name)
+            .and_then(|v| v.value.as_i64())
+            .filter(|v| *v > 0)
+    };
+
+    let (Some(from), Some(to)) = (position("from"), position("to")) else {
+        event!(Level::WARN, "no valid positions provided");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager
+                .move_track(guild_id, from as usize - 1, to as usize - 1)
+                .await
+            {
+                Ok(()) => t_vars(&interaction.locale, "move.moved", [from, to]),
+                Err(e) => {
+                    event!(Level::WARN, error = ?e, "cannot move the track");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/move` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("move");
+
+    command = serenity_command_name("move.name", command);
+    command = serenity_command_description("move.description", command);
+
+    command
+        .description("Move a song to another position in the queue.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "from",
+                "The current position of the song in the queue.",
+            )
+            .min_int_value(1)
+            .required(true);
+
+            option = serenity_command_option_name("move.from_name", option);
+            option = serenity_command_option_description("move.from_description", option);
+
+            option
+        })
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "to",
+                "The new position of the song in the queue.",
+            )
+            .min_int_value(1)
+            .required(true);
+
+            option = serenity_command_option_name("move.to_name", option);
+            option = serenity_command_option_description("move.to_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
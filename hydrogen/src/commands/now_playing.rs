@@ -0,0 +1,351 @@
+//! '/nowplaying' command registration and execution.
+
+use std::{sync::Arc, time::Instant};
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+    CreateEmbed, EditInteractionResponse, GuildId, Http,
+};
+use tokio::time::sleep;
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t_vars,
+    },
+    music::{PlayerState, Track},
+    utils::{
+        self,
+        constants::{
+            HYDROGEN_NOWPLAYING_LIVE_INTERVAL, HYDROGEN_NOWPLAYING_LIVE_LIMIT,
+            HYDROGEN_PRIMARY_COLOR,
+        },
+        progress_bar, time_to_string,
+    },
+};
+
+/// Executes the `/nowplaying` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let live = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_bool())
+        .unwrap_or(false);
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let Some(my_channel_id) = manager.get_voice_channel_id(guild_id).await else {
+        return err(&interaction.locale, "error.player_not_exists");
+    };
+
+    if my_channel_id != voice_channel_id {
+        return err(&interaction.locale, "error.not_in_voice_chat");
+    }
+
+    let seek_result = match manager.time(guild_id).await {
+        Ok(Some(v)) => v,
+        Ok(None) => return err(&interaction.locale, "error.empty_queue"),
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, "cannot get the player time");
+            return e.localize(&interaction.locale);
+        }
+    };
+
+    let Some(track) = manager.get_current_track(guild_id) else {
+        return err(&interaction.locale, "error.empty_queue");
+    };
+
+    if live {
+        let handle = tokio::spawn(live_update(
+            context.http.clone(),
+            interaction.token.clone(),
+            interaction.locale.clone(),
+            guild_id,
+            track.track.clone(),
+        ));
+
+        manager.set_nowplaying_handle(guild_id, handle);
+    }
+
+    let description = generate_description(
+        &interaction.locale,
+        &track,
+        seek_result.position,
+        seek_result.total,
+    );
+
+    if let Err(e) = interaction
+        .edit_response(
+            &context.http,
+            EditInteractionResponse::new().embed(generate_embed(track, description)),
+        )
+        .await
+    {
+        event!(Level::ERROR, error = %e, "cannot send the now-playing message");
+        return err(&interaction.locale, "error.discord");
+    }
+
+    Cow::borrowed("")
+}
+
+/// Keeps editing the response with the current position until the track changes or the limit is reached.
+async fn live_update(
+    http: Arc<Http>,
+    token: String,
+    locale: String,
+    guild_id: GuildId,
+    encoded_track: String,
+) {
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        return;
+    };
+
+    let start_time = Instant::now();
+    let mut last_description = None;
+
+    while start_time.elapsed() < HYDROGEN_NOWPLAYING_LIVE_LIMIT {
+        sleep(HYDROGEN_NOWPLAYING_LIVE_INTERVAL).await;
+
+        let (track, description) = match next_live_update(
+            &locale,
+            manager.get_player_state(guild_id),
+            &encoded_track,
+            last_description.as_ref(),
+        ) {
+            LiveUpdate::Stop => break,
+            LiveUpdate::Skip => continue,
+            LiveUpdate::Edit(track, description) => (track, description),
+        };
+
+        if let Err(e) = http
+            .edit_original_interaction_response(
+                &token,
+                &EditInteractionResponse::new().embed(generate_embed(*track, description.clone())),
+                Vec::new(),
+            )
+            .await
+        {
+            event!(Level::INFO, error = %e, "cannot edit the live now-playing message");
+            break;
+        }
+
+        last_description = Some(description);
+    }
+}
+
+/// What the live now-playing task does after waiting for the next update.
+enum LiveUpdate {
+    /// The player was destroyed or the track has ended, so the task stops.
+    Stop,
+    /// The message doesn't need to be edited, like when the position isn't known or hasn't changed.
+    Skip,
+    /// The message is edited with the track and its description.
+    Edit(Box<Track>, String),
+}
+
+/// Decide what the live now-playing task does with the player state, stopping when the track isn't playing anymore.
+fn next_live_update(
+    locale: &str,
+    player_state: Option<PlayerState>,
+    encoded_track: &str,
+    last_description: Option<&String>,
+) -> LiveUpdate {
+    let Some(player_state) = player_state else {
+        return LiveUpdate::Stop;
+    };
+
+    let Some(track) = player_state.track.filter(|t| t.track == encoded_track) else {
+        return LiveUpdate::Stop;
+    };
+
+    let Some(position) = player_state.position else {
+        return LiveUpdate::Skip;
+    };
+
+    let total = track.duration;
+    let description = generate_description(locale, &track, position.min(total), total);
+
+    // Avoid wasting the rate limit when nothing has changed, like when the player is paused.
+    if last_description == Some(&description) {
+        return LiveUpdate::Skip;
+    }
+
+    LiveUpdate::Edit(Box::new(track), description)
+}
+
+/// Generates the description with the author and the progress of the track, or a live indicator for streams.
+fn generate_description(locale: &str, track: &Track, position: u64, total: u64) -> String {
+    if track.is_stream || total == 0 {
+        return t_vars(locale, "nowplaying.stream", [&track.author]).into_owned();
+    }
+
+    t_vars(
+        locale,
+        "nowplaying.progress",
+        [
+            track.author.clone(),
+            time_to_string(position / 1000),
+            time_to_string(total / 1000),
+            progress_bar(position / 1000, total / 1000),
+        ],
+    )
+    .into_owned()
+}
+
+/// Generates the embed with the track and its description.
+fn generate_embed(track: Track, description: String) -> CreateEmbed {
+    let mut embed = CreateEmbed::new()
+        .title(track.title)
+        .description(description)
+        .color(HYDROGEN_PRIMARY_COLOR);
+
+    if let Some(url) = track.url {
+        embed = embed.url(url);
+    }
+
+    if let Some(thumbnail) = track.thumbnail {
+        embed = embed.thumbnail(thumbnail);
+    }
+
+    embed
+}
+
+/// Creates the `/nowplaying` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("nowplaying");
+
+    command = serenity_command_name("nowplaying.name", command);
+    command = serenity_command_description("nowplaying.description", command);
+
+    command
+        .description("See the song that is playing now.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "live",
+                "Keep updating the progress of the song.",
+            )
+            .required(false);
+
+            option = serenity_command_option_name("nowplaying.live_name", option);
+            option = serenity_command_option_description("nowplaying.live_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use serenity::all::{ChannelId, UserId};
+
+    use super::*;
+    use crate::music::{LoopMode, Player};
+
+    /// Create a player state playing the track at the position, or without a track.
+    fn player_state(encoded_track: Option<&str>, position: Option<u64>) -> PlayerState {
+        let mut player = Player::new(0, "en-US", ChannelId::new(1), LoopMode::None, true, 100);
+
+        player
+            .queue
+            .extend(encoded_track.map(|encoded_track| Track {
+                track: encoded_track.to_owned(),
+                author: "author".to_owned(),
+                title: "title".to_owned(),
+                requester: UserId::new(1),
+                duration: 60_000,
+                is_stream: false,
+                url: None,
+                identifier: encoded_track.to_owned(),
+                source_name: None,
+                seekable: true,
+                explicit: false,
+                thumbnail: None,
+                youtube_id: None,
+                isrc: None,
+                added_at: Instant::now(),
+            }));
+
+        player.state = position.map(|position| hydrolink::PlayerState {
+            time: 0,
+            position,
+            connected: true,
+            ping: 0,
+        });
+
+        PlayerState::from(&player)
+    }
+
+    #[test]
+    fn live_update_stops_when_track_ends() {
+        assert!(matches!(
+            next_live_update("en-US", None, "a", None),
+            LiveUpdate::Stop
+        ));
+        assert!(matches!(
+            next_live_update("en-US", Some(player_state(None, Some(0))), "a", None),
+            LiveUpdate::Stop
+        ));
+        assert!(matches!(
+            next_live_update("en-US", Some(player_state(Some("b"), Some(0))), "a", None),
+            LiveUpdate::Stop
+        ));
+    }
+
+    #[test]
+    fn live_update_edits_when_position_changes() {
+        assert!(matches!(
+            next_live_update("en-US", Some(player_state(Some("a"), None)), "a", None),
+            LiveUpdate::Skip
+        ));
+
+        let LiveUpdate::Edit(track, description) = next_live_update(
+            "en-US",
+            Some(player_state(Some("a"), Some(5000))),
+            "a",
+            None,
+        ) else {
+            panic!("the live now-playing message wasn't edited");
+        };
+
+        assert_eq!(track.track, "a");
+
+        assert!(matches!(
+            next_live_update(
+                "en-US",
+                Some(player_state(Some("a"), Some(5000))),
+                "a",
+                Some(&description)
+            ),
+            LiveUpdate::Skip
+        ));
+        assert!(matches!(
+            next_live_update(
+                "en-US",
+                Some(player_state(Some("a"), Some(10000))),
+                "a",
+                Some(&description)
+            ),
+            LiveUpdate::Edit(..)
+        ));
+    }
+}
@@ -0,0 +1,44 @@
+//! '/ping' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{CommandInteraction, Context, CreateCommand};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{err, serenity_command_description, serenity_command_name, t, t_vars},
+};
+
+/// Executes the `/ping` command.
+pub async fn execute<'a>(_: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    if !manager.contains_player(guild_id) {
+        return err(&interaction.locale, "error.player_not_exists");
+    }
+
+    match manager.get_ping(guild_id) {
+        Some(ping) => t_vars(&interaction.locale, "ping.result", [ping]),
+        None => Cow::borrowed(t(&interaction.locale, "ping.not_connected")),
+    }
+}
+
+/// Creates the `/ping` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("ping");
+
+    command = serenity_command_name("ping.name", command);
+    command = serenity_command_description("ping.description", command);
+
+    command
+        .description("Shows the latency of the player to the voice chat.")
+        .dm_permission(false)
+}
@@ -2,36 +2,41 @@
 
 use beef::lean::Cow;
 use serenity::all::{
-    ChannelId, CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
-    GuildId,
+    CommandInteraction, CommandOptionType, Context, CreateAutocompleteResponse, CreateCommand,
+    CreateCommandOption,
 };
-use songbird::{Call, Songbird};
 use std::default::Default;
-use std::sync::Arc;
-use tokio::sync::Mutex;
-use tracing::{event, Level};
+use tracing::{Level, event};
 
 use crate::i18n::t_all;
 use crate::music::{PlayMode, PlayRequest};
 use crate::{
+    PLAYER_MANAGER,
     i18n::{
-        serenity_command_description, serenity_command_name, serenity_command_option_description,
-        serenity_command_option_name, t, t_vars,
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t, t_plural, t_vars,
     },
     music::PlayResult,
-    utils, PLAYER_MANAGER,
+    utils::{
+        self,
+        constants::{
+            DISCORD_AUTOCOMPLETE_CHOICE_LIMIT, DISCORD_AUTOCOMPLETE_CHOICES,
+            HYDROGEN_AUTOCOMPLETE_MIN_LENGTH,
+        },
+        truncate,
+    },
 };
 
 /// Executes the `/play` command.
 pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(&interaction.locale, "error.not_in_guild"));
+        return err(&interaction.locale, "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+        return err(&interaction.locale, "error.unknown");
     };
 
     let Some(query) = interaction
@@ -41,72 +46,34 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
         .and_then(|v| v.value.as_str())
     else {
         event!(Level::WARN, "no query provided");
-        return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+        return err(&interaction.locale, "error.unknown");
     };
 
     let mode_option = interaction
         .data
         .options
-        .get(1)
+        .iter()
+        .find(|v| v.name == "mode")
         .and_then(|v| v.value.as_str());
 
+    let dedup = interaction
+        .data
+        .options
+        .iter()
+        .find(|v| v.name == "dedup")
+        .and_then(|v| v.value.as_bool())
+        .unwrap_or(false);
+
     let mode = match mode_option {
         Some("add_next") => PlayMode::AddToNext,
         Some("play_now") => PlayMode::PlayNow,
         _ => PlayMode::AddToEnd,
     };
 
-    let (voice_manager, voice_channel_id) = match utils::get_voice_essentials(
-        context,
-        &interaction.locale,
-        guild_id,
-        interaction.user.id,
-    )
-    .await
+    if let Err(e) =
+        utils::join_voice_channel(context, &interaction.locale, guild_id, interaction.user.id).await
     {
-        Ok(v) => v,
-        Err(e) => return e,
-    };
-
-    let call = match voice_manager.get(guild_id) {
-        Some(v) => {
-            let has_connection = v.lock().await.current_connection().is_some();
-
-            if !has_connection {
-                match join_gateway(
-                    &voice_manager,
-                    guild_id,
-                    voice_channel_id,
-                    &interaction.locale,
-                )
-                .await
-                {
-                    Ok(v) => v,
-                    Err(e) => return e,
-                }
-            } else {
-                v
-            }
-        }
-        None => match join_gateway(
-            &voice_manager,
-            guild_id,
-            voice_channel_id,
-            &interaction.locale,
-        )
-        .await
-        {
-            Ok(v) => v,
-            Err(e) => return e,
-        },
-    };
-
-    if let Some(connection_info) = call.lock().await.current_connection() {
-        if let Some(channel_id) = connection_info.channel_id {
-            if channel_id != voice_channel_id.into() {
-                return Cow::borrowed(t(&interaction.locale, "error.not_in_voice_channel"));
-            }
-        }
+        return e;
     }
 
     let play_request = PlayRequest {
@@ -117,18 +84,40 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
         locale: &interaction.locale,
         player_template: Default::default(),
         play_mode: mode,
+        start_position: utils::time_parsers::youtube_timestamp(query),
+        dedup,
     };
 
     let result = match manager.play(play_request).await {
         Ok(e) => e,
         Err(e) => {
             event!(Level::ERROR, error = ?e, guild_id = %guild_id, "cannot play the track");
-            return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+            return e.localize(&interaction.locale);
         }
     };
 
     if result.count > 0 {
-        generate_message(result, interaction)
+        let skipped_duplicates = result.skipped_duplicates;
+        let message = generate_message(result, interaction);
+
+        if skipped_duplicates == 0 {
+            return message;
+        }
+
+        Cow::owned(format!(
+            "{}\n\n{}",
+            message,
+            t_plural(
+                &interaction.locale,
+                "play.skipped_duplicates",
+                skipped_duplicates as u64,
+                [skipped_duplicates]
+            )
+        ))
+    } else if result.rejected > 0 {
+        Cow::borrowed(t(&interaction.locale, "play.explicit"))
+    } else if result.skipped_duplicates > 0 {
+        Cow::borrowed(t(&interaction.locale, "play.duplicates"))
     } else if !result.truncated {
         Cow::borrowed(t(&interaction.locale, "play.not_found"))
     } else {
@@ -136,6 +125,53 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
     }
 }
 
+/// Gets the search results for the `query` option of the `/play` command, as `title — author` choices.
+pub async fn autocomplete(
+    _context: &Context,
+    interaction: &CommandInteraction,
+) -> CreateAutocompleteResponse {
+    let response = CreateAutocompleteResponse::new();
+
+    let Some(query) = interaction
+        .data
+        .autocomplete()
+        .filter(|v| v.name == "query")
+        .map(|v| v.value.trim())
+        .filter(|v| v.chars().count() >= HYDROGEN_AUTOCOMPLETE_MIN_LENGTH)
+    else {
+        return response;
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return response;
+    };
+
+    let tracks = match manager
+        .search_choices(query, DISCORD_AUTOCOMPLETE_CHOICES)
+        .await
+    {
+        Ok(v) => v,
+        Err(e) => {
+            event!(Level::WARN, error = ?e, "cannot search the autocomplete choices");
+            return response;
+        }
+    };
+
+    tracks.into_iter().fold(response, |response, track| {
+        let name = format!("{} — {}", track.info.title, track.info.author);
+
+        // Links longer than the limit can't be used as the value, so the song is searched again by its name.
+        let value = track
+            .info
+            .uri
+            .filter(|v| v.chars().count() <= DISCORD_AUTOCOMPLETE_CHOICE_LIMIT)
+            .unwrap_or_else(|| truncate(&name, DISCORD_AUTOCOMPLETE_CHOICE_LIMIT));
+
+        response.add_string_choice(truncate(&name, DISCORD_AUTOCOMPLETE_CHOICE_LIMIT), value)
+    })
+}
+
 /// Creates the `/join` [CreateCommand].
 pub fn create_command() -> CreateCommand {
     let mut command = CreateCommand::new("play");
@@ -153,7 +189,8 @@ pub fn create_command() -> CreateCommand {
                 "query",
                 "A music or playlist URL, or a search term.",
             )
-                .required(true);
+                .required(true)
+                .set_autocomplete(true);
 
             option =
                 serenity_command_option_name("play.query_name", option);
@@ -184,20 +221,20 @@ pub fn create_command() -> CreateCommand {
 
             option
         })
-        .dm_permission(false)
-}
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "dedup",
+                "Skip the songs that are already in the queue.",
+            )
+            .required(false);
 
-/// Joins the voice channel.
-async fn join_gateway<'a>(
-    voice_manager: &Arc<Songbird>,
-    guild_id: GuildId,
-    voice_channel_id: ChannelId,
-    locale: &str,
-) -> Result<Arc<Mutex<Call>>, Cow<'a, str>> {
-    voice_manager.join_gateway(guild_id, voice_channel_id).await.map(|e| e.1).map_err(|e| {
-        event!(Level::INFO, voice_channel_id = %voice_channel_id, error = ?e, "cannot join the voice channel");
-        Cow::borrowed(t(locale, "error.cant_connect"))
-    })
+            option = serenity_command_option_name("play.dedup_name", option);
+            option = serenity_command_option_description("play.dedup_description", option);
+
+            option
+        })
+        .dm_permission(false)
 }
 
 /// Generates the message from the result from the player.
@@ -207,9 +244,12 @@ fn generate_message<'a>(result: PlayResult, interaction: &CommandInteraction) ->
         result = ?result,
         "generating message from PlayResult"
     );
+    // The position shown to the user starts from 1.
+    let queue_position = (result.queue_position.unwrap_or_default() + 1).to_string();
+
     if let Some(track) = result.track {
         if result.playing && result.count == 1 {
-            return if let Some(url) = track.url {
+            return if let Some(url) = track.public_url() {
                 t_vars(
                     &interaction.locale,
                     "play.play_single_url",
@@ -223,22 +263,22 @@ fn generate_message<'a>(result: PlayResult, interaction: &CommandInteraction) ->
                 )
             };
         } else if result.count == 1 {
-            return if let Some(url) = track.url {
+            return if let Some(url) = track.public_url() {
                 t_vars(
                     &interaction.locale,
                     "play.enqueue_single_url",
-                    [track.title, track.author, url],
+                    [track.title, track.author, url, queue_position],
                 )
             } else {
                 t_vars(
                     &interaction.locale,
                     "play.enqueue_single",
-                    [track.title, track.author],
+                    [track.title, track.author, queue_position],
                 )
             };
         } else if result.playing {
             return if !result.truncated {
-                if let Some(url) = track.url {
+                if let Some(url) = track.public_url() {
                     t_vars(
                         &interaction.locale,
                         "play.play_multi_url",
@@ -251,7 +291,7 @@ fn generate_message<'a>(result: PlayResult, interaction: &CommandInteraction) ->
                         [track.title, track.author, result.count.to_string()],
                     )
                 }
-            } else if let Some(url) = track.url {
+            } else if let Some(url) = track.public_url() {
                 Cow::owned(format!(
                     "{}\n\n{}",
                     t(&interaction.locale, "play.truncated_warn"),
@@ -279,17 +319,19 @@ fn generate_message<'a>(result: PlayResult, interaction: &CommandInteraction) ->
         return Cow::owned(format!(
             "{}\n\n{}",
             t(&interaction.locale, "play.truncated_warn"),
-            t_vars(
+            t_plural(
                 &interaction.locale,
                 "play.enqueue_multi",
-                [result.count.to_string()]
+                result.count as u64,
+                [result.count.to_string(), queue_position]
             ),
         ));
     }
 
-    t_vars(
+    t_plural(
         &interaction.locale,
         "play.enqueue_multi",
-        [result.count.to_string()],
+        result.count as u64,
+        [result.count.to_string(), queue_position],
     )
 }
@@ -0,0 +1,106 @@
+//! '/playto' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t, t_vars,
+    },
+    utils,
+    utils::{time_parsers::parse_duration, time_to_string},
+};
+
+/// Executes the `/playto` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let end_time = match interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_str())
+    {
+        Some(time) => match parse_duration(time) {
+            Some(v) => Some(v),
+            None => {
+                event!(Level::INFO, syntax = time, "invalid syntax provided");
+                return Cow::borrowed(t(&interaction.locale, "time.invalid_syntax"));
+            }
+        },
+        None => None,
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            if manager.get_current_track(guild_id).is_none() {
+                return err(&interaction.locale, "error.empty_queue");
+            }
+
+            if let Err(e) = manager.set_end_time(guild_id, end_time).await {
+                event!(Level::ERROR, error = ?e, "cannot set the end time");
+                return e.localize(&interaction.locale);
+            }
+
+            match end_time.filter(|v| !v.is_zero()) {
+                Some(end_time) => t_vars(
+                    &interaction.locale,
+                    "playto.set",
+                    [time_to_string(end_time.as_secs())],
+                ),
+                None => Cow::borrowed(t(&interaction.locale, "playto.reset")),
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/playto` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("playto");
+
+    command = serenity_command_name("playto.name", command);
+    command = serenity_command_description("playto.description", command);
+
+    command
+        .description("Stop the current song at a time.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::String,
+                "time",
+                "The time to stop the song at, leave it empty to play until the end.",
+            )
+            .required(false);
+
+            option = serenity_command_option_name("playto.time_name", option);
+            option = serenity_command_option_description("playto.time_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
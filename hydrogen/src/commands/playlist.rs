@@ -0,0 +1,219 @@
+//! '/playlist' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandDataOptionValue, CommandInteraction, CommandOptionType, Context, CreateAttachment,
+    CreateCommand, CreateCommandOption, EditInteractionResponse, GuildId,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t, t_vars,
+    },
+    music::PlayerManager,
+    utils::{
+        self,
+        constants::{HYDROGEN_PLAYLIST_FILE_LIMIT, HYDROGEN_QUEUE_LIMIT},
+    },
+};
+
+/// Executes the `/playlist` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(subcommand) = interaction.data.options.first() else {
+        event!(Level::WARN, "subcommand is missing");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let Some(my_channel_id) = manager.get_voice_channel_id(guild_id).await else {
+        return err(&interaction.locale, "error.player_not_exists");
+    };
+
+    if my_channel_id != voice_channel_id {
+        return err(&interaction.locale, "error.not_in_voice_chat");
+    }
+
+    match subcommand.name.as_str() {
+        "export" => export(context, interaction, manager, guild_id).await,
+        "import" => import(interaction, manager, guild_id, &subcommand.value).await,
+        _ => {
+            event!(
+                Level::WARN,
+                subcommand = subcommand.name,
+                "unknown subcommand"
+            );
+            err(&interaction.locale, "error.unknown")
+        }
+    }
+}
+
+/// Sends the player's queue as a playlist file.
+async fn export<'a>(
+    context: &Context,
+    interaction: &CommandInteraction,
+    manager: &PlayerManager,
+    guild_id: GuildId,
+) -> Cow<'a, str> {
+    let Some(encoded) = manager.export_queue(guild_id) else {
+        return err(&interaction.locale, "error.player_not_exists");
+    };
+
+    if encoded.is_empty() {
+        return err(&interaction.locale, "error.empty_queue");
+    }
+
+    let content = t_vars(&interaction.locale, "playlist.exported", [encoded.len()]);
+
+    if let Err(e) = interaction
+        .edit_response(
+            &context.http,
+            EditInteractionResponse::new()
+                .content(content.as_ref())
+                .new_attachment(CreateAttachment::bytes(encoded.join("\n"), "playlist.txt")),
+        )
+        .await
+    {
+        event!(Level::ERROR, error = %e, "cannot send the playlist file");
+        return err(&interaction.locale, "error.discord");
+    }
+
+    Cow::borrowed("")
+}
+
+/// Adds the tracks from a playlist file to the player's queue.
+async fn import<'a>(
+    interaction: &CommandInteraction,
+    manager: &PlayerManager,
+    guild_id: GuildId,
+    value: &CommandDataOptionValue,
+) -> Cow<'a, str> {
+    let attachment = match value {
+        CommandDataOptionValue::SubCommand(options) => options
+            .first()
+            .and_then(|v| v.value.as_attachment_id())
+            .and_then(|id| interaction.data.resolved.attachments.get(&id)),
+        _ => None,
+    };
+
+    let Some(attachment) = attachment else {
+        event!(Level::WARN, "file option is missing");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    if attachment.size > HYDROGEN_PLAYLIST_FILE_LIMIT {
+        return Cow::borrowed(t(&interaction.locale, "playlist.invalid_file"));
+    }
+
+    let content = match attachment.download().await {
+        Ok(v) => v,
+        Err(e) => {
+            event!(Level::WARN, error = %e, "cannot download the playlist file");
+            return err(&interaction.locale, "error.discord");
+        }
+    };
+
+    let Ok(content) = String::from_utf8(content) else {
+        return Cow::borrowed(t(&interaction.locale, "playlist.invalid_file"));
+    };
+
+    let encoded = content
+        .lines()
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .take(HYDROGEN_QUEUE_LIMIT)
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+
+    if encoded.is_empty() {
+        return Cow::borrowed(t(&interaction.locale, "playlist.invalid_file"));
+    }
+
+    match manager
+        .import_queue(guild_id, encoded, interaction.user.id)
+        .await
+    {
+        Ok(result) if result.count == 0 && result.truncated => {
+            Cow::borrowed(t(&interaction.locale, "playlist.queue_full"))
+        }
+        Ok(result) if result.count == 0 => {
+            Cow::borrowed(t(&interaction.locale, "playlist.invalid_file"))
+        }
+        Ok(result) if result.failed > 0 => t_vars(
+            &interaction.locale,
+            "playlist.imported_partially",
+            [result.count, result.failed],
+        ),
+        Ok(result) => t_vars(&interaction.locale, "playlist.imported", [result.count]),
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, "cannot import the playlist");
+            e.localize(&interaction.locale)
+        }
+    }
+}
+
+/// Creates the `/playlist` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("playlist");
+
+    command = serenity_command_name("playlist.name", command);
+    command = serenity_command_description("playlist.description", command);
+
+    command
+        .description("Exports or imports the queue as a playlist file.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "export",
+                "Sends the queue as a playlist file.",
+            );
+
+            option = serenity_command_option_name("playlist.export_name", option);
+            option = serenity_command_option_description("playlist.export_description", option);
+
+            option
+        })
+        .add_option({
+            let mut file_option = CreateCommandOption::new(
+                CommandOptionType::Attachment,
+                "file",
+                "The playlist file exported before.",
+            )
+            .required(true);
+
+            file_option = serenity_command_option_name("playlist.file_name", file_option);
+            file_option =
+                serenity_command_option_description("playlist.file_description", file_option);
+
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::SubCommand,
+                "import",
+                "Adds the songs from a playlist file to the queue.",
+            )
+            .add_sub_option(file_option);
+
+            option = serenity_command_option_name("playlist.import_name", option);
+            option = serenity_command_option_description("playlist.import_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
@@ -0,0 +1,52 @@
+//! '/queue' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+
+use crate::{
+    i18n::{
+        serenity_command_description, serenity_command_name, serenity_command_option_description,
+        serenity_command_option_name,
+    },
+    shared::{self, SharedInteraction},
+};
+
+/// Executes the `/queue` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let page = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_i64())
+        .map(|v| v.saturating_sub(1).max(0) as usize);
+
+    shared::queue::execute(context, &SharedInteraction::Command(interaction), page).await
+}
+
+/// Creates the `/queue` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("queue");
+
+    command = serenity_command_name("queue.name", command);
+    command = serenity_command_description("queue.description", command);
+
+    command
+        .description("See the songs in the queue.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "page",
+                "The page to show, instead of the page with the song playing now.",
+            )
+            .min_int_value(1)
+            .required(false);
+
+            option = serenity_command_option_name("queue.page_name", option);
+            option = serenity_command_option_description("queue.page_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
@@ -0,0 +1,96 @@
+//! '/remove' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t_vars,
+    },
+    utils,
+};
+
+/// Executes the `/remove` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(position) = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_i64())
+        .filter(|v| *v > 0)
+    else {
+        event!(Level::WARN, "no valid position provided");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.remove_track(guild_id, position as usize - 1).await {
+                Ok(Some(track)) => t_vars(
+                    &interaction.locale,
+                    "remove.removed",
+                    [track.title, track.author],
+                ),
+                Ok(None) => t_vars(&interaction.locale, "remove.not_found", [position]),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot remove the track");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/remove` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("remove");
+
+    command = serenity_command_name("remove.name", command);
+    command = serenity_command_description("remove.description", command);
+
+    command
+        .description("Remove a song from the queue.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "position",
+                "The position of the song in the queue.",
+            )
+            .min_int_value(1)
+            .required(true);
+
+            option = serenity_command_option_name("remove.position_name", option);
+            option = serenity_command_option_description("remove.position_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
@@ -0,0 +1,118 @@
+//! '/removerange' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t_plural,
+    },
+    utils,
+};
+
+/// Executes the `/removerange` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let position = |name: &str| {
+        interaction
+            .data
+            .options
+            .iter()
+            .find(|v| v.name == name)
+            .and_then(|v| v.value.as_i64())
+            .filter(|v| *v > 0)
+    };
+
+    let (Some(start), Some(end)) = (position("start"), position("end")) else {
+        event!(Level::WARN, "no valid positions provided");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            // The positions shown to the user start from 1 and include the end.
+            match manager
+                .remove_range(guild_id, start as usize - 1, end as usize)
+                .await
+            {
+                Ok(count) => t_plural(
+                    &interaction.locale,
+                    "removerange.removed",
+                    count as u64,
+                    [count],
+                ),
+                Err(e) => {
+                    event!(Level::WARN, error = ?e, "cannot remove the tracks");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/removerange` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("removerange");
+
+    command = serenity_command_name("removerange.name", command);
+    command = serenity_command_description("removerange.description", command);
+
+    command
+        .description("Remove multiple songs from the queue.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "start",
+                "The position of the first song to remove.",
+            )
+            .min_int_value(1)
+            .required(true);
+
+            option = serenity_command_option_name("removerange.start_name", option);
+            option = serenity_command_option_description("removerange.start_description", option);
+
+            option
+        })
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "end",
+                "The position of the last song to remove.",
+            )
+            .min_int_value(1)
+            .required(true);
+
+            option = serenity_command_option_name("removerange.end_name", option);
+            option = serenity_command_option_description("removerange.end_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
@@ -0,0 +1,69 @@
+//! '/replay' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{CommandInteraction, Context, CreateCommand};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, serenity_command_description, serenity_command_name, t_vars},
+    utils,
+};
+
+/// Executes the `/replay` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            let Some(track) = manager.get_current_track(guild_id) else {
+                return err(&interaction.locale, "error.empty_queue");
+            };
+
+            match manager.replay(guild_id).await {
+                Ok(()) => t_vars(
+                    &interaction.locale,
+                    "replay.replaying",
+                    [track.title, track.author],
+                ),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot replay the track");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/replay` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("replay");
+
+    command = serenity_command_name("replay.name", command);
+    command = serenity_command_description("replay.description", command);
+
+    command
+        .description("Restart the current song from the beginning.")
+        .dm_permission(false)
+}
@@ -0,0 +1,56 @@
+//! '/rewind' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+
+use crate::{
+    i18n::{
+        serenity_command_description, serenity_command_name, serenity_command_option_description,
+        serenity_command_option_name,
+    },
+    shared::{self, SharedInteraction},
+};
+
+/// Executes the `/rewind` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let time = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_str());
+
+    shared::seek::execute(
+        context,
+        &SharedInteraction::Command(interaction),
+        time,
+        false,
+    )
+    .await
+}
+
+/// Creates the `/rewind` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("rewind");
+
+    command = serenity_command_name("rewind.name", command);
+    command = serenity_command_description("rewind.description", command);
+
+    command
+        .description("Seek the current song back.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::String,
+                "time",
+                "How much to seek back, in seconds or a supported syntax.",
+            )
+            .required(true);
+
+            option = serenity_command_option_name("rewind.time_name", option);
+            option = serenity_command_option_description("rewind.time_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
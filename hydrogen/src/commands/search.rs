@@ -0,0 +1,160 @@
+//! '/search' command registration and execution.
+
+use beef::lean::Cow;
+use hydrolink::Track as LavalinkTrack;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateActionRow, CreateCommand,
+    CreateCommandOption, CreateEmbed, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption, EditInteractionResponse,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t, t_vars,
+    },
+    shared::search::{SEARCH_CHOICES, SearchChoices},
+    utils::{
+        self,
+        constants::{DISCORD_SELECT_OPTION_LIMIT, HYDROGEN_PRIMARY_COLOR, HYDROGEN_SEARCH_CHOICES},
+        time_to_string, truncate,
+    },
+};
+
+/// Executes the `/search` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(query) = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_str())
+    else {
+        event!(Level::WARN, "no query provided");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    if let Err(e) =
+        utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+    {
+        return e;
+    }
+
+    let tracks = match manager.search_choices(query, HYDROGEN_SEARCH_CHOICES).await {
+        Ok(v) => v,
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, "cannot search the tracks");
+            return e.localize(&interaction.locale);
+        }
+    };
+
+    if tracks.is_empty() {
+        return Cow::borrowed(t(&interaction.locale, "search.not_found"));
+    }
+
+    let description = tracks
+        .iter()
+        .enumerate()
+        .map(|(i, track)| {
+            t_vars(
+                &interaction.locale,
+                "search.entry",
+                [
+                    (i + 1).to_string(),
+                    track.info.title.clone(),
+                    track.info.author.clone(),
+                    time_to_string(track.info.length / 1000),
+                ],
+            )
+            .into_owned()
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let embed = CreateEmbed::new()
+        .title(t(&interaction.locale, "search.title"))
+        .description(description)
+        .color(HYDROGEN_PRIMARY_COLOR);
+
+    let options = tracks
+        .iter()
+        .enumerate()
+        .map(|(i, track)| generate_option(i, track))
+        .collect();
+
+    let components = vec![CreateActionRow::SelectMenu(
+        CreateSelectMenu::new("search_select", CreateSelectMenuKind::String { options })
+            .placeholder(t(&interaction.locale, "search.placeholder")),
+    )];
+
+    let message = match interaction
+        .edit_response(
+            &context.http,
+            EditInteractionResponse::new()
+                .embed(embed)
+                .components(components),
+        )
+        .await
+    {
+        Ok(v) => v,
+        Err(e) => {
+            event!(Level::ERROR, error = %e, "cannot send the search results");
+            return err(&interaction.locale, "error.discord");
+        }
+    };
+
+    SEARCH_CHOICES.insert(
+        message.id,
+        SearchChoices {
+            requester: interaction.user.id,
+            tracks,
+        },
+    );
+
+    Cow::borrowed("")
+}
+
+/// Generates the select menu option of a track, using its index as the value.
+fn generate_option(index: usize, track: &LavalinkTrack) -> CreateSelectMenuOption {
+    CreateSelectMenuOption::new(
+        truncate(
+            &format!("{}. {}", index + 1, track.info.title),
+            DISCORD_SELECT_OPTION_LIMIT,
+        ),
+        index.to_string(),
+    )
+    .description(truncate(&track.info.author, DISCORD_SELECT_OPTION_LIMIT))
+}
+
+/// Creates the `/search` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("search");
+
+    command = serenity_command_name("search.name", command);
+    command = serenity_command_description("search.description", command);
+
+    command
+        .description("Search for a song and choose which result to add to the queue.")
+        .add_option({
+            let mut option =
+                CreateCommandOption::new(CommandOptionType::String, "query", "The search term.")
+                    .required(true);
+
+            option = serenity_command_option_name("search.query_name", option);
+            option = serenity_command_option_description("search.query_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
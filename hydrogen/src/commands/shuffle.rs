@@ -1,17 +1,31 @@
 //! '/shuffle' command registration and execution.
 
 use beef::lean::Cow;
-use serenity::{all::CommandInteraction, builder::CreateCommand, client::Context};
+use serenity::{
+    all::{CommandInteraction, CommandOptionType, CreateCommandOption},
+    builder::CreateCommand,
+    client::Context,
+};
 
 use crate::shared::SharedInteraction;
 use crate::{
-    i18n::{serenity_command_description, serenity_command_name},
+    i18n::{
+        serenity_command_description, serenity_command_name, serenity_command_option_description,
+        serenity_command_option_name,
+    },
     shared,
 };
 
 /// Executes the `/shuffle` command.
 pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
-    shared::shuffle::execute(context, &SharedInteraction::Command(interaction)).await
+    let remaining = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_bool())
+        .unwrap_or(false);
+
+    shared::shuffle::execute(context, &SharedInteraction::Command(interaction), remaining).await
 }
 
 /// Creates the `/shuffle` [CreateCommand].
@@ -23,5 +37,17 @@ pub fn create_command() -> CreateCommand {
 
     command
         .description("Shuffle the player queue.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "remaining",
+                "Shuffle only the songs after the current one, keeping the queue position.",
+            );
+
+            option = serenity_command_option_name("shuffle.remaining_name", option);
+            option = serenity_command_option_description("shuffle.remaining_description", option);
+
+            option
+        })
         .dm_permission(false)
 }
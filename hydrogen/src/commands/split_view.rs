@@ -0,0 +1,62 @@
+//! '/splitview' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{CommandInteraction, Context, CreateCommand};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, serenity_command_description, serenity_command_name, t},
+    utils,
+};
+
+/// Executes the `/splitview` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.toggle_split_view(guild_id).await {
+                Ok(true) => Cow::borrowed(t(&interaction.locale, "splitview.enabled")),
+                Ok(false) => Cow::borrowed(t(&interaction.locale, "splitview.disabled")),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot toggle the split view");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/splitview` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("splitview");
+
+    command = serenity_command_name("splitview.name", command);
+    command = serenity_command_description("splitview.description", command);
+
+    command
+        .description("Split the player message into a now-playing message and a controls message.")
+        .dm_permission(false)
+}
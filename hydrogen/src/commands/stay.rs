@@ -0,0 +1,91 @@
+//! '/247' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption, Permissions,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t,
+    },
+    utils,
+};
+
+/// Executes the `/247` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(enabled) = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_bool())
+    else {
+        event!(Level::WARN, "enabled option is missing");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.set_stay(guild_id, enabled).await {
+                Ok(()) if enabled => Cow::borrowed(t(&interaction.locale, "stay.enabled")),
+                Ok(()) => Cow::borrowed(t(&interaction.locale, "stay.disabled")),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot set the 24/7 mode");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/247` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("247");
+
+    command = serenity_command_name("stay.name", command);
+    command = serenity_command_description("stay.description", command);
+
+    command
+        .description("Choose if I stay in the voice chat even when no one else is connected.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Boolean,
+                "enabled",
+                "If the 24/7 mode is enabled.",
+            )
+            .required(true);
+
+            option = serenity_command_option_name("stay.enabled_name", option);
+            option = serenity_command_option_description("stay.enabled_description", option);
+
+            option
+        })
+        .default_member_permissions(Permissions::MANAGE_GUILD)
+        .dm_permission(false)
+}
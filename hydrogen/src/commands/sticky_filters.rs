@@ -0,0 +1,62 @@
+//! '/stickyfilters' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{CommandInteraction, Context, CreateCommand};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, serenity_command_description, serenity_command_name, t},
+    utils,
+};
+
+/// Executes the `/stickyfilters` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.toggle_filters_sticky(guild_id) {
+                Ok(true) => Cow::borrowed(t(&interaction.locale, "stickyfilters.enabled")),
+                Ok(false) => Cow::borrowed(t(&interaction.locale, "stickyfilters.disabled")),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot toggle the sticky filters");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/stickyfilters` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("stickyfilters");
+
+    command = serenity_command_name("stickyfilters.name", command);
+    command = serenity_command_description("stickyfilters.description", command);
+
+    command
+        .description("Choose if the effects are kept when a new song starts.")
+        .dm_permission(false)
+}
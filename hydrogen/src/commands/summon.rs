@@ -0,0 +1,131 @@
+//! '/summon' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{ChannelId, CommandInteraction, Context, CreateCommand};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, serenity_command_description, serenity_command_name, t, t_vars},
+    utils,
+};
+
+use super::join;
+
+/// Executes the `/summon` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    match summon_action(
+        manager.contains_player(guild_id),
+        manager.get_voice_channel_id(guild_id).await,
+        voice_channel_id,
+    ) {
+        SummonAction::Join => return join::execute(context, interaction).await,
+        SummonAction::AlreadyHere => {
+            return Cow::borrowed(t(&interaction.locale, "summon.already_here"));
+        }
+        SummonAction::Move => {}
+    }
+
+    if let Err(e) = manager.move_to(guild_id, voice_channel_id).await {
+        event!(Level::INFO, voice_channel_id = %voice_channel_id, error = %e, "cannot move to the voice channel");
+        return e.localize(&interaction.locale);
+    }
+
+    t_vars(
+        &interaction.locale,
+        "summon.result",
+        [format!("<#{}>", voice_channel_id)],
+    )
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What `/summon` does with the player.
+enum SummonAction {
+    /// There's no player, so it behaves like `/join`.
+    Join,
+    /// The bot is already in the voice channel.
+    AlreadyHere,
+    /// The bot is moved to the voice channel, keeping the player and its queue.
+    Move,
+}
+
+/// Decide what `/summon` does, from if a player exists and the voice channel the bot is connected to.
+fn summon_action(
+    has_player: bool,
+    current_channel_id: Option<ChannelId>,
+    voice_channel_id: ChannelId,
+) -> SummonAction {
+    if !has_player {
+        SummonAction::Join
+    } else if current_channel_id == Some(voice_channel_id) {
+        SummonAction::AlreadyHere
+    } else {
+        SummonAction::Move
+    }
+}
+
+/// Creates the `/summon` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("summon");
+
+    command = serenity_command_name("summon.name", command);
+    command = serenity_command_description("summon.description", command);
+
+    command
+        .description("Move me to your voice chat, keeping the music playing.")
+        .dm_permission(false)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summon_without_player_joins() {
+        let voice_channel_id = ChannelId::new(1);
+
+        assert_eq!(
+            summon_action(false, None, voice_channel_id),
+            SummonAction::Join
+        );
+        assert_eq!(
+            summon_action(false, Some(voice_channel_id), voice_channel_id),
+            SummonAction::Join
+        );
+    }
+
+    #[test]
+    fn summon_while_playing_moves() {
+        let voice_channel_id = ChannelId::new(1);
+
+        assert_eq!(
+            summon_action(true, Some(ChannelId::new(2)), voice_channel_id),
+            SummonAction::Move
+        );
+        assert_eq!(
+            summon_action(true, None, voice_channel_id),
+            SummonAction::Move
+        );
+        assert_eq!(
+            summon_action(true, Some(voice_channel_id), voice_channel_id),
+            SummonAction::AlreadyHere
+        );
+    }
+}
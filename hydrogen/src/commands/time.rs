@@ -4,33 +4,30 @@ use beef::lean::Cow;
 use serenity::all::{
     CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
 };
-use tracing::{event, Level};
+use tracing::{Level, event};
 
 use crate::i18n::t;
 use crate::{
+    PLAYER_MANAGER,
     i18n::{
-        serenity_command_description, serenity_command_name, serenity_command_option_description,
-        serenity_command_option_name, t_vars,
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t_vars,
     },
+    music::Error,
     utils,
-    utils::{
-        progress_bar,
-        time_parsers::{semicolon_syntax, suffix_syntax},
-        time_to_string,
-    },
-    PLAYER_MANAGER,
+    utils::{progress_bar, time_parsers::parse_duration, time_to_string},
 };
 
 /// Executes the `/time` command.
 pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(&interaction.locale, "error.not_in_guild"));
+        return err(&interaction.locale, "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+        return err(&interaction.locale, "error.unknown");
     };
 
     let time_option = interaction
@@ -51,15 +48,9 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
     if let Some(my_channel_id) = my_channel_id {
         if my_channel_id == voice_channel_id {
             let possible_seek = if let Some(time) = time_option {
-                let seek_time = match suffix_syntax(time) {
-                    Some(v) => v,
-                    None => match semicolon_syntax(time) {
-                        Some(v) => v,
-                        None => {
-                            event!(Level::INFO, syntax = time, "invalid syntax provided");
-                            return Cow::borrowed(t(&interaction.locale, "error.invalid_syntax"));
-                        }
-                    },
+                let Some(seek_time) = parse_duration(time) else {
+                    event!(Level::INFO, syntax = time, "invalid syntax provided");
+                    return Cow::borrowed(t(&interaction.locale, "time.invalid_syntax"));
                 };
 
                 manager.seek(guild_id, seek_time).await
@@ -70,14 +61,25 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
             let seek_result = match possible_seek {
                 Ok(Some(v)) => v,
                 Ok(None) => {
-                    return Cow::borrowed(t(&interaction.locale, "error.empty_queue"));
+                    return err(&interaction.locale, "error.empty_queue");
+                }
+                Err(Error::TrackNotSeekable) => {
+                    return Cow::borrowed(t(&interaction.locale, "time.not_seekable"));
                 }
                 Err(e) => {
                     event!(Level::ERROR, error = ?e, "cannot seek the player");
-                    return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+                    return e.localize(&interaction.locale);
                 }
             };
 
+            if seek_result.is_stream {
+                return t_vars(
+                    &interaction.locale,
+                    "time.result_live",
+                    [time_to_string(seek_result.position / 1000)],
+                );
+            }
+
             let current_time = time_to_string(seek_result.position / 1000);
             let total_time = time_to_string(seek_result.total / 1000);
             let progress_bar = progress_bar(seek_result.position, seek_result.total);
@@ -88,10 +90,10 @@ pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) ->
                 [current_time, total_time, progress_bar],
             )
         } else {
-            Cow::borrowed(t(&interaction.locale, "error.not_in_voice_channel"))
+            err(&interaction.locale, "error.not_in_voice_chat")
         }
     } else {
-        Cow::borrowed(t(&interaction.locale, "error.player_not_exists"))
+        err(&interaction.locale, "error.player_not_exists")
     }
 }
 
@@ -0,0 +1,92 @@
+//! '/volume' command registration and execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    CommandInteraction, CommandOptionType, Context, CreateCommand, CreateCommandOption,
+};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{
+        LocalizeError, err, serenity_command_description, serenity_command_name,
+        serenity_command_option_description, serenity_command_option_name, t_vars,
+    },
+    utils::{
+        self,
+        constants::{HYDROGEN_DEFAULT_VOLUME, HYDROGEN_MAX_VOLUME},
+    },
+};
+
+/// Executes the `/volume` command.
+pub async fn execute<'a>(context: &Context, interaction: &CommandInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let level = interaction
+        .data
+        .options
+        .first()
+        .and_then(|v| v.value.as_i64())
+        .map(|v| v.clamp(0, HYDROGEN_MAX_VOLUME as i64) as u16)
+        .unwrap_or(HYDROGEN_DEFAULT_VOLUME);
+
+    let voice_channel_id =
+        match utils::get_voice_channel(context, &interaction.locale, guild_id, interaction.user.id)
+        {
+            Ok(v) => v,
+            Err(e) => return e,
+        };
+
+    let my_channel_id = manager.get_voice_channel_id(guild_id).await;
+
+    if let Some(my_channel_id) = my_channel_id {
+        if my_channel_id == voice_channel_id {
+            match manager.set_volume(guild_id, level).await {
+                Ok(volume) => t_vars(&interaction.locale, "volume.set", [volume]),
+                Err(e) => {
+                    event!(Level::ERROR, error = ?e, "cannot set the volume");
+                    e.localize(&interaction.locale)
+                }
+            }
+        } else {
+            err(&interaction.locale, "error.not_in_voice_chat")
+        }
+    } else {
+        err(&interaction.locale, "error.player_not_exists")
+    }
+}
+
+/// Creates the `/volume` [CreateCommand].
+pub fn create_command() -> CreateCommand {
+    let mut command = CreateCommand::new("volume");
+
+    command = serenity_command_name("volume.name", command);
+    command = serenity_command_description("volume.description", command);
+
+    command
+        .description("Change the volume of the player.")
+        .add_option({
+            let mut option = CreateCommandOption::new(
+                CommandOptionType::Integer,
+                "level",
+                "The volume in percentage, from 0 to 1000. (default: 100)",
+            )
+            .min_int_value(0)
+            .max_int_value(HYDROGEN_MAX_VOLUME as u64)
+            .required(false);
+
+            option = serenity_command_option_name("volume.level_name", option);
+            option = serenity_command_option_description("volume.level_description", option);
+
+            option
+        })
+        .dm_permission(false)
+}
@@ -6,18 +6,23 @@ use tracing::{Level, event};
 
 use crate::shared::SharedInteraction;
 use crate::utils::delete_player_message;
-use crate::{PLAYER_MANAGER, i18n::t, music::LoopMode, utils};
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{err, t},
+    music::LoopMode,
+    utils,
+};
 
 /// Executes the `loop` command.
 pub async fn execute<'a>(context: &Context, interaction: &ComponentInteraction) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(&interaction.locale, "error.not_in_guild"));
+        return err(&interaction.locale, "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(&interaction.locale, "error.unknown"));
+        return err(&interaction.locale, "error.unknown");
     };
 
     let voice_channel_id =
@@ -48,11 +53,11 @@ pub async fn execute<'a>(context: &Context, interaction: &ComponentInteraction)
 
             Cow::borrowed(t(&interaction.locale, loop_type_translation_key))
         } else {
-            Cow::borrowed(t(&interaction.locale, "error.not_in_voice_channel"))
+            err(&interaction.locale, "error.not_in_voice_chat")
         }
     } else {
         delete_player_message(context, &SharedInteraction::from(interaction)).await;
 
-        Cow::borrowed(t(&interaction.locale, "error.player_not_exists"))
+        err(&interaction.locale, "error.player_not_exists")
     }
 }
@@ -3,27 +3,68 @@
 //! This module contains all the components from Hydrogen.
 
 use beef::lean::Cow;
-use serenity::all::{ComponentInteraction, Context};
+use serenity::all::{ComponentInteraction, Context, ModalInteraction};
 use tracing::error;
 
 mod loop_switch;
 mod pause;
 mod prev;
+mod queue;
+mod search_select;
 mod shuffle;
 mod skip;
 mod stop;
 
+/// Answers the components that open a modal, returning `false` if the component doesn't open one.
+pub async fn open_modal(context: &Context, component: &ComponentInteraction) -> bool {
+    match component.data.custom_id.as_str() {
+        "queue_page" => queue::open_page_modal(context, component).await,
+        _ => return false,
+    }
+
+    true
+}
+
+/// Executes the modals opened by the components.
+pub async fn execute_modal<'a>(
+    context: &Context,
+    modal: &ModalInteraction,
+) -> Option<Cow<'a, str>> {
+    Some(match modal.data.custom_id.as_str() {
+        "queue_page" => queue::execute_page_modal(context, modal).await,
+        _ => {
+            error!(
+                "(components::execute_modal): unknown modal: {}",
+                modal.data.custom_id
+            );
+            return None;
+        }
+    })
+}
+
 pub async fn execute<'a>(
     context: &Context,
     component: &ComponentInteraction,
 ) -> Option<Cow<'a, str>> {
-    Some(match component.data.custom_id.as_str() {
+    let custom_id = component.data.custom_id.as_str();
+
+    // The pagination components carry the page to show in the custom ID.
+    if let Some(page) = custom_id
+        .strip_prefix("queue_prev:")
+        .or_else(|| custom_id.strip_prefix("queue_next:"))
+    {
+        return Some(queue::execute(context, component, page.parse().ok()).await);
+    }
+
+    Some(match custom_id {
         "loop" => loop_switch::execute(context, component).await,
         "pause" => pause::execute(context, component).await,
         "prev" => prev::execute(context, component).await,
         "skip" => skip::execute(context, component).await,
         "stop" => stop::execute(context, component).await,
         "shuffle" => shuffle::execute(context, component).await,
+        "queue_current" => queue::execute(context, component, None).await,
+        "search_select" => search_select::execute(context, component).await,
         _ => {
             error!(
                 "(components::execute): unknown component: {}",
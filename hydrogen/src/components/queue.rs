@@ -0,0 +1,77 @@
+//! 'queue_prev', 'queue_next', 'queue_current' and 'queue_page' components execution.
+
+use beef::lean::Cow;
+use serenity::all::{
+    ActionRowComponent, ComponentInteraction, Context, CreateActionRow, CreateInputText,
+    CreateInteractionResponse, CreateModal, InputTextStyle, ModalInteraction,
+};
+use tracing::{Level, event};
+
+use crate::i18n::{err, t};
+use crate::shared;
+use crate::shared::SharedInteraction;
+
+/// Executes the queue pagination components, showing the page with the current track if no page is provided.
+pub async fn execute<'a>(
+    context: &Context,
+    interaction: &ComponentInteraction,
+    page: Option<usize>,
+) -> Cow<'a, str> {
+    shared::queue::execute(context, &SharedInteraction::Component(interaction), page).await
+}
+
+/// Opens the modal asking for the page of the queue to show.
+pub async fn open_page_modal(context: &Context, interaction: &ComponentInteraction) {
+    let modal = CreateModal::new(
+        "queue_page",
+        t(&interaction.locale, "queue.page_modal_title"),
+    )
+    .components(vec![CreateActionRow::InputText(
+        CreateInputText::new(
+            InputTextStyle::Short,
+            t(&interaction.locale, "queue.page_modal_label"),
+            "page",
+        )
+        .min_length(1)
+        .max_length(6)
+        .required(true),
+    )]);
+
+    if let Err(e) = interaction
+        .create_response(&context.http, CreateInteractionResponse::Modal(modal))
+        .await
+    {
+        event!(Level::WARN, error = ?e, "cannot open the queue page modal");
+    }
+}
+
+/// Executes the `queue_page` modal, showing the page typed by the user.
+pub async fn execute_page_modal<'a>(
+    context: &Context,
+    interaction: &ModalInteraction,
+) -> Cow<'a, str> {
+    // The page shown to the user starts from 1.
+    let Some(page) = interaction
+        .data
+        .components
+        .iter()
+        .flat_map(|row| &row.components)
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) if input.custom_id == "page" => {
+                input.value.as_deref()
+            }
+            _ => None,
+        })
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|v| *v > 0)
+    else {
+        return err(&interaction.locale, "queue.invalid_page");
+    };
+
+    shared::queue::execute(
+        context,
+        &SharedInteraction::Modal(interaction),
+        Some(page - 1),
+    )
+    .await
+}
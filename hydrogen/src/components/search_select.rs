@@ -0,0 +1,104 @@
+//! 'search_select' component execution.
+
+use beef::lean::Cow;
+use serenity::all::{ComponentInteraction, ComponentInteractionDataKind, Context};
+use tracing::{Level, event};
+
+use crate::{
+    PLAYER_MANAGER,
+    i18n::{LocalizeError, err, t, t_vars},
+    music::{PlayMode, PlayResult, PlayTracksRequest},
+    shared::search::SEARCH_CHOICES,
+    utils,
+};
+
+/// Executes the `search_select` component, adding the chosen track to the end of the queue.
+pub async fn execute<'a>(context: &Context, interaction: &ComponentInteraction) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(&interaction.locale, "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(choices) = SEARCH_CHOICES.get(&interaction.message.id) else {
+        return Cow::borrowed(t(&interaction.locale, "search.expired"));
+    };
+
+    if choices.requester != interaction.user.id {
+        return Cow::borrowed(t(&interaction.locale, "search.not_requester"));
+    }
+
+    let ComponentInteractionDataKind::StringSelect { values } = &interaction.data.kind else {
+        event!(Level::WARN, "search_select isn't a string select menu");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    let Some(track) = values
+        .first()
+        .and_then(|v| v.parse::<usize>().ok())
+        .and_then(|i| choices.tracks.get(i))
+    else {
+        event!(Level::WARN, values = ?values, "invalid search choice");
+        return err(&interaction.locale, "error.unknown");
+    };
+
+    if let Err(e) =
+        utils::join_voice_channel(context, &interaction.locale, guild_id, interaction.user.id).await
+    {
+        return e;
+    }
+
+    let play_request = PlayTracksRequest {
+        tracks: vec![track.clone()],
+        selected: None,
+        requester: interaction.user.id,
+        guild_id,
+        text_channel: interaction.channel_id,
+        locale: &interaction.locale,
+        player_template: Default::default(),
+        play_mode: PlayMode::AddToEnd,
+        start_position: None,
+        dedup: false,
+    };
+
+    let result = match manager.play_tracks(play_request).await {
+        Ok(v) => v,
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, guild_id = %guild_id, "cannot play the chosen track");
+            return e.localize(&interaction.locale);
+        }
+    };
+
+    generate_message(&interaction.locale, result)
+}
+
+/// Generates the message from the result from the player.
+fn generate_message<'a>(locale: &str, result: PlayResult) -> Cow<'a, str> {
+    let Some(track) = result.track else {
+        return Cow::borrowed(t(
+            locale,
+            if result.rejected > 0 {
+                "play.explicit"
+            } else {
+                "play.truncated"
+            },
+        ));
+    };
+
+    if result.playing {
+        t_vars(locale, "play.play_single", [track.title, track.author])
+    } else {
+        // The position shown to the user starts from 1.
+        let queue_position = (result.queue_position.unwrap_or_default() + 1).to_string();
+
+        t_vars(
+            locale,
+            "play.enqueue_single",
+            [track.title, track.author, queue_position],
+        )
+    }
+}
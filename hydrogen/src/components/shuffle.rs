@@ -8,5 +8,5 @@ use crate::shared::SharedInteraction;
 
 /// Executes the `shuffle` command.
 pub async fn execute<'a>(context: &Context, interaction: &ComponentInteraction) -> Cow<'a, str> {
-    shared::shuffle::execute(context, &SharedInteraction::Component(interaction)).await
+    shared::shuffle::execute(context, &SharedInteraction::Component(interaction), false).await
 }
@@ -2,9 +2,11 @@
 
 use beef::lean::Cow;
 use moka::sync::Cache;
-use serenity::all::{ChannelId, CreateInteractionResponseFollowup, Message};
+use serenity::all::{
+    ChannelId, CreateInteractionResponse, CreateInteractionResponseFollowup, Message,
+};
 use serenity::{
-    all::{Command, CommandInteraction, ComponentInteraction, UserId},
+    all::{Command, CommandInteraction, ComponentInteraction, ModalInteraction, UserId},
     builder::EditInteractionResponse,
     client::Context,
     http::Http,
@@ -14,6 +16,8 @@ use std::sync::LazyLock;
 use std::time::Duration;
 use tracing::{event, instrument, Level};
 
+use crate::music::PlayerManager;
+use crate::utils::constants::HYDROGEN_READY_TIMEOUT;
 use crate::{commands, components, LOADED_COMMANDS};
 
 /// Cache of the messages used to clean up the old messages when too many messages are sent.
@@ -24,12 +28,17 @@ pub static MESSAGE_CACHE: LazyLock<Cache<(ChannelId, UserId), String>> = LazyLoc
 });
 
 /// Handles a command interaction.
+///
+/// Every command is deferred before being executed, as all of them may need to wait for Lavalink or Discord, taking
+/// longer than the time allowed to respond, and the deferred response is edited with the result.
 #[instrument(skip_all, name = "command_handler", fields(command_name = %command.data.name, user_id = %command.user.id, guild_id = ?command.guild_id.map(|v| v.get()), channel_id = %command.channel_id))]
 pub async fn handle_command(context: &Context, command: &CommandInteraction) {
     let common = CommonInteraction::Command(command);
 
     let deferred = common.defer_ephemeral(&context.http).await;
 
+    wait_ready().await;
+
     if let Some(message) = commands::execute(context, command).await {
         post_execute(context, deferred, message, &common).await;
     }
@@ -38,15 +47,69 @@ pub async fn handle_command(context: &Context, command: &CommandInteraction) {
 /// Handles a component interaction.
 #[instrument(skip_all, name = "component_handler", fields(component_name = %component.data.custom_id, user_id = %component.user.id, guild_id = ?component.guild_id.map(|v| v.get()), channel_id = %component.channel_id))]
 pub async fn handle_component(context: &Context, component: &ComponentInteraction) {
+    // Components answered with a modal can't be deferred, as the modal is the response.
+    if components::open_modal(context, component).await {
+        return;
+    }
+
     let common = CommonInteraction::Component(component);
 
     let deferred = common.defer_ephemeral(&context.http).await;
 
+    wait_ready().await;
+
     if let Some(message) = components::execute(context, component).await {
         post_execute(context, deferred, message, &common).await;
     }
 }
 
+/// Handles a modal submit interaction.
+#[instrument(skip_all, name = "modal_handler", fields(modal_name = %modal.data.custom_id, user_id = %modal.user.id, guild_id = ?modal.guild_id.map(|v| v.get()), channel_id = %modal.channel_id))]
+pub async fn handle_modal(context: &Context, modal: &ModalInteraction) {
+    let common = CommonInteraction::Modal(modal);
+
+    let deferred = common.defer_ephemeral(&context.http).await;
+
+    wait_ready().await;
+
+    if let Some(message) = components::execute_modal(context, modal).await {
+        post_execute(context, deferred, message, &common).await;
+    }
+}
+
+/// Waits for Hydrogen to be initialized, as interactions can arrive before the ready event is handled.
+async fn wait_ready() {
+    if PlayerManager::wait_ready(HYDROGEN_READY_TIMEOUT)
+        .await
+        .is_none()
+    {
+        event!(
+            Level::WARN,
+            "Hydrogen wasn't initialized before the timeout"
+        );
+    }
+}
+
+/// Handles an autocomplete interaction.
+///
+/// Autocomplete interactions can't be deferred, so they're answered directly with the choices.
+#[instrument(skip_all, name = "autocomplete_handler", fields(command_name = %autocomplete.data.name, user_id = %autocomplete.user.id, guild_id = ?autocomplete.guild_id.map(|v| v.get())))]
+pub async fn handle_autocomplete(context: &Context, autocomplete: &CommandInteraction) {
+    let Some(response) = commands::autocomplete(context, autocomplete).await else {
+        return;
+    };
+
+    if let Err(e) = autocomplete
+        .create_response(
+            &context.http,
+            CreateInteractionResponse::Autocomplete(response),
+        )
+        .await
+    {
+        event!(Level::WARN, error = ?e, "cannot send the autocomplete choices");
+    }
+}
+
 /// Executed after the command or component execution.
 async fn post_execute(
     context: &Context,
@@ -144,12 +207,14 @@ pub async fn register_commands(http: impl AsRef<Http>) -> bool {
     }
 }
 
-/// A wrapper for command and component interactions for common operations.
+/// A wrapper for command, component and modal interactions for common operations.
 enum CommonInteraction<'a> {
     /// Command interaction.
     Command(&'a CommandInteraction),
     /// Component interaction.
     Component(&'a ComponentInteraction),
+    /// Modal interaction.
+    Modal(&'a ModalInteraction),
 }
 
 impl CommonInteraction<'_> {
@@ -158,6 +223,7 @@ impl CommonInteraction<'_> {
         match self {
             Self::Command(v) => v.user.id,
             Self::Component(v) => v.user.id,
+            Self::Modal(v) => v.user.id,
         }
     }
 
@@ -166,6 +232,7 @@ impl CommonInteraction<'_> {
         match self {
             Self::Command(v) => v.channel_id,
             Self::Component(v) => v.channel_id,
+            Self::Modal(v) => v.channel_id,
         }
     }
 
@@ -174,6 +241,7 @@ impl CommonInteraction<'_> {
         match self {
             Self::Command(v) => &v.token,
             Self::Component(v) => &v.token,
+            Self::Modal(v) => &v.token,
         }
     }
 
@@ -182,6 +250,7 @@ impl CommonInteraction<'_> {
         match self {
             Self::Command(v) => v.defer_ephemeral(http).await,
             Self::Component(v) => v.defer_ephemeral(http).await,
+            Self::Modal(v) => v.defer_ephemeral(http).await,
         }
         .inspect_err(|e| {
             event!(Level::WARN, error = ?e, "failed to defer interaction");
@@ -198,6 +267,7 @@ impl CommonInteraction<'_> {
         match self {
             Self::Command(v) => v.edit_response(http, response).await,
             Self::Component(v) => v.edit_response(http, response).await,
+            Self::Modal(v) => v.edit_response(http, response).await,
         }
     }
 
@@ -210,6 +280,7 @@ impl CommonInteraction<'_> {
         match self {
             Self::Command(v) => v.create_followup(http, response).await,
             Self::Component(v) => v.create_followup(http, response).await,
+            Self::Modal(v) => v.create_followup(http, response).await,
         }
     }
 }
@@ -12,6 +12,22 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "error.player_not_exists" => "There's no music player on this server.",
     "error.empty_queue" => "There are no songs in the queue.",
     "error.not_in_guild" => "You can't use this command outside a server.",
+    "error.lavalink_unavailable" => "The audio service is currently unavailable, try again in a few minutes.",
+    "error.lavalink" => "The audio service couldn't handle your request, try again later.",
+    "error.discord" => "Discord couldn't handle my request, try again later.",
+    "error.start_failed" => "I couldn't start the music player, so I have left the voice chat. Try again in a few moments.",
+    "error.index_out_of_bounds" => "There's no song at this position in the queue.",
+    "error.load_failed" => "The music source couldn't load your request, try again later or use another link.",
+    "error.unsupported_source" => "The audio service can't play songs from this website, try another link.",
+    "error.unsupported_filter" => "The audio service doesn't support this effect.",
+    "error.track_exception" => "I couldn't play this song, skipping it: {0} ({1}).",
+    "error.severity_common" => "common",
+    "error.severity_suspicious" => "suspicious",
+    "error.severity_fault" => "fault",
+    "ping.name" => "ping",
+    "ping.description" => "Shows the latency of the player to the voice chat.",
+    "ping.result" => "The voice chat latency is ``{0}ms``.",
+    "ping.not_connected" => "The player isn't connected to the voice chat yet, try again in a few seconds.",
     "play.name" => "play",
     "play.description" => "Request a song to play, adding it to the queue or playing immediately if empty.",
     "play.query_name" => "query",
@@ -21,18 +37,49 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "play.mode_end" => "Add To End",
     "play.mode_next" => "Add Next",
     "play.mode_now" => "Play Now",
+    "play.dedup_name" => "dedup",
+    "play.dedup_description" => "Skip the songs that are already in the queue.",
     "play.play_single" => "Playing: **{0}** by **{1}**.",
     "play.play_single_url" => "Playing: [**{0}**](<{2}>) by **{1}**.",
     "play.play_multi" => "**{2}** songs from your playlist have been queued, **{0}** from **{1}** has been selected to play now.",
     "play.play_multi_url" => "**{2}** songs from your playlist have been queued, [**{0}**](<{3}>) from **{1}** has been selected to play now.",
-    "play.enqueue_single" => "**{0}** by **{1}** has been added to the queue.",
-    "play.enqueue_single_url" => "[**{0}**](<{2}>) by **{1}** has been added to the queue.",
-    "play.enqueue_multi" => "**{0}** songs from your playlist have been queued.",
+    "play.enqueue_single" => "**{0}** by **{1}** has been added to the queue at position **#{2}**.",
+    "play.enqueue_single_url" => "[**{0}**](<{2}>) by **{1}** has been added to the queue at position **#{3}**.",
+    "play.enqueue_multi.one" => "**{0}** song from your playlist has been queued, starting at position **#{1}**.",
+    "play.enqueue_multi.other" => "**{0}** songs from your playlist have been queued, starting at position **#{1}**.",
+    "play.explicit" => "The requested songs have explicit content, which is blocked in this player.",
     "play.not_found" => "I can't find the requested song.",
+    "play.duplicates" => "All the requested songs are already in the queue.",
+    "play.skipped_duplicates.one" => "**{0}** song was skipped for being already in the queue.",
+    "play.skipped_duplicates.other" => "**{0}** songs were skipped for being already in the queue.",
     "play.truncated" => "You can't add more songs to the queue as it's already at the allowed limit. Please remove some songs before trying again.",
     "play.truncated_warn" => "**Warning: I need to exclude some songs from your playlist because it exceeds the allowed limit.**",
     "player.empty" => "_There's nothing currently playing._",
     "player.timeout" => "There's no one else connected to me in the voice chat. I will leave in {0} seconds.",
+    "player.deleted_reason" => "The music player has been stopped.",
+    "player.voice_lost" => "I have lost the connection to the voice chat, the music player has been stopped.",
+    "player.live" => "🔴 LIVE",
+    "relative_time.seconds" => "{0}s ago",
+    "relative_time.minutes" => "{0}m ago",
+    "relative_time.hours" => "{0}h ago",
+    "relative_time.days" => "{0}d ago",
+    "debug.name" => "debug",
+    "debug.description" => "Dump the internal state of a player, only available for the bot owners.",
+    "debug.guild_name" => "guild",
+    "debug.guild_description" => "The ID of the server, the current one is used if not provided.",
+    "debug.refresh_name" => "refresh",
+    "debug.refresh_description" => "Update the player with the state from Lavalink before showing it.",
+    "debug.not_owner" => "Only the bot owners can use this command.",
+    "debug.invalid_guild_id" => "This isn't a valid server ID.",
+    "debug.result" => "```json\n{0}\n```",
+    "debug.players_name" => "players",
+    "debug.players_description" => "Pause or resume all the players instead of dumping one, ignoring the other options.",
+    "debug.players_pause" => "Pause",
+    "debug.players_resume" => "Resume",
+    "debug.paused_all.one" => "**{0}** player has been paused.",
+    "debug.paused_all.other" => "**{0}** players have been paused.",
+    "debug.resumed_all.one" => "**{0}** player has been resumed.",
+    "debug.resumed_all.other" => "**{0}** players have been resumed.",
     "join.name" => "join",
     "join.description" => "Make me join your voice channel without playing anything.",
     "join.template_name" => "template",
@@ -44,6 +91,41 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "join.template_rpg" => "RPG",
     "join.template_autoplay" => "Autoplay",
     "join.result" => "Created the player with the template **{0}**, now you can request any music using {1}.",
+    "splitview.name" => "splitview",
+    "splitview.description" => "Split the player message into a now-playing message and a controls message.",
+    "splitview.enabled" => "The player message is now split.",
+    "splitview.disabled" => "The player message is no longer split.",
+    "effect.name" => "effect",
+    "effect.description" => "Apply an effect to the player.",
+    "effect.preset_name" => "preset",
+    "effect.preset_description" => "The effect to apply.",
+    "effect.bassboost" => "Bass Boost",
+    "effect.nightcore" => "Nightcore",
+    "effect.vaporwave" => "Vaporwave",
+    "effect.8d" => "8D",
+    "effect.off" => "Off",
+    "effect.applied" => "The effect has been applied to the player.",
+    "endafter.name" => "endafter",
+    "endafter.description" => "Choose if the player stops when the current song ends.",
+    "endafter.enabled_name" => "enabled",
+    "endafter.enabled_description" => "If the player stops when the current song ends.",
+    "endafter.enabled" => "The player will stop when the current song ends.",
+    "endafter.disabled" => "The player will keep playing when the current song ends.",
+    "filterexplicit.name" => "filterexplicit",
+    "filterexplicit.description" => "Choose if songs with explicit content can be added to the queue.",
+    "filterexplicit.enabled" => "Songs with explicit content will be blocked.",
+    "filterexplicit.disabled" => "Songs with explicit content will be allowed.",
+    "stay.name" => "247",
+    "stay.description" => "Choose if I stay in the voice chat even when no one else is connected.",
+    "stay.enabled_name" => "enabled",
+    "stay.enabled_description" => "If the 24/7 mode is enabled.",
+    "stay.enabled" => "24/7 mode enabled, I will stay in the voice chat even when no one else is connected.",
+    "stay.disabled" => "24/7 mode disabled, I will leave the voice chat when no one else is connected.",
+    "stay.footer" => "24/7 mode enabled",
+    "stickyfilters.name" => "stickyfilters",
+    "stickyfilters.description" => "Choose if the effects are kept when a new song starts.",
+    "stickyfilters.enabled" => "The effects will be kept when a new song starts.",
+    "stickyfilters.disabled" => "The effects will be cleared when a new song starts.",
     "stop.name" => "stop",
     "stop.description" => "Stops the player.",
     "stop.stopped" => "I'm leaving the voice channel. Hope to see you soon.",
@@ -61,25 +143,131 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "loop.music" => "Looping the current song.",
     "loop.queue" => "Looping the entire queue.",
     "loop.autoplay" => "Autoplay enabled, the player will automatically add songs to the queue.",
+    "lyrics.name" => "lyrics",
+    "lyrics.description" => "Shows the lyrics of the current song.",
+    "lyrics.not_found" => "I couldn't find the lyrics of the current song.",
+    "lyrics.source" => "Lyrics provided by {0}",
+    "nowplaying.name" => "nowplaying",
+    "nowplaying.description" => "See the song that is playing now.",
+    "nowplaying.live_name" => "live",
+    "nowplaying.live_description" => "Keep updating the progress of the song.",
+    "nowplaying.progress" => "by **{0}**\n``{1}/{2}``\n{3}",
+    "nowplaying.stream" => "by **{0}**\n🔴 LIVE",
     "pause.name" => "pause",
     "pause.description" => "Pauses or resumes the player.",
     "pause.paused" => "You have paused the music player.",
     "pause.resumed" => "You have resumed the music player.",
+    "search.name" => "search",
+    "search.description" => "Search for a song and choose which result to add to the queue.",
+    "search.query_name" => "query",
+    "search.query_description" => "The search term.",
+    "search.title" => "Search results",
+    "search.entry" => "`{0}.` **{1}** by **{2}** ({3})",
+    "search.placeholder" => "Choose a song to add to the queue",
+    "search.not_found" => "I couldn't find any song for your search.",
+    "search.expired" => "These search results have expired, search again to choose a song.",
+    "search.not_requester" => "Only who searched can choose a song from these results.",
     "skip.name" => "skip",
     "skip.description" => "Skips to the next song in the queue.",
     "skip.skipping" => "Skipping to the song **{0}** by **{1}**.",
     "skip.skipping_url" => "Skipping to the song [**{0}**](<{2}>) by **{1}**.",
+    "skip.voted" => "Your vote to skip this song was registered, ``{0}/{1}`` votes.",
+    "skip.queue_ended" => "There are no more songs in the queue, the player has been stopped.",
     "prev.name" => "previous",
     "prev.description" => "Plays the previous song in the queue.",
     "prev.returning" => "Backing to the song **{0}** by **{1}**.",
     "prev.returning_url" => "Backing to the song [**{0}**](<{2}>) by **{1}**.",
+    "summon.name" => "summon",
+    "summon.description" => "Move me to your voice chat, keeping the music playing.",
+    "summon.result" => "I have moved to {0}.",
+    "summon.already_here" => "I'm already in your voice chat.",
+    "playlist.name" => "playlist",
+    "playlist.description" => "Exports or imports the queue as a playlist file.",
+    "playlist.export_name" => "export",
+    "playlist.export_description" => "Sends the queue as a playlist file.",
+    "playlist.import_name" => "import",
+    "playlist.import_description" => "Adds the songs from a playlist file to the queue.",
+    "playlist.file_name" => "file",
+    "playlist.file_description" => "The playlist file exported before.",
+    "playlist.exported" => "The playlist with {0} songs is attached, use `/playlist import` to add them to a queue.",
+    "playlist.imported" => "{0} songs were added to the queue.",
+    "playlist.imported_partially" => "{0} songs were added to the queue, {1} songs couldn't be loaded.",
+    "playlist.invalid_file" => "I couldn't load any song from this file, make sure it was exported with `/playlist export`.",
+    "playlist.queue_full" => "The queue is full, remove some songs before importing a playlist.",
+    "queue.name" => "queue",
+    "queue.description" => "See the songs in the queue.",
+    "queue.page_name" => "page",
+    "queue.page_description" => "The page to show, instead of the page with the song playing now.",
+    "queue.empty" => "There are no songs in the queue.",
+    "queue.title" => "Queue",
+    "queue.page" => "Page {0} of {1} · {2} in total",
+    "queue.entry" => "`{0}.` **{1}** by **{2}** ({3}) · {4}",
+    "queue.current_entry" => "`{0}.` ▶ **{1}** by **{2}** ({3}) · {4}",
+    "queue.page_modal_title" => "Go to page",
+    "queue.page_modal_label" => "Page",
+    "queue.invalid_page" => "The page must be a number greater than zero.",
+    "remove.name" => "remove",
+    "remove.description" => "Remove a song from the queue.",
+    "remove.position_name" => "position",
+    "remove.position_description" => "The position of the song in the queue.",
+    "remove.removed" => "**{0}** by **{1}** has been removed from the queue.",
+    "remove.not_found" => "There's no song at position **#{0}** in the queue.",
+    "removerange.name" => "removerange",
+    "removerange.description" => "Remove multiple songs from the queue.",
+    "removerange.start_name" => "start",
+    "removerange.start_description" => "The position of the first song to remove.",
+    "removerange.end_name" => "end",
+    "removerange.end_description" => "The position of the last song to remove.",
+    "removerange.removed.one" => "**{0}** song has been removed from the queue.",
+    "removerange.removed.other" => "**{0}** songs have been removed from the queue.",
+    "move.name" => "move",
+    "move.description" => "Move a song to another position in the queue.",
+    "move.from_name" => "from",
+    "move.from_description" => "The current position of the song in the queue.",
+    "move.to_name" => "to",
+    "move.to_description" => "The new position of the song in the queue.",
+    "move.moved" => "The song at position **#{0}** has been moved to position **#{1}**.",
+    "jump.name" => "jump",
+    "jump.description" => "Jump to a song in the queue, playing it now.",
+    "jump.position_name" => "position",
+    "jump.position_description" => "The position of the song in the queue.",
+    "jump.jumped" => "Playing **{0}** by **{1}** now.",
+    "clear.name" => "clear",
+    "clear.description" => "Remove all the songs from the queue, except the song playing now.",
+    "clear.done" => "**{0}** songs have been removed from the queue.",
+    "volume.name" => "volume",
+    "volume.description" => "Change the volume of the player.",
+    "volume.level_name" => "level",
+    "volume.level_description" => "The volume in percentage, from 0 to 1000. (default: 100)",
+    "volume.set" => "The volume has been set to **{0}%**.",
+    "forward.name" => "forward",
+    "forward.description" => "Seek the current song forward.",
+    "forward.time_name" => "time",
+    "forward.time_description" => "How much to seek forward, in seconds or a supported syntax.",
+    "rewind.name" => "rewind",
+    "rewind.description" => "Seek the current song back.",
+    "rewind.time_name" => "time",
+    "rewind.time_description" => "How much to seek back, in seconds or a supported syntax.",
     "time.name" => "time",
     "time.description" => "See or change the current time of the playing track.",
     "time.time_name" => "time",
     "time.time_description" => "Time in seconds or a supported syntax.",
-    "time.invalid_syntax" => "Invalid time time syntax. You can use numbers as seconds or suffix them with `m` to be minutes or `h` to be hours. You can also use `00:00` or `00:00:00` to set the hours.",
+    "time.invalid_syntax" => "Invalid time syntax. You can use numbers as seconds or suffix them with `m` to be minutes or `h` to be hours, combining them like `1h30m`. You can also use `00:00` or `00:00:00` to set the hours.",
+    "time.not_seekable" => "The current song can't be seeked.",
     "time.result" => "``{0}/{1}``\n{2}",
+    "time.result_live" => "``{0}``\n🔴 LIVE",
+    "playto.name" => "playto",
+    "playto.description" => "Stop the current song at a time.",
+    "playto.time_name" => "time",
+    "playto.time_description" => "The time to stop the song at, leave it empty to play until the end.",
+    "playto.set" => "The current song will stop at ``{0}``.",
+    "playto.reset" => "The current song will play until the end.",
+    "replay.name" => "replay",
+    "replay.description" => "Restart the current song from the beginning.",
+    "replay.replaying" => "**{0}** by **{1}** has been restarted.",
     "shuffle.name" => "shuffle",
     "shuffle.description" => "Shuffle the player queue.",
+    "shuffle.remaining_name" => "remaining",
+    "shuffle.remaining_description" => "Shuffle only the songs after the current one, keeping the queue position.",
     "shuffle.result" => "The queue has been shuffled.",
 };
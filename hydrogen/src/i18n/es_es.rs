@@ -0,0 +1,273 @@
+//! Spanish (Spain) translations.
+
+use phf::{Map, phf_map};
+
+// This macro generates a static map with the translations.
+pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
+    "error.unknown" => "¡Ha ocurrido un error desconocido!",
+    "error.unknown_voice_state" => "No puedo determinar tu estado de voz, comprueba mis permisos o si estás en un chat de voz.",
+    "error.cant_connect" => "No puedo entrar en tu canal de voz. Comprueba si tengo permiso para acceder a él.",
+    "error.not_in_voice_chat" => "No puedes controlar el reproductor de música desde fuera del canal de voz.",
+    "error.player_exists" => "Ya hay un reproductor de música en otro canal de voz.",
+    "error.player_not_exists" => "No hay ningún reproductor de música en este servidor.",
+    "error.empty_queue" => "No hay canciones en la cola.",
+    "error.not_in_guild" => "No puedes usar este comando fuera de un servidor.",
+    "error.lavalink_unavailable" => "El servicio de audio no está disponible ahora mismo, inténtalo de nuevo en unos minutos.",
+    "error.lavalink" => "El servicio de audio no ha podido atender tu petición, inténtalo de nuevo más tarde.",
+    "error.discord" => "Discord no ha podido atender mi petición, inténtalo de nuevo más tarde.",
+    "error.start_failed" => "No he podido iniciar el reproductor de música, así que he salido del chat de voz. Inténtalo de nuevo en unos momentos.",
+    "error.index_out_of_bounds" => "No hay ninguna canción en esta posición de la cola.",
+    "error.load_failed" => "La fuente de música no ha podido cargar tu petición, inténtalo de nuevo más tarde o usa otro enlace.",
+    "error.unsupported_source" => "El servicio de audio no puede reproducir canciones de este sitio, prueba con otro enlace.",
+    "error.unsupported_filter" => "El servicio de audio no admite este efecto.",
+    "error.track_exception" => "No pude reproducir esta canción, saltándola: {0} ({1}).",
+    "error.severity_common" => "común",
+    "error.severity_suspicious" => "sospechoso",
+    "error.severity_fault" => "fallo",
+    "ping.name" => "ping",
+    "ping.description" => "Muestra la latencia del reproductor con el chat de voz.",
+    "ping.result" => "La latencia del chat de voz es de ``{0}ms``.",
+    "ping.not_connected" => "El reproductor aún no está conectado al chat de voz, inténtalo de nuevo en unos segundos.",
+    "play.name" => "reproducir",
+    "play.description" => "Pide una canción, añadiéndola a la cola o reproduciéndola al momento si está vacía.",
+    "play.query_name" => "busqueda",
+    "play.query_description" => "Una URL de canción o lista de reproducción, o un término de búsqueda.",
+    "play.mode_name" => "modo",
+    "play.mode_description" => "El modo en el que reproducir la canción.",
+    "play.mode_end" => "Añadir Al Final",
+    "play.mode_next" => "Añadir Siguiente",
+    "play.mode_now" => "Reproducir Ahora",
+    "play.dedup_name" => "sin-repetidas",
+    "play.dedup_description" => "Omite las canciones que ya están en la cola.",
+    "play.play_single" => "Reproduciendo: **{0}** de **{1}**.",
+    "play.play_single_url" => "Reproduciendo: [**{0}**](<{2}>) de **{1}**.",
+    "play.play_multi" => "Se han añadido a la cola **{2}** canciones de tu lista, **{0}** de **{1}** se ha seleccionado para sonar ahora.",
+    "play.play_multi_url" => "Se han añadido a la cola **{2}** canciones de tu lista, [**{0}**](<{3}>) de **{1}** se ha seleccionado para sonar ahora.",
+    "play.enqueue_single" => "**{0}** de **{1}** se ha añadido a la cola en la posición **#{2}**.",
+    "play.enqueue_single_url" => "[**{0}**](<{2}>) de **{1}** se ha añadido a la cola en la posición **#{3}**.",
+    "play.enqueue_multi.one" => "Se ha añadido a la cola **{0}** canción de tu lista, empezando en la posición **#{1}**.",
+    "play.enqueue_multi.other" => "Se han añadido a la cola **{0}** canciones de tu lista, empezando en la posición **#{1}**.",
+    "play.explicit" => "Las canciones pedidas tienen contenido explícito, que está bloqueado en este reproductor.",
+    "play.not_found" => "No encuentro la canción pedida.",
+    "play.duplicates" => "Todas las canciones pedidas ya están en la cola.",
+    "play.skipped_duplicates.one" => "Se ha omitido **{0}** canción por estar ya en la cola.",
+    "play.skipped_duplicates.other" => "Se han omitido **{0}** canciones por estar ya en la cola.",
+    "play.truncated" => "No puedes añadir más canciones a la cola porque ya está en el límite permitido. Quita algunas canciones antes de volver a intentarlo.",
+    "play.truncated_warn" => "**Aviso: he tenido que excluir algunas canciones de tu lista porque supera el límite permitido.**",
+    "player.empty" => "_No está sonando nada ahora mismo._",
+    "player.timeout" => "No hay nadie más conectado conmigo en el chat de voz. Me iré en {0} segundos.",
+    "player.deleted_reason" => "El reproductor de música se ha detenido.",
+    "player.voice_lost" => "He perdido la conexión con el chat de voz, el reproductor de música se ha detenido.",
+    "player.live" => "🔴 EN DIRECTO",
+    "relative_time.seconds" => "hace {0}s",
+    "relative_time.minutes" => "hace {0}m",
+    "relative_time.hours" => "hace {0}h",
+    "relative_time.days" => "hace {0}d",
+    "debug.name" => "depurar",
+    "debug.description" => "Vuelca el estado interno de un reproductor, solo disponible para los dueños del bot.",
+    "debug.guild_name" => "servidor",
+    "debug.guild_description" => "El ID del servidor, se usa el actual si no se indica.",
+    "debug.refresh_name" => "actualizar",
+    "debug.refresh_description" => "Actualiza el reproductor con el estado de Lavalink antes de mostrarlo.",
+    "debug.not_owner" => "Solo los dueños del bot pueden usar este comando.",
+    "debug.invalid_guild_id" => "Este no es un ID de servidor válido.",
+    "debug.result" => "```json\n{0}\n```",
+    "debug.players_name" => "reproductores",
+    "debug.players_description" => "Pausa o reanuda todos los reproductores en lugar de volcar uno, ignorando las otras opciones.",
+    "debug.players_pause" => "Pausar",
+    "debug.players_resume" => "Reanudar",
+    "debug.paused_all.one" => "**{0}** reproductor ha sido pausado.",
+    "debug.paused_all.other" => "**{0}** reproductores han sido pausados.",
+    "debug.resumed_all.one" => "**{0}** reproductor ha sido reanudado.",
+    "debug.resumed_all.other" => "**{0}** reproductores han sido reanudados.",
+    "join.name" => "unirse",
+    "join.description" => "Hazme entrar en tu canal de voz sin reproducir nada.",
+    "join.template_name" => "plantilla",
+    "join.template_description" => "La plantilla con la que crear el reproductor.",
+    "join.template_default" => "Predeterminada",
+    "join.template_music" => "Música",
+    "join.template_queue" => "Cola",
+    "join.template_manual" => "Manual",
+    "join.template_rpg" => "RPG",
+    "join.template_autoplay" => "Reproducción Automática",
+    "join.result" => "He creado el reproductor con la plantilla **{0}**, ahora puedes pedir cualquier música con {1}.",
+    "splitview.name" => "dividir-reproductor",
+    "splitview.description" => "Divide el mensaje del reproductor en un mensaje de lo que suena y otro de controles.",
+    "splitview.enabled" => "El mensaje del reproductor ahora está dividido.",
+    "splitview.disabled" => "El mensaje del reproductor ya no está dividido.",
+    "effect.name" => "efecto",
+    "effect.description" => "Aplica un efecto al reproductor.",
+    "effect.preset_name" => "preajuste",
+    "effect.preset_description" => "El efecto que aplicar.",
+    "effect.bassboost" => "Potenciar Graves",
+    "effect.nightcore" => "Nightcore",
+    "effect.vaporwave" => "Vaporwave",
+    "effect.8d" => "8D",
+    "effect.off" => "Desactivado",
+    "effect.applied" => "El efecto se ha aplicado al reproductor.",
+    "endafter.name" => "terminar",
+    "endafter.description" => "Elige si el reproductor se detiene cuando termine la canción actual.",
+    "endafter.enabled_name" => "activado",
+    "endafter.enabled_description" => "Si el reproductor se detiene cuando termine la canción actual.",
+    "endafter.enabled" => "El reproductor se detendrá cuando termine la canción actual.",
+    "endafter.disabled" => "El reproductor seguirá sonando cuando termine la canción actual.",
+    "filterexplicit.name" => "filtrar-explicito",
+    "filterexplicit.description" => "Elige si las canciones con contenido explícito pueden añadirse a la cola.",
+    "filterexplicit.enabled" => "Las canciones con contenido explícito se bloquearán.",
+    "filterexplicit.disabled" => "Las canciones con contenido explícito se permitirán.",
+    "stay.name" => "247",
+    "stay.description" => "Elige si me quedo en el chat de voz aunque no haya nadie más conectado.",
+    "stay.enabled_name" => "activado",
+    "stay.enabled_description" => "Si el modo 24/7 está activado.",
+    "stay.enabled" => "Modo 24/7 activado, me quedaré en el chat de voz aunque no haya nadie más conectado.",
+    "stay.disabled" => "Modo 24/7 desactivado, saldré del chat de voz cuando no haya nadie más conectado.",
+    "stay.footer" => "Modo 24/7 activado",
+    "stickyfilters.name" => "fijar-efectos",
+    "stickyfilters.description" => "Elige si los efectos se mantienen cuando empieza una nueva canción.",
+    "stickyfilters.enabled" => "Los efectos se mantendrán cuando empiece una nueva canción.",
+    "stickyfilters.disabled" => "Los efectos se quitarán cuando empiece una nueva canción.",
+    "stop.name" => "detener",
+    "stop.description" => "Detiene el reproductor.",
+    "stop.stopped" => "Me voy del canal de voz. Espero veros pronto.",
+    "loop.name" => "repetir",
+    "loop.description" => "Cambia el modo de repetición del reproductor.",
+    "loop.mode_name" => "modo",
+    "loop.mode_description" => "El modo de repetición que establecer.",
+    "loop.mode_default" => "Predeterminado",
+    "loop.mode_single" => "Una",
+    "loop.mode_all" => "Todas",
+    "loop.mode_auto_pause" => "Pausa Automática",
+    "loop.mode_autoplay" => "Reproducción Automática",
+    "loop.normal" => "Repetición desactivada, el reproductor empezará la siguiente canción automáticamente.",
+    "loop.pause" => "Repetición desactivada, el reproductor esperará a que empieces la siguiente canción.",
+    "loop.music" => "Repitiendo la canción actual.",
+    "loop.queue" => "Repitiendo toda la cola.",
+    "loop.autoplay" => "Reproducción automática activada, el reproductor añadirá canciones a la cola automáticamente.",
+    "lyrics.name" => "letra",
+    "lyrics.description" => "Muestra la letra de la canción actual.",
+    "lyrics.not_found" => "No he podido encontrar la letra de la canción actual.",
+    "lyrics.source" => "Letra proporcionada por {0}",
+    "nowplaying.name" => "sonando-ahora",
+    "nowplaying.description" => "Mira la canción que está sonando ahora.",
+    "nowplaying.live_name" => "en-vivo",
+    "nowplaying.live_description" => "Sigue actualizando el progreso de la canción.",
+    "nowplaying.progress" => "de **{0}**\n``{1}/{2}``\n{3}",
+    "nowplaying.stream" => "de **{0}**\n🔴 EN DIRECTO",
+    "pause.name" => "pausar",
+    "pause.description" => "Pausa o reanuda el reproductor.",
+    "pause.paused" => "Has pausado el reproductor de música.",
+    "pause.resumed" => "Has reanudado el reproductor de música.",
+    "search.name" => "buscar",
+    "search.description" => "Busca una canción y elige qué resultado añadir a la cola.",
+    "search.query_name" => "busqueda",
+    "search.query_description" => "El término de búsqueda.",
+    "search.title" => "Resultados de la búsqueda",
+    "search.entry" => "`{0}.` **{1}** de **{2}** ({3})",
+    "search.placeholder" => "Elige una canción para añadir a la cola",
+    "search.not_found" => "No he encontrado ninguna canción para tu búsqueda.",
+    "search.expired" => "Estos resultados de búsqueda han caducado, vuelve a buscar para elegir una canción.",
+    "search.not_requester" => "Solo quien ha buscado puede elegir una canción de estos resultados.",
+    "skip.name" => "saltar",
+    "skip.description" => "Salta a la siguiente canción de la cola.",
+    "skip.skipping" => "Saltando a la canción **{0}** de **{1}**.",
+    "skip.skipping_url" => "Saltando a la canción [**{0}**](<{2}>) de **{1}**.",
+    "skip.voted" => "Tu voto para saltar esta canción se ha registrado, ``{0}/{1}`` votos.",
+    "skip.queue_ended" => "No hay más canciones en la cola, el reproductor se ha detenido.",
+    "prev.name" => "anterior",
+    "prev.description" => "Reproduce la canción anterior de la cola.",
+    "prev.returning" => "Volviendo a la canción **{0}** de **{1}**.",
+    "prev.returning_url" => "Volviendo a la canción [**{0}**](<{2}>) de **{1}**.",
+    "summon.name" => "llamar",
+    "summon.description" => "Muéveme a tu chat de voz, manteniendo la música sonando.",
+    "summon.result" => "Me he movido a {0}.",
+    "summon.already_here" => "Ya estoy en tu chat de voz.",
+    "playlist.name" => "lista",
+    "playlist.description" => "Exporta o importa la cola como un archivo de lista de reproducción.",
+    "playlist.export_name" => "exportar",
+    "playlist.export_description" => "Envía la cola como un archivo de lista de reproducción.",
+    "playlist.import_name" => "importar",
+    "playlist.import_description" => "Añade a la cola las canciones de un archivo de lista de reproducción.",
+    "playlist.file_name" => "archivo",
+    "playlist.file_description" => "El archivo de lista de reproducción exportado antes.",
+    "playlist.exported" => "La lista con {0} canciones está adjunta, usa `/playlist import` para añadirlas a una cola.",
+    "playlist.imported" => "Se han añadido {0} canciones a la cola.",
+    "playlist.imported_partially" => "Se han añadido {0} canciones a la cola, {1} canciones no se han podido cargar.",
+    "playlist.invalid_file" => "No he podido cargar ninguna canción de este archivo, asegúrate de que se exportó con `/playlist export`.",
+    "playlist.queue_full" => "La cola está llena, quita algunas canciones antes de importar una lista.",
+    "queue.name" => "cola",
+    "queue.description" => "Mira las canciones de la cola.",
+    "queue.page_name" => "pagina",
+    "queue.page_description" => "La página que mostrar, en lugar de la página con la canción que suena ahora.",
+    "queue.empty" => "No hay canciones en la cola.",
+    "queue.title" => "Cola",
+    "queue.page" => "Página {0} de {1} · {2} en total",
+    "queue.entry" => "`{0}.` **{1}** de **{2}** ({3}) · {4}",
+    "queue.current_entry" => "`{0}.` ▶ **{1}** de **{2}** ({3}) · {4}",
+    "queue.page_modal_title" => "Ir a la página",
+    "queue.page_modal_label" => "Página",
+    "queue.invalid_page" => "La página debe ser un número mayor que cero.",
+    "remove.name" => "quitar",
+    "remove.description" => "Quita una canción de la cola.",
+    "remove.position_name" => "posicion",
+    "remove.position_description" => "La posición de la canción en la cola.",
+    "remove.removed" => "**{0}** de **{1}** se ha quitado de la cola.",
+    "remove.not_found" => "No hay ninguna canción en la posición **#{0}** de la cola.",
+    "removerange.name" => "quitarvarias",
+    "removerange.description" => "Quita varias canciones de la cola.",
+    "removerange.start_name" => "inicio",
+    "removerange.start_description" => "La posición de la primera canción a quitar.",
+    "removerange.end_name" => "fin",
+    "removerange.end_description" => "La posición de la última canción a quitar.",
+    "removerange.removed.one" => "Se ha quitado **{0}** canción de la cola.",
+    "removerange.removed.other" => "Se han quitado **{0}** canciones de la cola.",
+    "move.name" => "mover",
+    "move.description" => "Mueve una canción a otra posición de la cola.",
+    "move.from_name" => "desde",
+    "move.from_description" => "La posición actual de la canción en la cola.",
+    "move.to_name" => "hasta",
+    "move.to_description" => "La nueva posición de la canción en la cola.",
+    "move.moved" => "La canción de la posición **#{0}** se ha movido a la posición **#{1}**.",
+    "jump.name" => "saltar-a",
+    "jump.description" => "Salta a una canción de la cola, reproduciéndola ahora.",
+    "jump.position_name" => "posicion",
+    "jump.position_description" => "La posición de la canción en la cola.",
+    "jump.jumped" => "Reproduciendo **{0}** de **{1}** ahora.",
+    "clear.name" => "limpiar",
+    "clear.description" => "Quita todas las canciones de la cola, excepto la que suena ahora.",
+    "clear.done" => "Se han quitado **{0}** canciones de la cola.",
+    "volume.name" => "volumen",
+    "volume.description" => "Cambia el volumen del reproductor.",
+    "volume.level_name" => "nivel",
+    "volume.level_description" => "El volumen en porcentaje, de 0 a 1000. (predeterminado: 100)",
+    "volume.set" => "El volumen se ha establecido en **{0}%**.",
+    "forward.name" => "avanzar",
+    "forward.description" => "Avanza la canción actual.",
+    "forward.time_name" => "tiempo",
+    "forward.time_description" => "Cuánto avanzar, en segundos o con una sintaxis admitida.",
+    "rewind.name" => "retroceder",
+    "rewind.description" => "Retrocede la canción actual.",
+    "rewind.time_name" => "tiempo",
+    "rewind.time_description" => "Cuánto retroceder, en segundos o con una sintaxis admitida.",
+    "time.name" => "tiempo",
+    "time.description" => "Mira o cambia el tiempo actual de la pista que suena.",
+    "time.time_name" => "tiempo",
+    "time.time_description" => "Tiempo en segundos o con una sintaxis admitida.",
+    "time.invalid_syntax" => "Sintaxis de tiempo no válida. Puedes usar números como segundos o añadirles `m` para minutos o `h` para horas, combinándolos como `1h30m`. También puedes usar `00:00` o `00:00:00` para indicar las horas.",
+    "time.not_seekable" => "No se puede cambiar el tiempo de la canción actual.",
+    "time.result" => "``{0}/{1}``\n{2}",
+    "time.result_live" => "``{0}``\n🔴 EN DIRECTO",
+    "playto.name" => "reproducirhasta",
+    "playto.description" => "Detiene la canción actual en un tiempo.",
+    "playto.time_name" => "tiempo",
+    "playto.time_description" => "El tiempo en el que detener la canción, déjalo vacío para reproducirla hasta el final.",
+    "playto.set" => "La canción actual se detendrá en ``{0}``.",
+    "playto.reset" => "La canción actual se reproducirá hasta el final.",
+    "replay.name" => "reiniciar",
+    "replay.description" => "Vuelve a empezar la canción actual desde el principio.",
+    "replay.replaying" => "**{0}** de **{1}** ha vuelto a empezar.",
+    "shuffle.name" => "mezclar",
+    "shuffle.description" => "Mezcla la cola del reproductor.",
+    "shuffle.remaining_name" => "restantes",
+    "shuffle.remaining_description" => "Mezcla solo las canciones después de la actual, manteniendo la posición en la cola.",
+    "shuffle.result" => "La cola se ha mezclado.",
+};
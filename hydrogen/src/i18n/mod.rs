@@ -5,17 +5,48 @@ use dynfmt::{Format, FormatArgs, SimpleCurlyFormat};
 use phf::Map;
 use serenity::all::{CreateCommand, CreateCommandOption};
 
+use crate::music::Error;
+
 mod en_us;
+mod es_es;
 mod pt_br;
 
-pub static AVAILABLE_LANGS: &[(&str, &Map<&str, &str>); 2] = &[
+pub static AVAILABLE_LANGS: &[(&str, &Map<&str, &str>); 3] = &[
     ("en-US", &en_us::TRANSLATIONS),
+    ("es-ES", &es_es::TRANSLATIONS),
     ("pt-BR", &pt_br::TRANSLATIONS),
 ];
 
 /// Translate a key to a specific language.
 pub fn t<'a>(lang: &str, key: &'a str) -> &'a str {
+    lookup(lang, key).unwrap_or(key)
+}
+
+/// Translate an error key to a specific language, ready to be returned as the response.
+pub fn err<'a>(lang: &str, key: &'a str) -> Cow<'a, str> {
+    Cow::borrowed(t(lang, key))
+}
+
+/// Translate a key to a specific language with variables.
+pub fn t_vars<'a, A: FormatArgs>(lang: &str, key: &'a str, args: A) -> Cow<'a, str> {
+    format(t(lang, key), args)
+}
+
+/// Translate a key to a specific language with variables, selecting the plural form based on the count.
+///
+/// The plural forms are stored as `key.one` and `key.other`, falling back to `key.other` when `key.one` is absent.
+pub fn t_plural<'a, A: FormatArgs>(lang: &str, key: &'a str, count: u64, args: A) -> Cow<'a, str> {
+    let content = lookup(lang, &format!("{}.{}", key, plural_category(lang, count)))
+        .or_else(|| lookup(lang, &format!("{}.other", key)))
+        .unwrap_or(key);
+
+    format(content, args)
+}
+
+/// Get the translation of a key in a specific language, falling back to English.
+fn lookup(lang: &str, key: &str) -> Option<&'static str> {
     let lang_content = match lang {
+        "es-ES" => &es_es::TRANSLATIONS,
         "pt-BR" => &pt_br::TRANSLATIONS,
         _ => &en_us::TRANSLATIONS,
     };
@@ -23,13 +54,21 @@ pub fn t<'a>(lang: &str, key: &'a str) -> &'a str {
     lang_content
         .get(key)
         .or(en_us::TRANSLATIONS.get(key))
-        .unwrap_or(&key)
+        .copied()
 }
 
-/// Translate a key to a specific language with variables.
-pub fn t_vars<'a, A: FormatArgs>(lang: &str, key: &'a str, args: A) -> Cow<'a, str> {
-    let content = t(lang, key);
+/// Get the plural category of a count in a specific language.
+fn plural_category(lang: &str, count: u64) -> &'static str {
+    let one = match lang {
+        "pt-BR" => count <= 1,
+        _ => count == 1,
+    };
+
+    if one { "one" } else { "other" }
+}
 
+/// Replace the variables in a translated content.
+fn format<'a, A: FormatArgs>(content: &'a str, args: A) -> Cow<'a, str> {
     let formatted = SimpleCurlyFormat.format(content, &args);
 
     match formatted {
@@ -38,6 +77,47 @@ pub fn t_vars<'a, A: FormatArgs>(lang: &str, key: &'a str, args: A) -> Cow<'a, s
     }
 }
 
+/// An error that can be translated into a message for the user.
+pub trait LocalizeError {
+    /// Translate the error to a specific language.
+    fn localize<'a>(&self, lang: &str) -> Cow<'a, str>;
+}
+
+impl LocalizeError for Error {
+    fn localize<'a>(&self, lang: &str) -> Cow<'a, str> {
+        err(lang, error_key(self))
+    }
+}
+
+/// Get the translation key of the message for the error.
+fn error_key(error: &Error) -> &'static str {
+    match error {
+        Error::NoAvailableLavalink => "error.lavalink_unavailable",
+        Error::Lavalink(_) => "error.lavalink",
+        Error::InvalidGuildId => "error.not_in_guild",
+        Error::Join(_) => "error.cant_connect",
+        Error::Serenity(_) => "error.discord",
+        Error::GuildChannelNotFound => "error.unknown_voice_state",
+        Error::PlayerNotFound => "error.player_not_exists",
+        Error::TrackNotSeekable => "time.not_seekable",
+        Error::StartFailed(_) => "error.start_failed",
+        Error::IndexOutOfBounds => "error.index_out_of_bounds",
+        Error::LoadFailed(_) => "error.load_failed",
+        Error::UnsupportedSource(_) => "error.unsupported_source",
+        Error::UnsupportedFilter(_) => "error.unsupported_filter",
+    }
+}
+
+/// Get the keys from the English translations that are missing in the other available languages, as `(language, key)` pairs.
+pub fn missing_keys() -> impl Iterator<Item = (&'static str, &'static str)> {
+    AVAILABLE_LANGS.iter().flat_map(|(lang, translations)| {
+        en_us::TRANSLATIONS
+            .keys()
+            .filter(|key| !translations.contains_key(*key))
+            .map(move |key| (*lang, *key))
+    })
+}
+
 /// Translate a key to all available languages.
 pub fn t_all(key: &str) -> Iter<'_> {
     Iter { key, index: 0 }
@@ -107,3 +187,57 @@ pub fn serenity_command_option_description(
 
     option
 }
+
+#[cfg(test)]
+mod tests {
+    use hydrolink::{Exception, Severity};
+    use songbird::error::JoinError;
+
+    use super::*;
+
+    #[test]
+    fn translations_are_complete() {
+        let missing = missing_keys().collect::<Vec<_>>();
+
+        assert!(missing.is_empty(), "missing translations: {:?}", missing);
+    }
+
+    #[test]
+    fn errors_have_translated_messages() {
+        let errors = [
+            Error::NoAvailableLavalink,
+            Error::Lavalink(hydrolink::Error::NodeNotFound),
+            Error::InvalidGuildId,
+            Error::Join(JoinError::NoCall),
+            Error::Serenity(serenity::Error::Other("test")),
+            Error::GuildChannelNotFound,
+            Error::PlayerNotFound,
+            Error::TrackNotSeekable,
+            Error::StartFailed(Box::new(Error::PlayerNotFound)),
+            Error::IndexOutOfBounds,
+            Error::LoadFailed(Exception {
+                message: None,
+                severity: Severity::Common,
+                cause: String::new(),
+            }),
+            Error::UnsupportedSource("youtube"),
+            Error::UnsupportedFilter("timescale"),
+        ];
+
+        for error in errors {
+            let key = error_key(&error);
+
+            assert_ne!(key, "error.unknown", "{} uses the unknown error", error);
+
+            for (lang, _) in AVAILABLE_LANGS {
+                assert_ne!(
+                    error.localize(lang),
+                    key,
+                    "{} has no translation for {}",
+                    key,
+                    lang
+                );
+            }
+        }
+    }
+}
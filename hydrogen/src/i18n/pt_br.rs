@@ -12,6 +12,22 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "error.player_not_exists" => "Não tem um tocador de música nesse servidor.",
     "error.empty_queue" => "Não há músicas na fila.",
     "error.not_in_guild" => "Você não pode usar esse comando fora de um servidor.",
+    "error.lavalink_unavailable" => "O serviço de áudio está indisponível no momento, tente novamente em alguns minutos.",
+    "error.lavalink" => "O serviço de áudio não conseguiu atender o seu pedido, tente novamente mais tarde.",
+    "error.discord" => "O Discord não conseguiu atender o meu pedido, tente novamente mais tarde.",
+    "error.start_failed" => "Eu não consegui iniciar o tocador de música, então eu saí do chat de voz. Tente novamente em alguns instantes.",
+    "error.index_out_of_bounds" => "Não há nenhuma música nessa posição da fila.",
+    "error.load_failed" => "A fonte de música não conseguiu carregar seu pedido, tente novamente mais tarde ou use outro link.",
+    "error.unsupported_source" => "O serviço de áudio não consegue tocar músicas desse site, tente outro link.",
+    "error.unsupported_filter" => "O serviço de áudio não suporta esse efeito.",
+    "error.track_exception" => "Eu não consegui tocar essa música, pulando ela: {0} ({1}).",
+    "error.severity_common" => "comum",
+    "error.severity_suspicious" => "suspeito",
+    "error.severity_fault" => "falha",
+    "ping.name" => "ping",
+    "ping.description" => "Mostra a latência do tocador de música para o chat de voz.",
+    "ping.result" => "A latência do chat de voz é ``{0}ms``.",
+    "ping.not_connected" => "O tocador de música ainda não está conectado ao chat de voz, tente novamente em alguns segundos.",
     "play.name" => "tocar",
     "play.description" => "Pede para uma música ser tocada, enfileirando ela na fila ou tocando imediatamente se vazio.",
     "play.query_name" => "pesquisa",
@@ -21,18 +37,49 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "play.mode_end" => "Adicionar ao Fim",
     "play.mode_next" => "Adicionar Próximo",
     "play.mode_now" => "Tocar Agora",
+    "play.dedup_name" => "sem-repetidas",
+    "play.dedup_description" => "Ignora as músicas que já estão na fila.",
     "play.play_single" => "Tocando: **{0}** by **{1}**.",
     "play.play_single_url" => "Tocando: [**{0}**](<{2}>) por **{1}**.",
     "play.play_multi" => "**{2}** músicas de sua playlist foram enfileirados, **{0}** por **{1}** foi selecionada para tocar agora.",
     "play.play_multi_url" => "**{2}** músicas de sua playlist foram enfileirados, [**{0}**](<{3}>) por **{1}** foi selecionada para tocar agora.",
-    "play.enqueue_single" => "**{0}** por **{1}** foi adicionado na fila.",
-    "play.enqueue_single_url" => "[**{0}**](<{2}>) por **{1}** foi adicionado na fila.",
-    "play.enqueue_multi" => "**{0}** músicas da sua playlist foram enfileirados.",
+    "play.enqueue_single" => "**{0}** por **{1}** foi adicionado na fila na posição **#{2}**.",
+    "play.enqueue_single_url" => "[**{0}**](<{2}>) por **{1}** foi adicionado na fila na posição **#{3}**.",
+    "play.enqueue_multi.one" => "**{0}** música da sua playlist foi enfileirada, começando na posição **#{1}**.",
+    "play.enqueue_multi.other" => "**{0}** músicas da sua playlist foram enfileiradas, começando na posição **#{1}**.",
+    "play.explicit" => "As músicas solicitadas têm conteúdo explícito, que está bloqueado neste tocador.",
     "play.not_found" => "Eu não pude encontrar a música solicitada.",
+    "play.duplicates" => "Todas as músicas pedidas já estão na fila.",
+    "play.skipped_duplicates.one" => "**{0}** música foi ignorada por já estar na fila.",
+    "play.skipped_duplicates.other" => "**{0}** músicas foram ignoradas por já estarem na fila.",
     "play.truncated" => "Você não pode adicionar mais músicas na queue uma vez que ela já esteja no limite permitido. Por favor remova umas algumas músicas antes de tentar de novo.",
     "play.truncated_warn" => "**Aviso: Eu preciso ignorar algumas músicas da sua playlist porque ela maior que o limite permitido.**",
     "player.empty" => "_Atualmente não estou tocando nada._",
     "player.timeout" => "Não há mais ninguém conectado no chat de voz. Eu estarei saindo em {0} segundos.",
+    "player.deleted_reason" => "O tocador de música foi parado.",
+    "player.voice_lost" => "Eu perdi a conexão com o chat de voz, o tocador de música foi parado.",
+    "player.live" => "🔴 AO VIVO",
+    "relative_time.seconds" => "há {0}s",
+    "relative_time.minutes" => "há {0}min",
+    "relative_time.hours" => "há {0}h",
+    "relative_time.days" => "há {0}d",
+    "debug.name" => "depurar",
+    "debug.description" => "Mostra o estado interno de um tocador, disponível apenas para os donos do bot.",
+    "debug.guild_name" => "servidor",
+    "debug.guild_description" => "O ID do servidor, o atual é usado se não for informado.",
+    "debug.refresh_name" => "atualizar",
+    "debug.refresh_description" => "Atualiza o tocador com o estado do Lavalink antes de mostrá-lo.",
+    "debug.not_owner" => "Apenas os donos do bot podem usar esse comando.",
+    "debug.invalid_guild_id" => "Esse não é um ID de servidor válido.",
+    "debug.result" => "```json\n{0}\n```",
+    "debug.players_name" => "tocadores",
+    "debug.players_description" => "Pausa ou retoma todos os tocadores em vez de mostrar um, ignorando as outras opções.",
+    "debug.players_pause" => "Pausar",
+    "debug.players_resume" => "Retomar",
+    "debug.paused_all.one" => "**{0}** tocador foi pausado.",
+    "debug.paused_all.other" => "**{0}** tocadores foram pausados.",
+    "debug.resumed_all.one" => "**{0}** tocador foi retomado.",
+    "debug.resumed_all.other" => "**{0}** tocadores foram retomados.",
     "join.name" => "entrar",
     "join.description" => "Me faça entrar no chat de voz sem tocar nada.",
     "join.template_name" => "template",
@@ -44,6 +91,41 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "join.template_rpg" => "RPG",
     "join.template_autoplay" => "Reprodução Automática",
     "join.result" => "Criei o tocador de música com o template **{0}**, agora você pode pedir qualquer música usando {1}.",
+    "splitview.name" => "dividir-tocador",
+    "splitview.description" => "Divide a mensagem do tocador em uma mensagem do que está tocando e uma com os controles.",
+    "splitview.enabled" => "A mensagem do tocador agora está dividida.",
+    "splitview.disabled" => "A mensagem do tocador não está mais dividida.",
+    "effect.name" => "efeito",
+    "effect.description" => "Aplica um efeito no tocador de música.",
+    "effect.preset_name" => "predefinicao",
+    "effect.preset_description" => "O efeito a ser aplicado.",
+    "effect.bassboost" => "Reforço de Graves",
+    "effect.nightcore" => "Nightcore",
+    "effect.vaporwave" => "Vaporwave",
+    "effect.8d" => "8D",
+    "effect.off" => "Desligado",
+    "effect.applied" => "O efeito foi aplicado no tocador de música.",
+    "endafter.name" => "terminar",
+    "endafter.description" => "Escolha se o tocador de música para quando a música atual terminar.",
+    "endafter.enabled_name" => "ativado",
+    "endafter.enabled_description" => "Se o tocador de música para quando a música atual terminar.",
+    "endafter.enabled" => "O tocador de música vai parar quando a música atual terminar.",
+    "endafter.disabled" => "O tocador de música vai continuar tocando quando a música atual terminar.",
+    "filterexplicit.name" => "filtrar-explicito",
+    "filterexplicit.description" => "Escolhe se músicas com conteúdo explícito podem ser adicionadas na fila.",
+    "filterexplicit.enabled" => "Músicas com conteúdo explícito serão bloqueadas.",
+    "filterexplicit.disabled" => "Músicas com conteúdo explícito serão permitidas.",
+    "stay.name" => "247",
+    "stay.description" => "Escolha se eu fico no chat de voz mesmo quando não há mais ninguém conectado.",
+    "stay.enabled_name" => "ativado",
+    "stay.enabled_description" => "Se o modo 24/7 está ativado.",
+    "stay.enabled" => "Modo 24/7 ativado, eu vou ficar no chat de voz mesmo quando não houver mais ninguém conectado.",
+    "stay.disabled" => "Modo 24/7 desativado, eu vou sair do chat de voz quando não houver mais ninguém conectado.",
+    "stay.footer" => "Modo 24/7 ativado",
+    "stickyfilters.name" => "fixar-efeitos",
+    "stickyfilters.description" => "Escolhe se os efeitos são mantidos quando uma nova música começa.",
+    "stickyfilters.enabled" => "Os efeitos serão mantidos quando uma nova música começar.",
+    "stickyfilters.disabled" => "Os efeitos serão removidos quando uma nova música começar.",
     "stop.name" => "parar",
     "stop.description" => "Para o tocador de música.",
     "stop.stopped" => "Eu estou saindo do chat de voz. Espero te ver em breve.",
@@ -61,25 +143,131 @@ pub static TRANSLATIONS: Map<&'static str, &'static str> = phf_map! {
     "loop.music" => "Repetindo a música atual.",
     "loop.queue" => "Repetindo a fila inteira.",
     "loop.autoplay" => "Reprodução automática ativada, o tocador de música irá adicionar músicas automaticamente na fila.",
+    "lyrics.name" => "letra",
+    "lyrics.description" => "Mostra a letra da música atual.",
+    "lyrics.not_found" => "Eu não consegui encontrar a letra da música atual.",
+    "lyrics.source" => "Letra fornecida por {0}",
+    "nowplaying.name" => "tocando-agora",
+    "nowplaying.description" => "Veja a música que está tocando agora.",
+    "nowplaying.live_name" => "ao-vivo",
+    "nowplaying.live_description" => "Continua atualizando o progresso da música.",
+    "nowplaying.progress" => "por **{0}**\n``{1}/{2}``\n{3}",
+    "nowplaying.stream" => "por **{0}**\n🔴 AO VIVO",
     "pause.name" => "pausar",
     "pause.description" => "Pausa ou resume o tocador de música.",
     "pause.paused" => "Você pausou o tocador de música.",
     "pause.resumed" => "Você resumiu o tocador de música.",
+    "search.name" => "pesquisar",
+    "search.description" => "Pesquise uma música e escolha qual resultado adicionar na fila.",
+    "search.query_name" => "pesquisa",
+    "search.query_description" => "O termo da pesquisa.",
+    "search.title" => "Resultados da pesquisa",
+    "search.entry" => "`{0}.` **{1}** por **{2}** ({3})",
+    "search.placeholder" => "Escolha uma música para adicionar na fila",
+    "search.not_found" => "Eu não encontrei nenhuma música para sua pesquisa.",
+    "search.expired" => "Esses resultados da pesquisa expiraram, pesquise novamente para escolher uma música.",
+    "search.not_requester" => "Apenas quem pesquisou pode escolher uma música desses resultados.",
     "skip.name" => "pular",
     "skip.description" => "Pula para a próxima música na fila.",
     "skip.skipping" => "Pulando para a música **{0}** por **{1}**.",
     "skip.skipping_url" => "Pulando para a música [**{0}**](<{2}>) por **{1}**.",
+    "skip.voted" => "Seu voto para pular essa música foi registrado, ``{0}/{1}`` votos.",
+    "skip.queue_ended" => "Não há mais músicas na fila, o tocador foi parado.",
     "prev.name" => "voltar",
     "prev.description" => "Toca a música anterior na fila.",
     "prev.returning" => "Voltando para a música **{0}** por **{1}**.",
     "prev.returning_url" => "Voltando para a música [**{0}**](<{2}>) por **{1}**.",
+    "summon.name" => "chamar",
+    "summon.description" => "Me move para o seu chat de voz, mantendo a música tocando.",
+    "summon.result" => "Eu me movi para {0}.",
+    "summon.already_here" => "Eu já estou no seu chat de voz.",
+    "playlist.name" => "playlist",
+    "playlist.description" => "Exporta ou importa a fila como um arquivo de playlist.",
+    "playlist.export_name" => "exportar",
+    "playlist.export_description" => "Envia a fila como um arquivo de playlist.",
+    "playlist.import_name" => "importar",
+    "playlist.import_description" => "Adiciona as músicas de um arquivo de playlist na fila.",
+    "playlist.file_name" => "arquivo",
+    "playlist.file_description" => "O arquivo de playlist exportado antes.",
+    "playlist.exported" => "A playlist com {0} músicas está anexada, use `/playlist importar` para adicioná-las em uma fila.",
+    "playlist.imported" => "{0} músicas foram adicionadas na fila.",
+    "playlist.imported_partially" => "{0} músicas foram adicionadas na fila, {1} músicas não puderam ser carregadas.",
+    "playlist.invalid_file" => "Eu não consegui carregar nenhuma música desse arquivo, verifique se ele foi exportado com `/playlist exportar`.",
+    "playlist.queue_full" => "A fila está cheia, remova algumas músicas antes de importar uma playlist.",
+    "queue.name" => "fila",
+    "queue.description" => "Veja as músicas na fila.",
+    "queue.page_name" => "pagina",
+    "queue.page_description" => "A página a ser mostrada, ao invés da página com a música tocando agora.",
+    "queue.empty" => "Não há músicas na fila.",
+    "queue.title" => "Fila",
+    "queue.page" => "Página {0} de {1} · {2} no total",
+    "queue.entry" => "`{0}.` **{1}** de **{2}** ({3}) · {4}",
+    "queue.current_entry" => "`{0}.` ▶ **{1}** de **{2}** ({3}) · {4}",
+    "queue.page_modal_title" => "Ir para a página",
+    "queue.page_modal_label" => "Página",
+    "queue.invalid_page" => "A página deve ser um número maior que zero.",
+    "remove.name" => "remover",
+    "remove.description" => "Remove uma música da fila.",
+    "remove.position_name" => "posicao",
+    "remove.position_description" => "A posição da música na fila.",
+    "remove.removed" => "**{0}** de **{1}** foi removida da fila.",
+    "remove.not_found" => "Não há nenhuma música na posição **#{0}** da fila.",
+    "removerange.name" => "removervarias",
+    "removerange.description" => "Remove várias músicas da fila.",
+    "removerange.start_name" => "inicio",
+    "removerange.start_description" => "A posição da primeira música a remover.",
+    "removerange.end_name" => "fim",
+    "removerange.end_description" => "A posição da última música a remover.",
+    "removerange.removed.one" => "**{0}** música foi removida da fila.",
+    "removerange.removed.other" => "**{0}** músicas foram removidas da fila.",
+    "move.name" => "mover",
+    "move.description" => "Move uma música para outra posição da fila.",
+    "move.from_name" => "de",
+    "move.from_description" => "A posição atual da música na fila.",
+    "move.to_name" => "para",
+    "move.to_description" => "A nova posição da música na fila.",
+    "move.moved" => "A música na posição **#{0}** foi movida para a posição **#{1}**.",
+    "jump.name" => "pular-para",
+    "jump.description" => "Pula para uma música da fila, tocando ela agora.",
+    "jump.position_name" => "posicao",
+    "jump.position_description" => "A posição da música na fila.",
+    "jump.jumped" => "Tocando **{0}** de **{1}** agora.",
+    "clear.name" => "limpar",
+    "clear.description" => "Remove todas as músicas da fila, exceto a música tocando agora.",
+    "clear.done" => "**{0}** músicas foram removidas da fila.",
+    "volume.name" => "volume",
+    "volume.description" => "Muda o volume do tocador de música.",
+    "volume.level_name" => "nivel",
+    "volume.level_description" => "O volume em porcentagem, de 0 a 1000. (padrão: 100)",
+    "volume.set" => "O volume foi definido para **{0}%**.",
+    "forward.name" => "avancar",
+    "forward.description" => "Avança a música atual.",
+    "forward.time_name" => "tempo",
+    "forward.time_description" => "Quanto avançar, em segundos ou sintaxe suportada.",
+    "rewind.name" => "retroceder",
+    "rewind.description" => "Volta a música atual.",
+    "rewind.time_name" => "tempo",
+    "rewind.time_description" => "Quanto voltar, em segundos ou sintaxe suportada.",
     "time.name" => "tempo",
     "time.description" => "Veja ou mude o tempo atual da música tocando.",
     "time.time_name" => "tempo",
     "time.time_description" => "Tempo em segundos ou sintaxe suportada.",
-    "time.invalid_syntax" => "Sintaxe de tempo inválida. Você pode usar números como segundos ou sufixa-los com `m` para minutos ou `h` para horas. Você também pode usar `00:00` ou `00:00:00` para definir as horas.",
+    "time.invalid_syntax" => "Sintaxe de tempo inválida. Você pode usar números como segundos ou sufixa-los com `m` para minutos ou `h` para horas, combinando-os como `1h30m`. Você também pode usar `00:00` ou `00:00:00` para definir as horas.",
+    "time.not_seekable" => "Não é possível avançar ou voltar na música atual.",
     "time.result" => "``{0}/{1}``\n{2}",
+    "time.result_live" => "``{0}``\n🔴 AO VIVO",
+    "playto.name" => "tocarate",
+    "playto.description" => "Para a música atual em um tempo.",
+    "playto.time_name" => "tempo",
+    "playto.time_description" => "O tempo para parar a música, deixe vazio para tocar até o final.",
+    "playto.set" => "A música atual vai parar em ``{0}``.",
+    "playto.reset" => "A música atual vai tocar até o final.",
+    "replay.name" => "recomecar",
+    "replay.description" => "Recomeça a música atual do início.",
+    "replay.replaying" => "**{0}** de **{1}** foi recomeçada.",
     "shuffle.name" => "embaralhar",
     "shuffle.description" => "Embaralha a fila do tocador de música.",
+    "shuffle.remaining_name" => "restantes",
+    "shuffle.remaining_description" => "Embaralha apenas as músicas após a atual, mantendo a posição na fila.",
     "shuffle.result" => "A fila foi embaralhada.",
 };
@@ -1,5 +1,7 @@
 use crate::utils::constants::HYDROGEN_USER_AGENT;
-use handler::{handle_command, handle_component, register_commands};
+use handler::{
+    handle_autocomplete, handle_command, handle_component, handle_modal, register_commands,
+};
 use hydrolink::{Rest, cluster::Cluster};
 use music::PlayerManager;
 use parking_lot::Mutex;
@@ -41,10 +43,11 @@ pub static PLAYER_MANAGER: OnceLock<PlayerManager> = OnceLock::new();
 
 /// The program's entry point.
 fn main() {
-    registry()
-        .with(layer())
-        .with(EnvFilter::from_default_env())
-        .init();
+    registry().with(layer()).with(log_filter()).init();
+
+    for (lang, key) in i18n::missing_keys() {
+        event!(Level::WARN, lang = lang, key = key, "missing translation");
+    }
 
     let disable_multi_threading = env::var("DISABLE_MULTI_THREADING").is_ok_and(|v| v == "true");
 
@@ -67,6 +70,18 @@ fn main() {
     tokio_runtime.block_on(hydrogen());
 }
 
+/// Creates the log filter from `RUST_LOG` or `LOG_LEVEL`, in this order, defaulting to `info`.
+fn log_filter() -> EnvFilter {
+    if let Ok(filter) = EnvFilter::try_from_default_env() {
+        return filter;
+    }
+
+    env::var("LOG_LEVEL")
+        .ok()
+        .and_then(|v| EnvFilter::try_new(v).ok())
+        .unwrap_or_else(|| EnvFilter::new("info"))
+}
+
 /// Hydrogen's entry point.
 async fn hydrogen() {
     let lavalink_nodes = init_lavalink();
@@ -128,7 +143,13 @@ fn init_lavalink() -> Vec<Rest> {
         }
     };
 
-    lavalink_builder.parse(&lavalink)
+    match lavalink_builder.parse(&lavalink) {
+        Ok(v) => v,
+        Err(e) => {
+            event!(Level::ERROR, error = %e, "cannot parse the Lavalink nodes");
+            exit(1);
+        }
+    }
 }
 
 /// The Hydrogen handler.
@@ -165,10 +186,19 @@ impl EventHandler for HydrogenHandler {
             .set(
                 PlayerManager::new(
                     songbird,
-                    Arc::new(Cluster::new(lavalink_nodes, &ready.user.id.to_string()).await),
+                    Arc::new(
+                        Cluster::new(lavalink_nodes, &ready.user.id.to_string())
+                            .await
+                            .with_warmup(true),
+                    ),
                     ctx.cache.clone(),
                     ctx.http.clone(),
                     ready.user.id,
+                    env::var("SPLIT_VIEW").is_ok_and(|v| v == "true"),
+                    env::var("VOTE_SKIP")
+                        .ok()
+                        .and_then(|v| v.parse::<u8>().ok())
+                        .filter(|v| (1..=100).contains(v)),
                 )
                 .await,
             )
@@ -182,6 +212,8 @@ impl EventHandler for HydrogenHandler {
             exit(1);
         }
 
+        PlayerManager::notify_ready();
+
         let exec_time = init_time.elapsed();
         if exec_time > utils::constants::HYDROGEN_READY_THRESHOLD {
             event!(Level::WARN, time = ?exec_time, user_name = %ready.user.name, "initializing Hydrogen took too long");
@@ -255,6 +287,10 @@ impl EventHandler for HydrogenHandler {
         match interaction {
             Interaction::Command(command) => handle_command(&ctx, &command).await,
             Interaction::Component(component) => handle_component(&ctx, &component).await,
+            Interaction::Autocomplete(autocomplete) => {
+                handle_autocomplete(&ctx, &autocomplete).await
+            }
+            Interaction::Modal(modal) => handle_modal(&ctx, &modal).await,
             _ => (),
         }
 
@@ -1,29 +1,110 @@
 use std::{
+    num::NonZeroU64,
     sync::Arc,
     time::{Duration, Instant},
 };
 
-use hydrolink::{Event, Message, MessageKind, cluster::Cluster};
+use dashmap::DashMap;
+use hydrolink::{Event, Message, MessageKind, PlayerUpdate, cluster::Cluster};
 use serenity::all::GuildId;
-use tokio::time::sleep;
+use tokio::{
+    sync::{Mutex, mpsc},
+    time::sleep,
+};
 use tracing::{Instrument, Level, event, instrument, span};
 
 use super::PlayerManager;
-use crate::utils::constants::{HYDROGEN_LAVALINK_EVENT_THRESHOLD, LAVALINK_RECONNECTION_DELAY};
+use crate::utils::constants::{
+    DISCORD_VOICE_DISCONNECTED, DISCORD_VOICE_SESSION_INVALID, DISCORD_VOICE_SESSION_TIMEOUT,
+    HYDROGEN_LAVALINK_EVENT_THRESHOLD, HYDROGEN_LAVALINK_QUEUE_SIZE, HYDROGEN_LAVALINK_WORKERS,
+    LAVALINK_RECONNECTION_DELAY,
+};
+
+/// A message waiting to be processed by a worker.
+enum Work {
+    /// A message that can't be dropped.
+    Message(usize, Option<Result<Message, hydrolink::Error>>),
+    /// The latest player update for the guild, stored in the pending updates.
+    PlayerUpdate(GuildId),
+}
 
 /// Handle the Lavalink events.
+///
+/// The messages are processed by a pool of workers, while player updates for the same guild are coalesced, keeping
+/// only the latest one, avoiding that slow handlers stall the messages.
 pub fn handle_lavalink(player_manager: PlayerManager) {
+    let (sender, receiver) = mpsc::channel(HYDROGEN_LAVALINK_QUEUE_SIZE);
+    let receiver = Arc::new(Mutex::new(receiver));
+    let pending_updates = Arc::new(DashMap::<GuildId, (usize, PlayerUpdate)>::new());
+
+    for _ in 0..HYDROGEN_LAVALINK_WORKERS {
+        let player_manager = player_manager.clone();
+        let receiver = receiver.clone();
+        let pending_updates = pending_updates.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let Some(work) = receiver.lock().await.recv().await else {
+                    break;
+                };
+
+                match work {
+                    Work::Message(node_id, message) => {
+                        process_message(player_manager.clone(), node_id, message).await;
+                    }
+                    Work::PlayerUpdate(guild_id) => {
+                        if let Some((_, (node_id, player_update))) =
+                            pending_updates.remove(&guild_id)
+                        {
+                            process_message(
+                                player_manager.clone(),
+                                node_id,
+                                Some(Ok(Message::PlayerUpdate(player_update))),
+                            )
+                            .await;
+                        }
+                    }
+                }
+            }
+        });
+    }
+
     tokio::spawn(async move {
-        while let Some((node_id, message)) = player_manager.clone().lavalink.recv().await {
-            let player_manager = player_manager.clone();
+        while let Some((node_id, message)) = player_manager.lavalink.recv().await {
+            let Some(work) = schedule_message(&pending_updates, node_id, message) else {
+                continue;
+            };
 
-            tokio::spawn(async move {
-                process_message(player_manager, node_id, message).await;
-            });
+            if sender.send(work).await.is_err() {
+                break;
+            }
         }
     });
 }
 
+/// Get the work for a message received from Lavalink, or [None] if it doesn't need to be sent to the workers.
+///
+/// Player updates are stored in the pending updates, only creating a work when there's no pending update for the
+/// guild, while all the other messages are always sent to the workers.
+fn schedule_message(
+    pending_updates: &DashMap<GuildId, (usize, PlayerUpdate)>,
+    node_id: usize,
+    message: Option<Result<Message, hydrolink::Error>>,
+) -> Option<Work> {
+    match message {
+        Some(Ok(Message::PlayerUpdate(player_update))) => {
+            let guild_id = parse_guild_id(&player_update.guild_id)?;
+
+            // A worker is already going to process this guild, so only the update is replaced.
+            pending_updates
+                .insert(guild_id, (node_id, player_update))
+                .is_none()
+                .then_some(Work::PlayerUpdate(guild_id))
+        }
+        message => Some(Work::Message(node_id, message)),
+    }
+}
+
 #[instrument(skip_all, parent = None, name = "lavalink_handler", fields(node_id = node_id, message_kind = ?message.as_ref().and_then(|v| v.as_ref().ok().map(|v2| v2.kind())), event_kind = ?message.as_ref().and_then(|v| v.as_ref().ok().and_then(|v2| v2.as_event().map(|v3| v3.kind()))), guild_id = ?message.as_ref().and_then(|v| v.as_ref().map(|v2| v2.guild_id()).ok()).flatten()))]
 /// Process the message from Lavalink.
 async fn process_message(
@@ -65,51 +146,30 @@ async fn process_message(
     } else {
         event!(Level::WARN, "Lavalink has disconnected");
 
-        let mut should_remove = false;
-
-        let mut restart_player_list = Vec::new();
-
-        for mut player in player_manager.players.iter_mut() {
-            let player_key = *player.key();
-            let player_value = player.value_mut();
-
-            if player_value.node_id == node_id {
-                if let Some(node_id) = player_manager.lavalink.search_connected_node() {
-                    event!(
-                        Level::DEBUG,
-                        old_node = player_value.node_id,
-                        new_node = node_id,
-                        "migrating player..."
-                    );
-                    player_value.node_id = node_id;
-
-                    restart_player_list.push(player_key);
-                } else {
-                    event!(
-                        Level::ERROR,
-                        "there's no available Lavalink to migrate, removing players"
-                    );
-                    should_remove = true;
-                    break;
-                }
-            }
-        }
+        if player_manager
+            .players
+            .iter()
+            .any(|player| player.node_id == node_id)
+        {
+            if let Some(new_node_id) = player_manager.lavalink.search_connected_node() {
+                event!(
+                    Level::DEBUG,
+                    old_node = node_id,
+                    new_node = new_node_id,
+                    "migrating players..."
+                );
 
-        for player_key in restart_player_list {
-            if let Err(e) = player_manager.sync(player_key).await {
+                player_manager.migrate_node(node_id, new_node_id).await;
+            } else {
                 event!(
                     Level::ERROR,
-                    guild_id = %player_key,
-                    error = ?e,
-                    "failed to restart player"
+                    "there's no available Lavalink to migrate, removing players"
                 );
-            }
-        }
 
-        if should_remove {
-            player_manager
-                .players
-                .retain(|_, player| player.node_id != node_id);
+                player_manager
+                    .players
+                    .retain(|_, player| player.node_id != node_id);
+            }
         }
 
         reconnect_node(player_manager.lavalink.clone(), node_id);
@@ -139,30 +199,118 @@ async fn process_message(
 
 /// Process the Lavalink data.
 async fn process_data(message: &Message, player_manager: &PlayerManager) {
-    if let Some(event) = message.as_event() {
-        process_event(event, player_manager).await;
+    match message {
+        Message::PlayerUpdate(player_update) => {
+            if let Some(guild_id) = parse_guild_id(&player_update.guild_id) {
+                player_manager
+                    .on_player_update(guild_id, player_update.state.clone())
+                    .await;
+            }
+        }
+        Message::Event(event) => process_event(event, player_manager).await,
+        _ => {}
     }
 }
 
 /// Process the Lavalink event.
 async fn process_event(event: &Event, player_manager: &PlayerManager) {
+    // Events from plugins aren't handled, and they may not have a guild ID.
+    if event.is_unknown() {
+        return;
+    }
+
+    let Some(guild_id) = parse_guild_id(event.guild_id()) else {
+        return;
+    };
+
     match event {
         Event::TrackStart(track) => {
-            if let Some(guild_id) = track.guild_id.parse::<u64>().ok().map(GuildId::new) {
-                player_manager.update_message(guild_id).await;
+            player_manager.clear_skip_votes(guild_id);
+            player_manager.emit_track_event(guild_id, &track.track.encoded, true);
+            player_manager.update_message(guild_id).await;
+        }
+        // Normal closures don't need to be handled.
+        Event::WebSocketClosed(closed) if (1000..2000).contains(&closed.code) => {}
+        // The voice session is no longer usable, so the voice connection is re-established.
+        Event::WebSocketClosed(closed)
+            if closed.code == DISCORD_VOICE_SESSION_INVALID
+                || closed.code == DISCORD_VOICE_SESSION_TIMEOUT =>
+        {
+            event!(
+                Level::INFO,
+                guild_id = %guild_id,
+                code = closed.code,
+                reason = closed.reason,
+                "voice session closed, reconnecting to the voice channel"
+            );
+
+            if let Err(e) = player_manager.reconnect_voice(guild_id).await {
+                event!(
+                    Level::ERROR,
+                    error = %e,
+                    guild_id = %guild_id,
+                    "failed to reconnect to the voice channel"
+                );
+            }
+        }
+        // The voice connection was closed in a way that can't be recovered by the node, so the player is recreated.
+        Event::WebSocketClosed(closed)
+            if (4000..5000).contains(&closed.code) && closed.code != DISCORD_VOICE_DISCONNECTED =>
+        {
+            let Some(node_id) = player_manager.lavalink.best_node() else {
+                return;
+            };
+
+            event!(
+                Level::WARN,
+                guild_id = %guild_id,
+                code = closed.code,
+                reason = closed.reason,
+                "voice connection closed, recreating the player"
+            );
+
+            if let Err(e) = player_manager.failover(guild_id, node_id).await {
+                event!(
+                    Level::ERROR,
+                    error = %e,
+                    guild_id = %guild_id,
+                    "failed to recreate the player"
+                );
             }
         }
+        // Lavalink sends a track end event after the exception, which advances the queue.
+        Event::TrackException(exception) => {
+            event!(
+                Level::WARN,
+                guild_id = %guild_id,
+                exception = ?exception.exception,
+                "track threw an exception"
+            );
+
+            player_manager
+                .report_track_exception(guild_id, &exception.exception)
+                .await;
+        }
         Event::TrackEnd(track) => {
-            if track.reason.may_start_next() {
-                if let Some(guild_id) = track.guild_id.parse::<u64>().ok().map(GuildId::new) {
-                    if let Err(e) = player_manager.next_track(guild_id).await {
-                        event!(
-                            Level::ERROR,
-                            error = %e,
-                            guild_id = %guild_id,
-                            "failed to play the next track"
-                        );
-                    }
+            player_manager.emit_track_event(guild_id, &track.track.encoded, false);
+
+            if track.reason.may_start_next() && player_manager.take_stop_after_current(guild_id) {
+                if let Err(e) = player_manager.destroy(guild_id).await {
+                    event!(
+                        Level::ERROR,
+                        error = %e,
+                        guild_id = %guild_id,
+                        "failed to stop the player after the track"
+                    );
+                }
+            } else if track.reason.may_start_next() {
+                if let Err(e) = player_manager.next_track(guild_id).await {
+                    event!(
+                        Level::ERROR,
+                        error = %e,
+                        guild_id = %guild_id,
+                        "failed to play the next track"
+                    );
                 }
             }
         }
@@ -170,6 +318,21 @@ async fn process_event(event: &Event, player_manager: &PlayerManager) {
     }
 }
 
+/// Parse the guild ID sent by Lavalink, returning [None] if it's malformed.
+fn parse_guild_id(guild_id: &str) -> Option<GuildId> {
+    let parsed = guild_id.parse::<NonZeroU64>().ok().map(GuildId::from);
+
+    if parsed.is_none() {
+        event!(
+            Level::WARN,
+            guild_id = guild_id,
+            "Lavalink sent a malformed guild ID"
+        );
+    }
+
+    parsed
+}
+
 /// Reconnect a Lavalink node, retrying until it connects.
 pub fn reconnect_node(lavalink: Arc<Cluster>, node_id: usize) {
     event!(
@@ -180,9 +343,15 @@ pub fn reconnect_node(lavalink: Arc<Cluster>, node_id: usize) {
     tokio::spawn(
         async move {
             sleep(Duration::from_secs(LAVALINK_RECONNECTION_DELAY)).await;
-            while let Err(e) = lavalink.connect(node_id).await {
-                event!(Level::WARN, error = %e, "failed to reconnect to Lavalink ");
-                sleep(Duration::from_secs(LAVALINK_RECONNECTION_DELAY)).await;
+            loop {
+                match lavalink.connect(node_id).await {
+                    // Another task has connected the node in the meantime.
+                    Ok(()) | Err(hydrolink::Error::AlreadyConnected) => break,
+                    Err(e) => {
+                        event!(Level::WARN, error = %e, "failed to reconnect to Lavalink ");
+                        sleep(Duration::from_secs(LAVALINK_RECONNECTION_DELAY)).await;
+                    }
+                }
             }
             event!(Level::INFO, "reconnected to Lavalink");
         }
@@ -193,3 +362,117 @@ pub fn reconnect_node(lavalink: Arc<Cluster>, node_id: usize) {
         )),
     );
 }
+
+#[cfg(test)]
+mod tests {
+    use hydrolink::{PlayerState, WebSocketClosedEvent};
+
+    use super::*;
+
+    /// Create a player update for the guild with the position.
+    fn player_update(guild_id: u64, position: u64) -> Option<Result<Message, hydrolink::Error>> {
+        Some(Ok(Message::PlayerUpdate(PlayerUpdate {
+            guild_id: guild_id.to_string(),
+            state: PlayerState {
+                time: position,
+                position,
+                connected: true,
+                ping: 0,
+            },
+        })))
+    }
+
+    /// Create an event for the guild.
+    fn event(guild_id: u64) -> Option<Result<Message, hydrolink::Error>> {
+        Some(Ok(Message::Event(Box::new(Event::WebSocketClosed(
+            WebSocketClosedEvent {
+                guild_id: guild_id.to_string(),
+                code: 4006,
+                reason: String::new(),
+                by_remote: true,
+            },
+        )))))
+    }
+
+    #[test]
+    fn flooded_updates_are_coalesced() {
+        let pending_updates = DashMap::new();
+        let mut works = Vec::new();
+
+        for position in 0..1000 {
+            works.extend(schedule_message(
+                &pending_updates,
+                0,
+                player_update(1, position),
+            ));
+            works.extend(schedule_message(
+                &pending_updates,
+                0,
+                player_update(2, position),
+            ));
+
+            if position % 100 == 0 {
+                works.extend(schedule_message(&pending_updates, 0, event(1)));
+            }
+        }
+
+        let updates = works
+            .iter()
+            .filter_map(|work| match work {
+                Work::PlayerUpdate(guild_id) => Some(guild_id.get()),
+                Work::Message(..) => None,
+            })
+            .collect::<Vec<_>>();
+        let events = works
+            .iter()
+            .filter(|work| matches!(work, Work::Message(_, Some(Ok(Message::Event(_))))))
+            .count();
+
+        assert_eq!(updates, [1, 2]);
+        assert_eq!(events, 10);
+        assert_eq!(
+            pending_updates
+                .get(&GuildId::new(1))
+                .map(|update| update.1.state.position),
+            Some(999)
+        );
+
+        // After a worker takes the pending update, the next one creates a work again.
+        pending_updates.remove(&GuildId::new(1));
+
+        assert!(matches!(
+            schedule_message(&pending_updates, 0, player_update(1, 1000)),
+            Some(Work::PlayerUpdate(_))
+        ));
+        assert!(schedule_message(&pending_updates, 0, player_update(2, 1000)).is_none());
+    }
+
+    #[test]
+    fn disconnections_are_never_dropped() {
+        let pending_updates = DashMap::new();
+
+        assert!(matches!(
+            schedule_message(&pending_updates, 3, None),
+            Some(Work::Message(3, None))
+        ));
+        assert!(schedule_message(&pending_updates, 0, player_update(0, 0)).is_none());
+        assert!(pending_updates.is_empty());
+    }
+
+    #[test]
+    fn parse_guild_id_snowflakes() {
+        assert_eq!(
+            parse_guild_id("1128087591179268116"),
+            Some(GuildId::new(1128087591179268116))
+        );
+        assert_eq!(parse_guild_id("0"), None);
+        assert_eq!(parse_guild_id("guild"), None);
+        assert_eq!(parse_guild_id("12a"), None);
+        assert_eq!(parse_guild_id(""), None);
+        assert_eq!(parse_guild_id("18446744073709551616"), None);
+        assert_eq!(
+            parse_guild_id("18446744073709551615"),
+            Some(GuildId::new(u64::MAX))
+        );
+    }
+}
@@ -1,13 +1,13 @@
 use beef::lean::Cow;
 use serenity::all::{
     ButtonStyle, ChannelId, CreateActionRow, CreateButton, CreateEmbed, CreateEmbedAuthor,
-    CreateMessage, EditMessage, GuildId, MessageId, ReactionType,
+    CreateEmbedFooter, CreateMessage, EditMessage, GuildId, MessageId, ReactionType,
 };
 use tracing::{Level, event};
 
 use crate::{
     i18n::{t, t_vars},
-    utils::constants::{HYDROGEN_EMPTY_CHAT_TIMEOUT, HYDROGEN_PRIMARY_COLOR},
+    utils::{constants::HYDROGEN_PRIMARY_COLOR, progress_bar, time_to_string},
 };
 
 use super::{PlayerManager, PlayerState, Track};
@@ -25,14 +25,14 @@ const DISABLE_LOOP: bool = false;
 /// Whether to disable the shuffle button.
 const DISABLE_SHUFFLE: bool = false;
 
-/// Updates the player message.
+/// Updates the player message, returning the channel ID, the message ID and the now-playing message ID.
 pub async fn update_message(
     manager: &PlayerManager,
     guild_id: GuildId,
     player: &PlayerState,
     playing: bool,
     thinking: bool,
-) -> (Option<ChannelId>, Option<MessageId>) {
+) -> (Option<ChannelId>, Option<MessageId>, Option<MessageId>) {
     event!(
         Level::TRACE,
         thinking = thinking,
@@ -44,9 +44,17 @@ pub async fn update_message(
 
     let state = PlayerMessageState::detect_state(track, thinking);
 
+    if player.split_view {
+        let embed = generate_nowplaying_embed(manager, guild_id, player).await;
+        let components = generate_components(player, &state, playing);
+
+        return update_split_message(manager, guild_id, player, embed, components).await;
+    }
+
     let title = generate_title(player, track);
     let description = generate_message(player, track);
     let url = generate_url(player, track);
+    let footer = generate_footer(player);
     let author = generate_author(manager, player, guild_id).await;
     // It's very cheaper to clone the author than re-generate it
     let author_clone = author.clone();
@@ -58,7 +66,7 @@ pub async fn update_message(
         track.and_then(|track| track.thumbnail.as_ref())
     };
 
-    let embed = generate_embed(&description, title, url, thumbnail, author);
+    let embed = generate_embed(&description, title, url.as_ref(), thumbnail, author, footer);
 
     if let Some(channel_id) = player.text_channel {
         if let Some(message_id) = player.message_id {
@@ -77,7 +85,7 @@ pub async fn update_message(
                         guild_id = ?guild_id,
                         "player message updated"
                     );
-                    return (Some(channel_id), Some(msg.id));
+                    return (Some(channel_id), Some(msg.id), None);
                 }
                 Err(e) => {
                     event!(
@@ -95,9 +103,10 @@ pub async fn update_message(
         let embed = generate_embed(
             &description,
             generate_title(player, track),
-            url,
+            url.as_ref(),
             track.and_then(|track| track.thumbnail.as_ref()),
             author_clone,
+            footer,
         );
 
         let components = generate_components(player, &state, playing);
@@ -116,7 +125,7 @@ pub async fn update_message(
                     guild_id = ?guild_id,
                     "player message sent"
                 );
-                (Some(channel_id), Some(message.id))
+                (Some(channel_id), Some(message.id), None)
             }
             Err(e) => {
                 event!(
@@ -126,12 +135,182 @@ pub async fn update_message(
                     guild_id = ?guild_id,
                     "cannot send player message"
                 );
-                (None, None)
+                (None, None, None)
             }
         };
     }
 
-    (player.text_channel, player.message_id)
+    (player.text_channel, player.message_id, None)
+}
+
+/// Updates only the now-playing message, used to refresh the progress when the split view is enabled.
+pub async fn update_nowplaying_message(
+    manager: &PlayerManager,
+    guild_id: GuildId,
+    player: &PlayerState,
+) {
+    let (Some(channel_id), Some(message_id)) = (player.text_channel, player.nowplaying_message_id)
+    else {
+        return;
+    };
+
+    let embed = generate_nowplaying_embed(manager, guild_id, player).await;
+
+    if let Err(e) = channel_id
+        .edit_message(&manager, message_id, EditMessage::new().embed(embed))
+        .await
+    {
+        event!(
+            Level::INFO,
+            error = %e,
+            guild_id = ?guild_id,
+            "cannot update the now-playing message"
+        );
+    }
+}
+
+/// Generates the embed for the now-playing message, used when the split view is enabled.
+async fn generate_nowplaying_embed(
+    manager: &PlayerManager,
+    guild_id: GuildId,
+    player: &PlayerState,
+) -> CreateEmbed {
+    let track = player.track.as_ref();
+
+    let description = generate_message(player, track);
+    let description = match generate_progress(player, track) {
+        Some(progress) => Cow::owned(format!("{}\n\n{}", description, progress)),
+        None => description,
+    };
+
+    let thumbnail = if player.has_destroy_handle {
+        None
+    } else {
+        track.and_then(|track| track.thumbnail.as_ref())
+    };
+
+    generate_embed(
+        &description,
+        generate_title(player, track),
+        generate_url(player, track).as_ref(),
+        thumbnail,
+        generate_author(manager, player, guild_id).await,
+        generate_footer(player),
+    )
+}
+
+/// Updates the now-playing message and the controls message, used when the split view is enabled.
+async fn update_split_message(
+    manager: &PlayerManager,
+    guild_id: GuildId,
+    player: &PlayerState,
+    embed: CreateEmbed,
+    components: Vec<CreateActionRow>,
+) -> (Option<ChannelId>, Option<MessageId>, Option<MessageId>) {
+    let Some(channel_id) = player.text_channel else {
+        return (None, player.message_id, player.nowplaying_message_id);
+    };
+
+    let nowplaying_message_id = match player.nowplaying_message_id {
+        Some(message_id) => {
+            edit_or_send(
+                manager,
+                guild_id,
+                channel_id,
+                message_id,
+                EditMessage::new().embed(embed.clone()),
+                CreateMessage::new().embed(embed),
+            )
+            .await
+        }
+        None => {
+            send(
+                manager,
+                guild_id,
+                channel_id,
+                CreateMessage::new().embed(embed),
+            )
+            .await
+        }
+    };
+
+    let message_id = match player.message_id {
+        Some(message_id) => {
+            edit_or_send(
+                manager,
+                guild_id,
+                channel_id,
+                message_id,
+                EditMessage::new().components(components.clone()),
+                CreateMessage::new().components(components),
+            )
+            .await
+        }
+        None => {
+            send(
+                manager,
+                guild_id,
+                channel_id,
+                CreateMessage::new().components(components),
+            )
+            .await
+        }
+    };
+
+    (Some(channel_id), message_id, nowplaying_message_id)
+}
+
+/// Edits a message, sending a new one if it can't be edited.
+async fn edit_or_send(
+    manager: &PlayerManager,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    message_id: MessageId,
+    edit_message: EditMessage,
+    create_message: CreateMessage,
+) -> Option<MessageId> {
+    match channel_id
+        .edit_message(&manager, message_id, edit_message)
+        .await
+    {
+        Ok(message) => {
+            event!(Level::DEBUG, guild_id = ?guild_id, "player message updated");
+            Some(message.id)
+        }
+        Err(e) => {
+            event!(
+                Level::INFO,
+                error = %e,
+                guild_id = ?guild_id,
+                "cannot edit player message, sending a new one"
+            );
+            send(manager, guild_id, channel_id, create_message).await
+        }
+    }
+}
+
+/// Sends a new message.
+async fn send(
+    manager: &PlayerManager,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    create_message: CreateMessage,
+) -> Option<MessageId> {
+    match channel_id.send_message(&manager, create_message).await {
+        Ok(message) => {
+            event!(Level::DEBUG, guild_id = ?guild_id, "player message sent");
+            Some(message.id)
+        }
+        Err(e) => {
+            event!(
+                Level::INFO,
+                error = %e,
+                guild_id = ?guild_id,
+                "cannot send player message"
+            );
+            None
+        }
+    }
 }
 
 /// Generates the embed for the player message.
@@ -141,6 +320,7 @@ fn generate_embed(
     url: Option<&String>,
     thumbnail: Option<&String>,
     author: Option<CreateEmbedAuthor>,
+    footer: Option<&str>,
 ) -> CreateEmbed {
     let mut embed = CreateEmbed::new()
         .description(description)
@@ -162,6 +342,10 @@ fn generate_embed(
         embed = embed.thumbnail(thumbnail)
     }
 
+    if let Some(footer) = footer {
+        embed = embed.footer(CreateEmbedFooter::new(footer));
+    }
+
     embed
 }
 
@@ -180,7 +364,7 @@ fn generate_message<'a>(player: &PlayerState, track: Option<&'a Track>) -> Cow<'
         t_vars(
             &player.locale,
             "player.timeout",
-            [HYDROGEN_EMPTY_CHAT_TIMEOUT],
+            [player.empty_timeout.as_secs()],
         )
     } else {
         match track {
@@ -190,10 +374,41 @@ fn generate_message<'a>(player: &PlayerState, track: Option<&'a Track>) -> Cow<'
     }
 }
 
+/// Generates the footer for the embed, showing if the 24/7 mode is enabled.
+fn generate_footer(player: &PlayerState) -> Option<&'static str> {
+    player.stay.then(|| t(&player.locale, "stay.footer"))
+}
+
+/// Generates the progress of the current track for the now-playing message.
+fn generate_progress(player: &PlayerState, track: Option<&Track>) -> Option<String> {
+    if player.has_destroy_handle {
+        return None;
+    }
+
+    let track = track?;
+
+    if track.is_stream {
+        return Some(format!(
+            "``{}``\n{}",
+            time_to_string(player.position? / 1000),
+            t(&player.locale, "player.live")
+        ));
+    }
+
+    let position = player.position?.min(track.duration);
+
+    Some(format!(
+        "``{}/{}``\n{}",
+        time_to_string(position / 1000),
+        time_to_string(track.duration / 1000),
+        progress_bar(position, track.duration)
+    ))
+}
+
 /// Generates the URL for the embed.
-fn generate_url<'a>(player: &PlayerState, track: Option<&'a Track>) -> Option<&'a String> {
+fn generate_url(player: &PlayerState, track: Option<&Track>) -> Option<String> {
     if !player.has_destroy_handle {
-        track.and_then(|track| track.url.as_ref())
+        track.and_then(|track| track.public_url())
     } else {
         None
     }
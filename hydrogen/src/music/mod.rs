@@ -5,34 +5,47 @@ mod message;
 mod player;
 
 use hydrolink::{
-    LoadResult, Rest, Track as LavalinkTrack, UpdatePlayer, UpdatePlayerTrack, VoiceState,
-    cluster::Cluster,
+    Exception, Filters, LoadResult, Lyrics, PlayerState as LavalinkPlayerState, Rest, Severity,
+    Track as LavalinkTrack, UpdatePlayer, UpdatePlayerTrack, VoiceState, cluster::Cluster,
 };
-use message::update_message;
+use message::{update_message, update_nowplaying_message};
 pub use player::*;
-use tokio::time::sleep;
+use tokio::{
+    sync::Notify,
+    task::{JoinHandle, JoinSet},
+    time::{sleep, timeout},
+};
 use tracing::{Level, event};
 
+use crate::PLAYER_MANAGER;
+use crate::i18n::{t, t_vars};
 use crate::utils::constants::{
-    HYDROGEN_EMPTY_CHAT_TIMEOUT, HYDROGEN_QUEUE_LIMIT, HYDROGEN_SEARCH_PREFIXES,
+    HYDROGEN_EMPTY_CHAT_TIMEOUT, HYDROGEN_MAX_QUEUE_LIMIT, HYDROGEN_MAX_VOLUME,
+    HYDROGEN_MIGRATION_TIMEOUT, HYDROGEN_PAUSE_ALL_CONCURRENCY, HYDROGEN_SEARCH_PREFIXES,
+    HYDROGEN_SPLIT_VIEW_UPDATE_INTERVAL, HYDROGEN_TRACK_EXCEPTION_COOLDOWN, HYDROGEN_URL_SOURCES,
 };
 use beef::lean::Cow;
 use dashmap::DashMap;
 use lavalink::{handle_lavalink, reconnect_node};
-use rand::prelude::SliceRandom;
+use serde_json::Value;
 use serenity::all::{
-    Cache, CacheHttp, ChannelId, ChannelType, GuildId, Http, UserId, VoiceServerUpdateEvent,
-    VoiceState as SerenityVoiceState,
+    Cache, CacheHttp, ChannelId, ChannelType, GuildId, Http, MessageId, UserId,
+    VoiceServerUpdateEvent, VoiceState as SerenityVoiceState,
 };
-use songbird::{Songbird, error::JoinError};
+use songbird::{ConnectionInfo, Songbird, error::JoinError, id::ChannelId as VoiceChannelId};
 use std::{
+    collections::HashSet,
     error::Error as StdError,
-    fmt::{self, Display, Formatter},
+    fmt::{self, Debug, Display, Formatter},
+    iter, mem,
     result::Result as StdResult,
-    sync::Arc,
-    time::Duration,
+    sync::{Arc, RwLock},
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
 };
 
+/// Notifier used to wake up the interactions waiting for the player manager to be initialized.
+static PLAYER_MANAGER_READY: Notify = Notify::const_new();
+
 #[derive(Debug, Clone)]
 /// The player manager.
 pub struct PlayerManager {
@@ -40,6 +53,8 @@ pub struct PlayerManager {
     players: Arc<DashMap<GuildId, Player>>,
     /// The voice manager.
     ///
+    /// This is the only source of truth for the voice connections, players don't track them.
+    ///
     /// This [Arc] comes from outside the player manager.
     songbird: Arc<Songbird>,
     /// The Lavalink cluster.
@@ -54,6 +69,40 @@ pub struct PlayerManager {
     http: Arc<Http>,
     /// The bot user ID.
     user_id: UserId,
+    /// If new players should split the player message.
+    split_view: bool,
+    /// The percentage of listeners required to skip a track, or [None] to skip without voting.
+    vote_skip: Option<u8>,
+    /// The callbacks registered for the track events.
+    track_callbacks: Arc<RwLock<TrackCallbacks>>,
+}
+
+/// A callback invoked with the guild and the track from a track event.
+///
+/// Callbacks are invoked from the Lavalink event loop, so they should be cheap or spawn their own tasks.
+pub type TrackCallback = Box<dyn Fn(GuildId, &Track) + Send + Sync>;
+
+/// A [TrackCallback] after being registered, shared between the lists of callbacks.
+type SharedTrackCallback = Arc<dyn Fn(GuildId, &Track) + Send + Sync>;
+
+#[derive(Default)]
+/// The callbacks registered for the track events.
+///
+/// The lists are swapped when a callback is registered, so invoking them only needs to clone an [Arc].
+struct TrackCallbacks {
+    /// Callbacks invoked when a track starts.
+    start: Arc<Vec<SharedTrackCallback>>,
+    /// Callbacks invoked when a track ends.
+    end: Arc<Vec<SharedTrackCallback>>,
+}
+
+impl Debug for TrackCallbacks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TrackCallbacks")
+            .field("start", &self.start.len())
+            .field("end", &self.end.len())
+            .finish()
+    }
 }
 
 impl PlayerManager {
@@ -64,18 +113,30 @@ impl PlayerManager {
         cache: Arc<Cache>,
         http: Arc<Http>,
         user_id: UserId,
+        split_view: bool,
+        vote_skip: Option<u8>,
     ) -> Self {
         let players = Arc::new(DashMap::<GuildId, Player>::new());
 
-        for i in 0..lavalink.nodes().len() {
+        let nodes = lavalink.nodes();
+
+        for i in nodes.iter().copied() {
             event!(Level::DEBUG, node_id = i, "connecting to Lavalink...");
             if let Err(e) = lavalink.connect(i).await {
                 event!(Level::ERROR, node_id = i, error = ?e, "failed to connect to Lavalink");
                 reconnect_node(lavalink.clone(), i);
+            } else {
+                event!(Level::INFO, node_id = i, "connected to Lavalink");
             }
-            event!(Level::INFO, node_id = i, "connected to Lavalink");
         }
 
+        event!(
+            Level::INFO,
+            connected = lavalink.connected_count(),
+            total = nodes.len(),
+            "Lavalink nodes connected"
+        );
+
         let me = Self {
             players,
             songbird,
@@ -83,6 +144,9 @@ impl PlayerManager {
             cache,
             http,
             user_id,
+            split_view,
+            vote_skip,
+            track_callbacks: Arc::new(RwLock::new(TrackCallbacks::default())),
         };
 
         handle_lavalink(me.clone());
@@ -90,6 +154,77 @@ impl PlayerManager {
         me
     }
 
+    /// Wait for the player manager to be initialized, returning [None] if it isn't initialized before the timeout.
+    pub async fn wait_ready(wait: Duration) -> Option<&'static PlayerManager> {
+        let notified = PLAYER_MANAGER_READY.notified();
+
+        if let Some(manager) = PLAYER_MANAGER.get() {
+            return Some(manager);
+        }
+
+        _ = timeout(wait, notified).await;
+
+        PLAYER_MANAGER.get()
+    }
+
+    /// Wake up the interactions waiting for the player manager to be initialized.
+    pub fn notify_ready() {
+        PLAYER_MANAGER_READY.notify_waiters();
+    }
+
+    /// Register a callback invoked every time a track starts playing.
+    ///
+    /// The callback is invoked from the Lavalink event loop, so it should be cheap or spawn its own task.
+    pub fn on_track_start(&self, callback: TrackCallback) {
+        if let Ok(mut callbacks) = self.track_callbacks.write() {
+            let mut start = callbacks.start.as_ref().clone();
+            start.push(Arc::from(callback));
+            callbacks.start = Arc::new(start);
+        }
+    }
+
+    /// Register a callback invoked every time a track ends.
+    ///
+    /// The callback is invoked from the Lavalink event loop, so it should be cheap or spawn its own task.
+    pub fn on_track_end(&self, callback: TrackCallback) {
+        if let Ok(mut callbacks) = self.track_callbacks.write() {
+            let mut end = callbacks.end.as_ref().clone();
+            end.push(Arc::from(callback));
+            callbacks.end = Arc::new(end);
+        }
+    }
+
+    /// Invoke the callbacks registered for a track event, using the track from the queue.
+    fn emit_track_event(&self, guild_id: GuildId, encoded: &str, start: bool) {
+        let Some(callbacks) = self.track_callbacks.read().ok().map(|c| {
+            if start {
+                c.start.clone()
+            } else {
+                c.end.clone()
+            }
+        }) else {
+            return;
+        };
+
+        if callbacks.is_empty() {
+            return;
+        }
+
+        let Some(track) = self
+            .players
+            .view(&guild_id, |_, p| {
+                p.queue.iter().find(|t| t.track == encoded).cloned()
+            })
+            .flatten()
+        else {
+            return;
+        };
+
+        for callback in callbacks.iter() {
+            callback(guild_id, &track);
+        }
+    }
+
     /// Initialize a new player for the guild.
     pub async fn init(
         &self,
@@ -102,12 +237,13 @@ impl PlayerManager {
             self.create_player(guild_id, text_channel, locale, player_template)?;
 
             if let Some(player) = self.get_player_state(guild_id) {
-                let (channel_id, message_id) =
+                let (channel_id, message_id, nowplaying_message_id) =
                     update_message(self, guild_id, &player, false, false).await;
 
                 self.players.alter(&guild_id, |_, p| Player {
                     channel_id,
                     message_id,
+                    nowplaying_message_id,
                     ..p
                 });
             }
@@ -126,13 +262,13 @@ impl PlayerManager {
     ) -> Result<()> {
         let node_id = self
             .lavalink
-            .search_connected_node()
+            .best_node()
             .ok_or(Error::NoAvailableLavalink)?;
 
-        self.players.insert(
-            guild_id,
-            template.into_player(node_id, locale, text_channel),
-        );
+        let mut player = template.into_player(node_id, locale, text_channel);
+        player.split_view = self.split_view;
+
+        self.players.insert(guild_id, player);
 
         Ok(())
     }
@@ -142,11 +278,16 @@ impl PlayerManager {
     }
 
     /// Check if the connection exists for the guild.
+    ///
+    /// A connection only exists when Songbird has both the voice connection info and the voice channel.
     pub async fn contains_connection(&self, guild_id: GuildId) -> bool {
         if let Some(call) = self.songbird.get(guild_id) {
             let call_locked = call.lock().await;
 
-            call_locked.current_connection().is_some() && call_locked.current_channel().is_some()
+            is_connected(
+                call_locked.current_connection(),
+                call_locked.current_channel(),
+            )
         } else {
             false
         }
@@ -175,6 +316,43 @@ impl PlayerManager {
             .flatten()
     }
 
+    /// Get the tracks in the queue for the guild, starting from the offset and up to the limit.
+    pub fn get_queue(&self, guild_id: GuildId, offset: usize, limit: usize) -> Option<Vec<Track>> {
+        self.players.view(&guild_id, |_, p| {
+            p.queue.iter().skip(offset).take(limit).cloned().collect()
+        })
+    }
+
+    /// Get the index of the current track and the length of the queue for the guild.
+    pub fn get_queue_position(&self, guild_id: GuildId) -> Option<(usize, usize)> {
+        self.players
+            .view(&guild_id, |_, p| (p.current_track, p.queue.len()))
+    }
+
+    /// Get the latest ping from the Lavalink node to the Discord voice server, in milliseconds.
+    ///
+    /// This method will return `None` if the node isn't connected to the voice server or hasn't reported it yet.
+    pub fn get_ping(&self, guild_id: GuildId) -> Option<i32> {
+        self.players
+            .view(&guild_id, |_, p| p.state.as_ref().map(|s| s.ping))
+            .flatten()
+            .filter(|ping| *ping >= 0)
+    }
+
+    /// Get the total duration of the tracks in the player's queue, ignoring streams.
+    pub fn queue_duration(&self, guild_id: GuildId) -> Option<Duration> {
+        self.players.view(&guild_id, |_, p| {
+            let total = p
+                .queue
+                .iter()
+                .filter(|t| !t.is_stream && t.duration > 0)
+                .map(|t| u128::from(t.duration))
+                .sum::<u128>();
+
+            Duration::from_millis(u64::try_from(total).unwrap_or(u64::MAX))
+        })
+    }
+
     /// Get the voice channel ID for the guild.
     ///
     /// This method will return `None` if the player does not exist too.
@@ -188,24 +366,49 @@ impl PlayerManager {
             .map(|c| ChannelId::new(c.0.into()))
     }
 
-    /// Search for the music using multiple prefixes.
-    pub async fn search(&self, node: &Rest, music: &str) -> Result<LoadResult> {
-        let result = node.load_track(music).await.map_err(Error::from)?;
+    /// Dump the internal state of the player for debugging, returning `None` if the player does not exist.
+    pub async fn debug_dump(&self, guild_id: GuildId) -> Option<Value> {
+        let voice_channel_id = self.get_voice_channel_id(guild_id).await;
 
-        if result.is_empty() {
-            for prefix in HYDROGEN_SEARCH_PREFIXES.iter() {
-                let result = node
-                    .load_track(&format!("{}{}", prefix, music))
-                    .await
-                    .map_err(Error::from)?;
+        self.players
+            .view(&guild_id, |_, p| p.debug_dump(guild_id, voice_channel_id))
+    }
 
-                if !result.is_empty() {
-                    return Ok(result);
-                }
+    /// Search for the music using multiple prefixes, stopping at the first source that finds it or fails.
+    pub async fn search(&self, node: &Rest, music: &str) -> Result<SearchOutcome> {
+        let queries = iter::once(Cow::borrowed(music)).chain(
+            HYDROGEN_SEARCH_PREFIXES
+                .iter()
+                .map(|prefix| Cow::owned(format!("{}{}", prefix, music))),
+        );
+
+        for query in queries {
+            match node.load_track(&query).await.map_err(Error::from)? {
+                LoadResult::Empty => continue,
+                LoadResult::Error(exception) => return Ok(SearchOutcome::Errored(exception)),
+                result => return Ok(SearchOutcome::Found(result)),
             }
         }
 
-        Ok(result)
+        Ok(SearchOutcome::NotFound)
+    }
+
+    /// Search for tracks using the first search prefix, returning up to `limit` tracks to be chosen.
+    pub async fn search_choices(&self, query: &str, limit: usize) -> Result<Vec<LavalinkTrack>> {
+        let node = self
+            .lavalink
+            .best_node()
+            .and_then(|node_id| self.lavalink.node(node_id))
+            .ok_or(Error::NoAvailableLavalink)?;
+
+        match node
+            .load_track(&format!("{}{}", HYDROGEN_SEARCH_PREFIXES[0], query))
+            .await?
+        {
+            LoadResult::Search(tracks) => Ok(tracks.into_iter().take(limit).collect()),
+            LoadResult::Error(exception) => Err(Error::LoadFailed(exception)),
+            _ => Ok(Vec::new()),
+        }
     }
 
     /// Initialize the player for the guild, creating it if needed.
@@ -224,15 +427,16 @@ impl PlayerManager {
 
         let player_state = self
             .get_player_state(guild_id)
-            .ok_or(Error::InvalidGuildId)?;
+            .ok_or(Error::PlayerNotFound)?;
 
         if initializing {
-            let (channel_id, message_id) =
+            let (channel_id, message_id, nowplaying_message_id) =
                 update_message(self, guild_id, &player_state, false, true).await;
 
             self.players.alter(&guild_id, |_, p| Player {
                 channel_id,
                 message_id,
+                nowplaying_message_id,
                 ..p
             });
         }
@@ -242,35 +446,28 @@ impl PlayerManager {
 
     /// Search for the music and fetch the result.
     async fn fetch(&self, query: &str, node_id: usize) -> Result<Option<FetchResult>> {
-        let lavalink_node = &self.lavalink.nodes()[node_id];
-
-        let songs = self.search(lavalink_node, query).await?;
+        let lavalink_node = self
+            .lavalink
+            .node(node_id)
+            .ok_or(Error::NoAvailableLavalink)?;
 
-        Ok(match songs {
-            LoadResult::Search(tracks) => tracks.into_iter().nth(0).map(|t| FetchResult {
-                selected: None,
-                tracks: vec![t],
-            }),
-            LoadResult::Playlist(playlist) => Some(FetchResult {
-                selected: if playlist.info.selected_track >= 0 {
-                    Some(playlist.info.selected_track as usize)
-                } else {
-                    None
-                },
+        if let Some(source) = url_source(query) {
+            if !self.lavalink.supports_source(node_id, source) {
+                return Err(Error::UnsupportedSource(source));
+            }
+        }
 
-                tracks: playlist.tracks.into_iter().collect(),
-            }),
-            LoadResult::Track(music) => Some(FetchResult {
-                selected: None,
-                tracks: vec![*music],
-            }),
-            LoadResult::Empty => None,
-            LoadResult::Error(exception) => {
+        let songs = match self.search(&lavalink_node, query).await? {
+            SearchOutcome::Found(songs) => songs,
+            SearchOutcome::NotFound => return Ok(None),
+            SearchOutcome::Errored(exception) => {
                 event!(Level::WARN, error = ?exception, "failed to load track");
 
-                None
+                return Err(Error::LoadFailed(exception));
             }
-        })
+        };
+
+        Ok(FetchResult::from_load_result(songs))
     }
 
     /// Add the fetched tracks to the player's queue.
@@ -280,72 +477,13 @@ impl PlayerManager {
         fetch_result: FetchResult,
         requester: UserId,
         operation: AddQueueOperation,
+        dedup: bool,
     ) -> Result<AddQueueResult> {
-        let mut player = self
+        Ok(self
             .players
             .get_mut(&guild_id)
-            .ok_or(Error::PlayerNotFound)?;
-
-        let old_queue_size = player.queue.len();
-
-        let first_track_index = match operation {
-            AddQueueOperation::End => player.queue.len(),
-            AddQueueOperation::Next => player.current_track + 1,
-        };
-
-        let available_size = HYDROGEN_QUEUE_LIMIT - old_queue_size;
-
-        if available_size == 0 {
-            return Ok(AddQueueResult {
-                count: 0,
-                truncated: true,
-                first_track_index: old_queue_size,
-                selected: None,
-            });
-        }
-
-        let old_tracks_size = fetch_result.tracks.len();
-
-        let tracks = fetch_result
-            .tracks
-            .into_iter()
-            .take(available_size)
-            .map(|t| Track::from_track(t, requester))
-            .collect::<Vec<_>>();
-
-        let truncated = tracks.len() < old_tracks_size;
-
-        let tracks_size = tracks.len();
-
-        match operation {
-            AddQueueOperation::End => player.queue.extend(tracks),
-            AddQueueOperation::Next => {
-                let current_track = player.current_track;
-
-                let index = current_track + 1;
-
-                player.queue.splice(index..index, tracks);
-            }
-        }
-
-        let selected = fetch_result.selected.map(|i| {
-            if let Some(new_index) = first_track_index.checked_add(i) {
-                if new_index < player.queue.len() {
-                    new_index
-                } else {
-                    first_track_index
-                }
-            } else {
-                first_track_index
-            }
-        });
-
-        Ok(AddQueueResult {
-            count: tracks_size,
-            truncated,
-            first_track_index,
-            selected,
-        })
+            .ok_or(Error::PlayerNotFound)?
+            .add_tracks(fetch_result, requester, operation, dedup))
     }
 
     /// Check if the player is playing music.
@@ -458,6 +596,8 @@ impl PlayerManager {
 
     /// Play a music or add it to the queue, initializing the player if needed.
     pub async fn play(&self, play_request: PlayRequest<'_>) -> Result<PlayResult> {
+        let initializing = !self.contains_player(play_request.guild_id);
+
         let player_state = self
             .initialize_player(
                 play_request.guild_id,
@@ -467,48 +607,228 @@ impl PlayerManager {
             )
             .await?;
 
-        let Some(fetch_result) = self.fetch(play_request.music, player_state.node_id).await? else {
+        let fetch_result = self
+            .fetch(play_request.music, player_state.node_id)
+            .await?
+            .unwrap_or(FetchResult {
+                selected: None,
+                tracks: Vec::new(),
+            });
+
+        self.play_resolved(
+            PlayTracksRequest {
+                tracks: fetch_result.tracks,
+                selected: fetch_result.selected,
+                requester: play_request.requester,
+                guild_id: play_request.guild_id,
+                text_channel: play_request.text_channel,
+                locale: play_request.locale,
+                player_template: play_request.player_template,
+                play_mode: play_request.play_mode,
+                start_position: play_request.start_position,
+                dedup: play_request.dedup,
+            },
+            initializing,
+        )
+        .await
+    }
+
+    /// Play tracks already resolved by the caller, without searching them.
+    pub async fn play_tracks(&self, play_request: PlayTracksRequest<'_>) -> Result<PlayResult> {
+        let initializing = !self.contains_player(play_request.guild_id);
+
+        self.play_resolved(play_request, initializing).await
+    }
+
+    /// Play resolved tracks, destroying the player if it was initialized by this request and can't start.
+    async fn play_resolved(
+        &self,
+        play_request: PlayTracksRequest<'_>,
+        initializing: bool,
+    ) -> Result<PlayResult> {
+        self.initialize_player(
+            play_request.guild_id,
+            play_request.text_channel,
+            play_request.locale,
+            play_request.player_template,
+        )
+        .await?;
+
+        if play_request.tracks.is_empty() {
             return Ok(PlayResult {
                 track: None,
                 count: 0,
                 playing: false,
                 truncated: false,
+                queue_position: None,
+                rejected: 0,
+                skipped_duplicates: 0,
             });
-        };
+        }
 
-        let add_queue_operation = match play_request.play_mode {
-            PlayMode::AddToEnd => AddQueueOperation::End,
-            _ => AddQueueOperation::Next,
-        };
+        // A fresh queue always loads its first track, even if the loop mode wouldn't sync it,
+        // so templates that start paused (like RPG) show the track paused instead of idle.
+        let (queue_was_empty, current_track) = self
+            .players
+            .view(&play_request.guild_id, |_, p| {
+                (p.queue.is_empty(), p.current_track)
+            })
+            .unwrap_or((true, 0));
 
         let add_queue_result = self.add_queue(
             play_request.guild_id,
-            fetch_result,
+            FetchResult {
+                selected: play_request.selected,
+                tracks: play_request.tracks,
+            },
             play_request.requester,
-            add_queue_operation,
+            play_request.play_mode.add_queue_operation(),
+            play_request.dedup,
         )?;
 
-        let sync_result = if play_request.play_mode == PlayMode::PlayNow {
-            self.forced_update_sync(
-                play_request.guild_id,
-                add_queue_result
-                    .selected
-                    .unwrap_or(add_queue_result.first_track_index),
-            )
-            .await
+        if add_queue_result.count == 0 {
+            return Ok(PlayResult {
+                track: None,
+                count: 0,
+                playing: false,
+                truncated: add_queue_result.truncated,
+                queue_position: None,
+                rejected: add_queue_result.rejected,
+                skipped_duplicates: add_queue_result.skipped_duplicates,
+            });
+        }
+
+        let sync_result = if play_request.play_mode.forces_sync(queue_was_empty) {
+            self.forced_update_sync(play_request.guild_id, add_queue_result.start_index())
+                .await
         } else {
-            self.checked_update_sync(
-                play_request.guild_id,
-                add_queue_result
-                    .selected
-                    .unwrap_or(add_queue_result.first_track_index),
-            )
-            .await
-        }?;
+            self.checked_update_sync(play_request.guild_id, add_queue_result.start_index())
+                .await
+        };
+
+        let sync_result = match sync_result {
+            Ok(v) => v,
+            // A player created for this request is destroyed, otherwise only the tracks added by it are removed.
+            Err(e) if initializing && queue_was_empty => {
+                event!(
+                    Level::WARN,
+                    error = ?e,
+                    guild_id = ?play_request.guild_id,
+                    "cannot start the new player, rolling it back"
+                );
+
+                if let Err(e) = self.destroy(play_request.guild_id).await {
+                    event!(Level::ERROR, error = ?e, guild_id = ?play_request.guild_id, "cannot roll back the player");
+                }
+
+                return Err(Error::StartFailed(Box::new(e)));
+            }
+            Err(e) => {
+                if let Some(mut player) = self.players.get_mut(&play_request.guild_id) {
+                    player.rollback_tracks(&add_queue_result, current_track);
+                }
+
+                return Err(e);
+            }
+        };
+
+        if let Some(start_position) = play_request.start_position.filter(|_| sync_result.playing) {
+            // The track is already playing, so failing to seek it isn't a reason to fail the request.
+            if let Err(e) = self.seek(play_request.guild_id, start_position).await {
+                event!(Level::INFO, error = ?e, guild_id = ?play_request.guild_id, "cannot seek to the start position");
+            }
+        }
 
         Ok(PlayResult::merge(add_queue_result, sync_result))
     }
 
+    /// Export the encoded tracks of the player's queue.
+    pub fn export_queue(&self, guild_id: GuildId) -> Option<Vec<String>> {
+        self.players.view(&guild_id, |_, p| {
+            p.queue.iter().map(|t| t.track.clone()).collect::<Vec<_>>()
+        })
+    }
+
+    /// Import encoded tracks to the end of the player's queue, skipping the tracks that can't be decoded.
+    pub async fn import_queue(
+        &self,
+        guild_id: GuildId,
+        encoded: Vec<String>,
+        requester: UserId,
+    ) -> Result<ImportQueueResult> {
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        let node = self
+            .lavalink
+            .node(node_id)
+            .ok_or(Error::NoAvailableLavalink)?;
+
+        let tracks = self.decode_tracks(&node, &encoded).await;
+
+        let failed = encoded.len() - tracks.len();
+
+        let queue_was_empty = self
+            .players
+            .view(&guild_id, |_, p| p.queue.is_empty())
+            .unwrap_or(true);
+
+        let add_queue_result = self.add_queue(
+            guild_id,
+            FetchResult {
+                selected: None,
+                tracks,
+            },
+            requester,
+            AddQueueOperation::End,
+            false,
+        )?;
+
+        if add_queue_result.count > 0 {
+            if queue_was_empty {
+                self.forced_update_sync(guild_id, add_queue_result.first_track_index)
+                    .await?;
+            } else {
+                self.checked_update_sync(guild_id, add_queue_result.first_track_index)
+                    .await?;
+            }
+
+            self.update_message(guild_id).await;
+        }
+
+        Ok(ImportQueueResult {
+            count: add_queue_result.count,
+            failed,
+            truncated: add_queue_result.truncated,
+            rejected: add_queue_result.rejected,
+        })
+    }
+
+    /// Decode the tracks, decoding them one by one when they can't be decoded together to skip the invalid ones.
+    async fn decode_tracks(&self, node: &Rest, encoded: &[String]) -> Vec<LavalinkTrack> {
+        let encoded = encoded.iter().map(String::as_str).collect::<Vec<_>>();
+
+        match node.decode_tracks(&encoded).await {
+            Ok(tracks) => tracks,
+            Err(e) => {
+                event!(Level::DEBUG, error = ?e, "cannot decode the tracks together, decoding them one by one");
+
+                let mut tracks = Vec::with_capacity(encoded.len());
+
+                for encoded_track in encoded {
+                    match node.decode_track(encoded_track).await {
+                        Ok(track) => tracks.push(track),
+                        Err(e) => event!(Level::DEBUG, error = ?e, "cannot decode the track"),
+                    }
+                }
+
+                tracks
+            }
+        }
+    }
+
     /// Get the current playing time from the player.
     pub async fn time(&self, guild_id: GuildId) -> Result<Option<SeekResult>> {
         if !self.contains_player(guild_id) {
@@ -530,23 +850,41 @@ impl PlayerManager {
             p.track.map(|t| SeekResult {
                 position: t.info.position,
                 total: t.info.length,
+                is_stream: t.info.is_stream,
             })
         }))
     }
 
+    /// Get the lyrics of the current track, using the LavaLyrics plugin.
+    pub async fn lyrics(&self, guild_id: GuildId) -> Result<Option<Lyrics>> {
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        self.lavalink
+            .get_lyrics(node_id, &guild_id.to_string(), false)
+            .await
+            .map_err(Error::from)
+    }
+
     /// Seek the player to a certain time.
     pub async fn seek(&self, guild_id: GuildId, time: Duration) -> Result<Option<SeekResult>> {
         if !self.contains_player(guild_id) {
             return Err(Error::PlayerNotFound);
         }
 
-        let update_player = UpdatePlayer::default().set_position(time.as_millis() as u64);
-
-        let node_id = self
+        let (node_id, seekable) = self
             .players
-            .view(&guild_id, |_, p| p.node_id)
+            .view(&guild_id, |_, p| (p.node_id, p.can_seek()))
             .ok_or(Error::PlayerNotFound)?;
 
+        if !seekable {
+            return Err(Error::TrackNotSeekable);
+        }
+
+        let update_player = UpdatePlayer::default().set_position(time.as_millis() as u64);
+
         let player = self
             .lavalink
             .update_player(node_id, &guild_id.to_string(), &update_player, true)
@@ -555,36 +893,338 @@ impl PlayerManager {
 
         let position = time.as_millis() as u64;
 
-        Ok(player.track.map(|t| SeekResult {
+        let seek_result = player.track.map(|t| SeekResult {
             position: if position > t.info.length {
                 t.info.length
             } else {
                 position
             },
             total: t.info.length,
-        }))
-    }
-
-    /// Get the loop mode for the guild.
-    pub fn get_loop_mode(&self, guild_id: GuildId) -> Option<LoopMode> {
-        self.players.view(&guild_id, |_, p| p.loop_mode)
-    }
-
-    /// Set the loop mode for the guild.
-    pub async fn set_loop_mode(&self, guild_id: GuildId, loop_mode: LoopMode) {
-        self.players
-            .alter(&guild_id, |_, p| Player { loop_mode, ..p });
+            is_stream: t.info.is_stream,
+        });
 
-        self.update_message(guild_id).await;
-    }
+        if let Some(seek_result) = seek_result.as_ref() {
+            self.cache_apply(guild_id, |state| {
+                state.position = seek_result.position;
+                state.time = unix_millis();
+            });
+        }
 
-    /// Get the pause state for the guild.
-    pub fn get_pause(&self, guild_id: GuildId) -> Option<bool> {
-        self.players.view(&guild_id, |_, p| p.paused)
+        Ok(seek_result)
     }
 
-    /// Set the pause state for the guild.
-    pub async fn set_pause(&self, guild_id: GuildId, paused: bool) -> Result<bool> {
+    /// Restart the current track from the beginning, playing it again if it has already ended.
+    ///
+    /// Without a current track, nothing is done.
+    pub async fn replay(&self, guild_id: GuildId) -> Result<()> {
+        let (node_id, seekable) = self
+            .players
+            .view(&guild_id, |_, p| {
+                (
+                    p.node_id,
+                    p.queue
+                        .get(p.current_track)
+                        .map(|t| t.seekable && !t.is_stream),
+                )
+            })
+            .ok_or(Error::PlayerNotFound)?;
+
+        let Some(seekable) = seekable else {
+            return Ok(());
+        };
+
+        // Tracks that can't be seeked or have already ended are started again.
+        if !seekable || !self.is_playing(guild_id).await? {
+            self.sync(guild_id).await?;
+            return Ok(());
+        }
+
+        let update_player = UpdatePlayer::default().set_position(0);
+
+        self.lavalink
+            .update_player(node_id, &guild_id.to_string(), &update_player, true)
+            .await
+            .map_err(Error::from)?;
+
+        self.cache_apply(guild_id, |state| {
+            state.position = 0;
+            state.time = unix_millis();
+        });
+
+        Ok(())
+    }
+
+    /// Set the time in the current track where the player stops, or reset it with [None] to play the track until the end.
+    pub async fn set_end_time(&self, guild_id: GuildId, end: Option<Duration>) -> Result<()> {
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        // Lavalink requires the end time to be greater than zero, so zero also resets it.
+        let update_player = match end.map(|v| v.as_millis() as u64).filter(|v| *v > 0) {
+            Some(end) => UpdatePlayer::default().set_end_time(end),
+            None => UpdatePlayer::default().reset_end_time(),
+        };
+
+        self.lavalink
+            .update_player(node_id, &guild_id.to_string(), &update_player, true)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(())
+    }
+
+    /// Seek the player forward by a positive delta or back by a negative one, in milliseconds, clamping the position to
+    /// the track.
+    pub async fn seek_relative(&self, guild_id: GuildId, delta: i64) -> Result<Option<SeekResult>> {
+        let Some(current) = self.time(guild_id).await? else {
+            return Ok(None);
+        };
+
+        let position = (current.position as i64)
+            .saturating_add(delta)
+            .clamp(0, current.total as i64);
+
+        self.seek(guild_id, Duration::from_millis(position as u64))
+            .await
+    }
+
+    /// Handle the player state received from Lavalink, storing it and recovering the voice connection if it was lost.
+    async fn on_player_update(&self, guild_id: GuildId, state: LavalinkPlayerState) {
+        let Some((voice_recovery, channel_id, locale)) =
+            self.players.get_mut(&guild_id).map(|mut player| {
+                (
+                    player.apply_player_update(state),
+                    player.channel_id,
+                    player.locale.clone(),
+                )
+            })
+        else {
+            return;
+        };
+
+        if voice_recovery == VoiceRecovery::Destroy {
+            event!(
+                Level::WARN,
+                guild_id = ?guild_id,
+                "voice connection is still lost, destroying the player"
+            );
+
+            if let Some(channel_id) = channel_id {
+                if let Err(e) = channel_id
+                    .say(&self.http, t(&locale, "player.voice_lost"))
+                    .await
+                {
+                    event!(Level::INFO, error = %e, guild_id = ?guild_id, "cannot send the voice lost message");
+                }
+            }
+
+            if let Err(e) = self.destroy(guild_id).await {
+                event!(Level::ERROR, error = ?e, guild_id = ?guild_id, "cannot destroy the player");
+            }
+        } else if voice_recovery == VoiceRecovery::Resend {
+            event!(
+                Level::WARN,
+                guild_id = ?guild_id,
+                "Lavalink lost the voice connection, sending the voice state again"
+            );
+
+            let voice = self.get_connection(guild_id).await;
+
+            let Some(node_id) = self.players.view(&guild_id, |_, p| p.node_id) else {
+                return;
+            };
+
+            if let Err(e) = self.update_connection(voice, node_id, guild_id).await {
+                event!(Level::ERROR, error = ?e, guild_id = ?guild_id, "cannot send the voice state again");
+            }
+        }
+    }
+
+    /// Reconcile the local player with the player in the Lavalink node, which is the source of truth for what is playing.
+    ///
+    /// The current track is moved to the track playing in the node when it's in the queue, and the cached state is
+    /// cleared if the node isn't playing anything.
+    pub async fn refresh_from_node(&self, guild_id: GuildId) -> Result<()> {
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        let node_player = self
+            .lavalink
+            .get_player(node_id, &guild_id.to_string())
+            .await
+            .map_err(Error::from)?;
+
+        {
+            let mut player = self
+                .players
+                .get_mut(&guild_id)
+                .ok_or(Error::PlayerNotFound)?;
+
+            let old_track = player.current_track;
+
+            if player.reconcile(node_player) {
+                if player.current_track != old_track {
+                    event!(
+                        Level::DEBUG,
+                        guild_id = ?guild_id,
+                        old_track = old_track,
+                        new_track = player.current_track,
+                        "current track diverged from the node"
+                    );
+                }
+            } else {
+                event!(Level::DEBUG, guild_id = ?guild_id, "the node isn't playing anything");
+            }
+        }
+
+        self.update_message(guild_id).await;
+
+        Ok(())
+    }
+
+    /// Apply a change to the cached Lavalink player state, avoiding to wait for the next player update.
+    fn cache_apply<F: FnOnce(&mut LavalinkPlayerState)>(&self, guild_id: GuildId, mutator: F) {
+        if let Some(mut player) = self.players.get_mut(&guild_id) {
+            if let Some(state) = player.state.as_mut() {
+                mutator(state);
+            }
+        }
+    }
+
+    /// Get the loop mode for the guild.
+    pub fn get_loop_mode(&self, guild_id: GuildId) -> Option<LoopMode> {
+        self.players.view(&guild_id, |_, p| p.loop_mode)
+    }
+
+    /// Set the loop mode for the guild, restarting the queue if it has finished and the new mode would continue it.
+    pub async fn set_loop_mode(&self, guild_id: GuildId, loop_mode: LoopMode) {
+        self.players
+            .alter(&guild_id, |_, p| Player { loop_mode, ..p });
+
+        if let Err(e) = self.restart_finished_queue(guild_id, loop_mode).await {
+            event!(Level::ERROR, error = ?e, guild_id = ?guild_id, "cannot restart the finished queue");
+        }
+
+        self.update_message(guild_id).await;
+    }
+
+    /// Restart the queue if the player has stopped at its last track, using the loop mode to choose what to play.
+    async fn restart_finished_queue(&self, guild_id: GuildId, loop_mode: LoopMode) -> Result<()> {
+        let finished = self
+            .players
+            .view(&guild_id, |_, p| p.can_restart_queue())
+            .unwrap_or(false);
+
+        if !finished || self.is_playing(guild_id).await? {
+            return Ok(());
+        }
+
+        event!(Level::DEBUG, guild_id = ?guild_id, loop_mode = ?loop_mode, "restarting the finished queue");
+
+        if let Some(mut player) = self.players.get_mut(&guild_id) {
+            player.restart_queue();
+        }
+
+        if loop_mode == LoopMode::Autoplay {
+            self.autoplay(guild_id).await?;
+        } else {
+            self.sync(guild_id).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Get the volume for the guild.
+    pub fn get_volume(&self, guild_id: GuildId) -> Option<u16> {
+        self.players.view(&guild_id, |_, p| p.volume)
+    }
+
+    /// Set the volume for the guild, returning the volume applied after clamping it to the range allowed by Lavalink.
+    pub async fn set_volume(&self, guild_id: GuildId, volume: u16) -> Result<u16> {
+        let volume = volume.min(HYDROGEN_MAX_VOLUME);
+
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        let update_player = UpdatePlayer::default().set_volume(volume);
+
+        self.lavalink
+            .update_player(node_id, &guild_id.to_string(), &update_player, true)
+            .await
+            .map_err(Error::from)?;
+
+        self.players.alter(&guild_id, |_, p| Player { volume, ..p });
+
+        Ok(volume)
+    }
+
+    /// Set the queue limit for the guild, returning the limit applied after clamping it to the range allowed.
+    ///
+    /// Tracks already in the queue aren't removed when the new limit is lower than the queue size.
+    pub fn set_queue_limit(&self, guild_id: GuildId, limit: usize) -> Result<usize> {
+        let limit = limit.clamp(1, HYDROGEN_MAX_QUEUE_LIMIT);
+
+        self.players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .queue_limit = limit;
+
+        Ok(limit)
+    }
+
+    /// Set the time to wait before leaving the voice chat when no one else is connected, used the next time the voice
+    /// chat becomes empty.
+    pub fn set_empty_timeout(&self, guild_id: GuildId, timeout: Duration) -> Result<()> {
+        self.players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .empty_timeout = timeout;
+
+        Ok(())
+    }
+
+    /// Set the filters for the guild, replacing all the filters applied before.
+    pub async fn set_filters(&self, guild_id: GuildId, filters: Filters) -> Result<()> {
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        if let Some(filter) = filters
+            .names()
+            .into_iter()
+            .find(|filter| !self.lavalink.supports_filter(node_id, filter))
+        {
+            return Err(Error::UnsupportedFilter(filter));
+        }
+
+        let update_player = UpdatePlayer::default().set_filters(filters.clone());
+
+        self.lavalink
+            .update_player(node_id, &guild_id.to_string(), &update_player, true)
+            .await
+            .map_err(Error::from)?;
+
+        if let Some(mut player) = self.players.get_mut(&guild_id) {
+            player.filters = filters;
+        }
+
+        Ok(())
+    }
+
+    /// Get the pause state for the guild.
+    pub fn get_pause(&self, guild_id: GuildId) -> Option<bool> {
+        self.players.view(&guild_id, |_, p| p.paused)
+    }
+
+    /// Set the pause state for the guild.
+    pub async fn set_pause(&self, guild_id: GuildId, paused: bool) -> Result<bool> {
         let is_playing = self.is_playing(guild_id).await?;
 
         if is_playing {
@@ -604,14 +1244,19 @@ impl PlayerManager {
                 .await
                 .map_err(Error::from)?;
 
+            if let Some(mut player) = self.players.get_mut(&guild_id) {
+                player.cache_pause(paused, unix_millis());
+            }
+
             player_state.paused = paused;
 
-            let (channel_id, message_id) =
+            let (channel_id, message_id, nowplaying_message_id) =
                 update_message(self, guild_id, &player_state, true, false).await;
 
             self.players.alter(&guild_id, |_, p| Player {
                 channel_id,
                 message_id,
+                nowplaying_message_id,
                 paused,
                 ..p
             });
@@ -627,12 +1272,91 @@ impl PlayerManager {
         }
     }
 
+    /// Pause all the players that are playing, returning how many were paused.
+    pub async fn pause_all(&self) -> Result<usize> {
+        self.set_pause_all(true).await
+    }
+
+    /// Resume all the paused players, returning how many were resumed.
+    pub async fn resume_all(&self) -> Result<usize> {
+        self.set_pause_all(false).await
+    }
+
+    /// Set the pause state of all the players that are playing, returning how many were changed.
+    ///
+    /// Up to [HYDROGEN_PAUSE_ALL_CONCURRENCY] players are updated at the same time, the failures are logged without
+    /// stopping the other players from being updated.
+    async fn set_pause_all(&self, paused: bool) -> Result<usize> {
+        let mut guild_ids = self
+            .players
+            .iter()
+            .filter(|p| p.paused != paused)
+            .map(|p| *p.key())
+            .collect::<Vec<_>>()
+            .into_iter();
+
+        let mut tasks = JoinSet::new();
+        let mut changed = 0;
+        let mut failed = 0;
+
+        loop {
+            while tasks.len() < HYDROGEN_PAUSE_ALL_CONCURRENCY {
+                let Some(guild_id) = guild_ids.next() else {
+                    break;
+                };
+
+                let manager = self.clone();
+
+                tasks.spawn(async move {
+                    // Pausing or resuming a player without a track would start the queue again.
+                    let result = match manager.is_playing(guild_id).await {
+                        Ok(true) => manager.set_pause(guild_id, paused).await.map(|_| true),
+                        other => other,
+                    };
+
+                    (guild_id, result)
+                });
+            }
+
+            let Some(result) = tasks.join_next().await else {
+                break;
+            };
+
+            match result {
+                Ok((_, Ok(true))) => changed += 1,
+                Ok((_, Ok(false))) => {}
+                Ok((guild_id, Err(e))) => {
+                    failed += 1;
+                    event!(Level::WARN, error = ?e, guild_id = %guild_id, paused = paused, "cannot set the pause state of the player");
+                }
+                Err(e) => {
+                    failed += 1;
+                    event!(Level::ERROR, error = %e, paused = paused, "the task setting the pause state has failed");
+                }
+            }
+        }
+
+        event!(
+            Level::INFO,
+            changed = changed,
+            failed = failed,
+            paused = paused,
+            "pause state of all the players set"
+        );
+
+        Ok(changed)
+    }
+
     /// Go to the previous track in the queue.
     pub async fn previous(&self, guild_id: GuildId) -> Result<Option<Track>> {
         let mut player = self
             .players
             .get_mut(&guild_id)
-            .ok_or(Error::InvalidGuildId)?;
+            .ok_or(Error::PlayerNotFound)?;
+
+        if player.queue.is_empty() {
+            return Ok(None);
+        }
 
         player.current_track = if player.current_track > 0 {
             player.current_track - 1
@@ -649,59 +1373,394 @@ impl PlayerManager {
         Ok(current_track)
     }
 
-    /// Go to the next track in the queue.
+    /// Go to the next track in the queue, using autoplay to find it when skipping the last track with
+    /// [LoopMode::Autoplay].
     pub async fn skip(&self, guild_id: GuildId) -> Result<Option<Track>> {
+        if self.should_autoplay(guild_id) && self.autoplay(guild_id).await? {
+            return Ok(self
+                .players
+                .view(&guild_id, |_, p| p.queue.get(p.current_track).cloned())
+                .flatten());
+        }
+
         let mut player = self
             .players
             .get_mut(&guild_id)
-            .ok_or(Error::InvalidGuildId)?;
+            .ok_or(Error::PlayerNotFound)?;
 
-        player.current_track = (player.current_track + 1) % player.queue.len();
+        let update = player.skip();
 
         let current_track = player.queue.get(player.current_track).cloned();
 
         drop(player);
 
-        self.sync(guild_id).await?;
+        match update {
+            None => Ok(None),
+            Some(QueueUpdate::Stop) => {
+                self.stop_track(guild_id).await?;
+
+                Ok(None)
+            }
+            Some(update) => {
+                self.apply_queue_update(guild_id, update).await?;
+
+                Ok(current_track)
+            }
+        }
+    }
+
+    /// Jump to the track at the index in the queue, playing it immediately.
+    pub async fn jump(&self, guild_id: GuildId, index: usize) -> Result<SyncResult> {
+        let queue_length = self
+            .players
+            .view(&guild_id, |_, p| p.queue.len())
+            .ok_or(Error::PlayerNotFound)?;
+
+        if index >= queue_length {
+            return Err(Error::IndexOutOfBounds);
+        }
+
+        self.forced_update_sync(guild_id, index).await
+    }
+
+    /// Remove the track at the index from the queue, returning it or `None` if the index is out of bounds.
+    ///
+    /// The current track keeps playing if another track is removed, and removing the current track plays the next one.
+    pub async fn remove_track(&self, guild_id: GuildId, index: usize) -> Result<Option<Track>> {
+        let Some((track, update)) = self
+            .players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .remove_track(index)
+        else {
+            return Ok(None);
+        };
+
+        self.apply_queue_update(guild_id, update).await?;
+
+        Ok(Some(track))
+    }
+
+    /// Update the player message, play the current track or stop it, as required by the change in the queue.
+    async fn apply_queue_update(&self, guild_id: GuildId, update: QueueUpdate) -> Result<()> {
+        match update {
+            QueueUpdate::Message => {
+                self.update_message(guild_id).await;
+                Ok(())
+            }
+            QueueUpdate::Sync => self.sync(guild_id).await.map(|_| ()),
+            QueueUpdate::Stop => self.stop_track(guild_id).await,
+        }
+    }
+
+    /// Remove the tracks in the range `[start, end)` from the queue, returning how many tracks were removed.
+    ///
+    /// If the current track is removed, the first track after the range starts playing.
+    pub async fn remove_range(&self, guild_id: GuildId, start: usize, end: usize) -> Result<usize> {
+        let (count, update) = self
+            .players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .remove_range(start, end)
+            .ok_or(Error::IndexOutOfBounds)?;
+
+        if count > 0 {
+            self.apply_queue_update(guild_id, update).await?;
+        }
+
+        Ok(count)
+    }
+
+    /// Remove all the tracks from the queue except the current one, returning how many tracks were removed.
+    ///
+    /// The current track keeps playing, and with the loop of the queue, it's the only track played again.
+    pub async fn clear_queue(&self, guild_id: GuildId) -> Result<usize> {
+        let mut player = self
+            .players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        let queue_length = player.queue.len();
+        let current_track = player.current_track;
+
+        let queue = mem::take(&mut player.queue);
+        player.queue = queue.into_iter().nth(current_track).into_iter().collect();
+        player.current_track = 0;
+
+        let removed = queue_length - player.queue.len();
+
+        drop(player);
+
+        self.update_message(guild_id).await;
+
+        Ok(removed)
+    }
+
+    /// Move the track from an index to another in the queue, keeping the current track pointing to the same track.
+    pub async fn move_track(&self, guild_id: GuildId, from: usize, to: usize) -> Result<()> {
+        if !self
+            .players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .move_track(from, to)
+        {
+            return Err(Error::IndexOutOfBounds);
+        }
+
+        self.update_message(guild_id).await;
+
+        Ok(())
+    }
+
+    /// Stop the track playing in the player, keeping the queue.
+    async fn stop_track(&self, guild_id: GuildId) -> Result<()> {
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        let update_player =
+            UpdatePlayer::default().set_track(UpdatePlayerTrack::default().set_stop_player());
+
+        self.lavalink
+            .update_player(node_id, &guild_id.to_string(), &update_player, false)
+            .await
+            .map_err(Error::from)?;
+
+        self.update_message(guild_id).await;
+
+        Ok(())
+    }
+
+    /// Starts the player, requesting the Lavalink node to play the music.
+    async fn sync(&self, guild_id: GuildId) -> Result<bool> {
+        self.sync_from(guild_id, 0).await
+    }
+
+    /// Starts the player from a position in milliseconds, requesting the Lavalink node to play the music.
+    async fn sync_from(&self, guild_id: GuildId, position: u64) -> Result<bool> {
+        let player_state = self
+            .players
+            .view(&guild_id, |_, p| {
+                let track = p.queue.get(p.current_track)?;
+
+                Some((
+                    p.start_update(position)?,
+                    track.track.clone(),
+                    track
+                        .url
+                        .clone()
+                        .unwrap_or_else(|| track.identifier.clone()),
+                    p.node_id,
+                    p.should_reset_filters(),
+                ))
+            })
+            .flatten();
+
+        if let Some((mut update_player, encoded, identifier, node_id, reset_filters)) = player_state
+        {
+            update_player.voice = self.get_connection(guild_id).await;
+
+            match self
+                .lavalink
+                .update_player(node_id, &guild_id.to_string(), &update_player, false)
+                .await
+            {
+                Ok(_) => {}
+                // The encoded track can become stale, so the track is loaded again from its identifier.
+                Err(hydrolink::Error::Lavalink(e)) if e.is_track_decode_error() => {
+                    event!(
+                        Level::DEBUG,
+                        guild_id = ?guild_id,
+                        error = ?e,
+                        "cannot play the encoded track, retrying with its identifier"
+                    );
+
+                    update_player = update_player
+                        .set_track(UpdatePlayerTrack::default().set_identifier(&identifier));
+
+                    let node_player = self
+                        .lavalink
+                        .update_player(node_id, &guild_id.to_string(), &update_player, false)
+                        .await
+                        .map_err(Error::from)?;
+
+                    // Keep the track events and the node reconciliation finding the track by its new encoded string.
+                    if let Some(track) = node_player.track {
+                        if let Some(mut player) = self.players.get_mut(&guild_id) {
+                            player.replace_encoded(&encoded, track.encoded);
+                        }
+                    }
+                }
+                Err(e) => return Err(Error::from(e)),
+            }
+
+            if reset_filters {
+                if let Some(mut player) = self.players.get_mut(&guild_id) {
+                    player.filters = Filters::default();
+                }
+            }
+
+            self.cache_apply(guild_id, |state| {
+                state.position = position;
+                state.time = unix_millis();
+            });
+
+            event!(
+                Level::DEBUG,
+                guild_id = ?guild_id,
+                "player started"
+            );
+
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+
+    /// Move the player to another node, recreating it there from the current track and its estimated position.
+    ///
+    /// The position is estimated from the last player state received from Lavalink, as the old node may be unreachable.
+    pub async fn failover(&self, guild_id: GuildId, node_id: usize) -> Result<bool> {
+        let position = self
+            .players
+            .get_mut(&guild_id)
+            .map(|mut player| {
+                player.node_id = node_id;
+                player.position().unwrap_or_default()
+            })
+            .ok_or(Error::PlayerNotFound)?;
+
+        self.sync_from(guild_id, position).await
+    }
+
+    /// Move the players from a disconnected node to another one.
+    ///
+    /// The players are migrated with the state stored in the old node when its REST API is still reachable, the ones
+    /// that can't be migrated are recreated from the last state received from Lavalink, like in [Self::failover].
+    pub async fn migrate_node(&self, from: usize, to: usize) {
+        let guild_ids = self
+            .players
+            .iter()
+            .filter(|player| player.node_id == from)
+            .map(|player| *player.key())
+            .collect::<Vec<_>>();
+
+        let migrated = match timeout(
+            HYDROGEN_MIGRATION_TIMEOUT,
+            self.lavalink.migrate_players(from, to),
+        )
+        .await
+        {
+            Ok(Ok(results)) => results
+                .into_iter()
+                .filter(|(_, result)| result.is_ok())
+                .filter_map(|(guild_id, _)| guild_id.parse::<GuildId>().ok())
+                .collect::<HashSet<_>>(),
+            Ok(Err(e)) => {
+                event!(Level::WARN, error = ?e, node_id = from, "cannot migrate the players, recreating them");
+                HashSet::new()
+            }
+            Err(_) => {
+                event!(
+                    Level::WARN,
+                    node_id = from,
+                    "migrating the players took too long, recreating them"
+                );
+                HashSet::new()
+            }
+        };
+
+        for guild_id in guild_ids {
+            let result = if migrated.contains(&guild_id) {
+                self.players
+                    .get_mut(&guild_id)
+                    .map(|mut player| player.node_id = to)
+                    .ok_or(Error::PlayerNotFound)
+            } else {
+                self.failover(guild_id, to).await.map(|_| ())
+            };
 
-        Ok(current_track)
+            if let Err(e) = result {
+                event!(
+                    Level::ERROR,
+                    guild_id = %guild_id,
+                    error = ?e,
+                    "failed to restart player"
+                );
+            } else if let Err(e) = self.refresh_from_node(guild_id).await {
+                event!(
+                    Level::WARN,
+                    guild_id = %guild_id,
+                    error = ?e,
+                    "failed to refresh the player from the new node"
+                );
+            }
+        }
     }
 
-    /// Starts the player, requesting the Lavalink node to play the music.
-    async fn sync(&self, guild_id: GuildId) -> Result<bool> {
-        let player_state = self
+    /// Re-establish the voice connection, leaving and joining the voice channel again and resuming the current track at
+    /// its last known position.
+    pub async fn reconnect_voice(&self, guild_id: GuildId) -> Result<bool> {
+        let channel_id = self
+            .get_voice_channel_id(guild_id)
+            .await
+            .ok_or(Error::PlayerNotFound)?;
+
+        let position = self
             .players
-            .view(&guild_id, |_, p| {
-                p.queue
-                    .get(p.current_track)
-                    .map(|t| (t.track.clone(), p.paused, p.node_id))
+            .get_mut(&guild_id)
+            .map(|mut player| {
+                player.voice_reconnecting = true;
+                player.position().unwrap_or_default()
             })
-            .flatten();
+            .ok_or(Error::PlayerNotFound)?;
 
-        if let Some((song, paused, node_id)) = player_state {
-            let voice = self.get_connection(guild_id).await;
+        let result = self.rejoin(guild_id, channel_id).await;
 
-            let update_player = UpdatePlayer {
-                voice,
-                ..Default::default()
-            }
-            .set_track(UpdatePlayerTrack::default().set_encoded(&song))
-            .set_paused(paused);
+        if let Some(mut player) = self.players.get_mut(&guild_id) {
+            player.voice_reconnecting = false;
+        }
 
-            self.lavalink
-                .update_player(node_id, &guild_id.to_string(), &update_player, false)
-                .await
-                .map_err(Error::from)?;
+        result?;
 
-            event!(
-                Level::DEBUG,
-                guild_id = ?guild_id,
-                "player started"
-            );
+        self.sync_from(guild_id, position).await
+    }
 
-            Ok(true)
+    /// Leave the voice channel and join it again, sending the new voice state to Lavalink.
+    async fn rejoin(&self, guild_id: GuildId, channel_id: ChannelId) -> Result<()> {
+        if let Some(call) = self.songbird.get(guild_id) {
+            call.lock().await.leave().await.map_err(Error::from)?;
+        }
+
+        self.move_to(guild_id, channel_id).await
+    }
+
+    /// Count the members connected to the voice chat, returning `None` if the channel isn't a voice chat.
+    fn voice_member_count(
+        &self,
+        guild_id: GuildId,
+        channel_id: ChannelId,
+    ) -> Result<Option<usize>> {
+        let cache_ref = self
+            .cache
+            .guild(guild_id)
+            .ok_or(Error::GuildChannelNotFound)?;
+
+        let channel = cache_ref
+            .channels
+            .get(&channel_id)
+            .ok_or(Error::GuildChannelNotFound)?;
+
+        if channel.kind == ChannelType::Voice || channel.kind == ChannelType::Stage {
+            let members_len = channel
+                .members(self.cache.as_ref())
+                .map_err(Error::from)?
+                .len();
+
+            Ok(Some(members_len))
         } else {
-            Ok(false)
+            Ok(None)
         }
     }
 
@@ -728,6 +1787,12 @@ impl PlayerManager {
                     self.update_connection(voice, player_state.node_id, guild_id)
                         .await?;
                 }
+            } else if self
+                .players
+                .view(&guild_id, |_, p| p.voice_reconnecting)
+                .unwrap_or(false)
+            {
+                return Ok(false);
             } else {
                 self.destroy(guild_id).await?;
                 return Ok(true);
@@ -741,40 +1806,23 @@ impl PlayerManager {
         };
 
         if let Some(channel_id) = voice_channel_id {
-            let member_count = {
-                let cache_ref = self
-                    .cache
-                    .guild(guild_id)
-                    .ok_or(Error::GuildChannelNotFound)?;
-
-                let channel = cache_ref
-                    .channels
-                    .get(&channel_id)
-                    .ok_or(Error::GuildChannelNotFound)?;
-
-                if channel.kind == ChannelType::Voice || channel.kind == ChannelType::Stage {
-                    let members_len = channel
-                        .members(self.cache.as_ref())
-                        .map_err(Error::from)?
-                        .len();
-
-                    Some(members_len)
-                } else {
-                    None
-                }
-            };
+            if let Some(members_count) = self.voice_member_count(guild_id, channel_id)? {
+                let thinking =
+                    if members_count <= 1 && !player_state.as_ref().is_some_and(|p| p.stay) {
+                        let empty_timeout = player_state
+                            .as_ref()
+                            .map_or(Duration::from_secs(HYDROGEN_EMPTY_CHAT_TIMEOUT), |p| {
+                                p.empty_timeout
+                            });
 
-            if let Some(members_count) = member_count {
-                let thinking = if members_count <= 1 {
-                    self.timed_destroy(guild_id, Duration::from_secs(HYDROGEN_EMPTY_CHAT_TIMEOUT))
-                        .await;
+                        self.timed_destroy(guild_id, empty_timeout).await;
 
-                    true
-                } else {
-                    self.cancel_destroy(guild_id);
+                        true
+                    } else {
+                        self.cancel_destroy(guild_id);
 
-                    false
-                };
+                        false
+                    };
 
                 let new_player_state = self.get_player_state(guild_id);
 
@@ -784,12 +1832,13 @@ impl PlayerManager {
                         .await
                         .unwrap_or(true);
 
-                    let (channel_id, message_id) =
+                    let (channel_id, message_id, nowplaying_message_id) =
                         update_message(self, guild_id, &player_state, is_playing, thinking).await;
 
                     self.players.alter(&guild_id, |_, p| Player {
                         channel_id,
                         message_id,
+                        nowplaying_message_id,
                         ..p
                     });
                 }
@@ -842,12 +1891,65 @@ impl PlayerManager {
         Ok(())
     }
 
+    /// Move the player to another voice channel, keeping the playback.
+    ///
+    /// Nothing is done if the player is already in this voice channel.
+    pub async fn move_to(&self, guild_id: GuildId, channel_id: ChannelId) -> Result<()> {
+        let node_id = self
+            .players
+            .view(&guild_id, |_, p| p.node_id)
+            .ok_or(Error::PlayerNotFound)?;
+
+        if self.get_voice_channel_id(guild_id).await == Some(channel_id) {
+            return Ok(());
+        }
+
+        let (connection, _) = self
+            .songbird
+            .join_gateway(guild_id, channel_id)
+            .await
+            .map_err(Error::from)?;
+
+        let voice = VoiceState::new(
+            &connection.token,
+            &connection.endpoint,
+            &connection.session_id,
+        );
+
+        self.update_connection(Some(voice), node_id, guild_id).await
+    }
+
     /// Destroy the player, stopping the music and leaving the voice channel.
     pub async fn destroy(&self, guild_id: GuildId) -> Result<()> {
         let Some((_, player)) = self.players.remove(&guild_id) else {
             return Ok(());
         };
 
+        if let Some(destroy_handle) = player.destroy_handle {
+            destroy_handle.abort();
+        }
+
+        if let Some(nowplaying_handle) = player.nowplaying_handle {
+            nowplaying_handle.abort();
+        }
+
+        // The player messages are always sent by the bot, so deleting them doesn't need any permission.
+        if let Some(text_channel) = player.channel_id {
+            let reason = t(&player.locale, "player.deleted_reason");
+
+            delete_player_messages(
+                guild_id,
+                [player.nowplaying_message_id, player.message_id]
+                    .into_iter()
+                    .flatten(),
+                |message_id| {
+                    self.http
+                        .delete_message(text_channel, message_id, Some(reason))
+                },
+            )
+            .await;
+        }
+
         self.songbird.leave(guild_id).await.map_err(Error::from)?;
 
         self.lavalink
@@ -855,21 +1957,6 @@ impl PlayerManager {
             .await
             .map_err(Error::from)?;
 
-        if let Some((message_id, text_channel)) = player.message_id.zip(player.channel_id) {
-            self.http
-                .delete_message(
-                    text_channel,
-                    message_id,
-                    Some("Message auto-deleted by timeout."),
-                )
-                .await
-                .map_err(Error::from)?;
-        }
-
-        if let Some(destroy_handle) = player.destroy_handle {
-            destroy_handle.abort();
-        }
-
         Ok(())
     }
 
@@ -900,17 +1987,11 @@ impl PlayerManager {
         });
     }
 
-    /// Check if the player exists, is playing the last track, and is with
+    /// Check if the player exists, is playing the last track, and is with the [LoopMode::Autoplay] loop mode.
     fn should_autoplay(&self, guild_id: GuildId) -> bool {
-        let Some(player) = self.players.get(&guild_id) else {
-            return false;
-        };
-
-        if player.current_track + 1 >= player.queue.len() {
-            matches!(player.loop_mode, LoopMode::Autoplay)
-        } else {
-            false
-        }
+        self.players
+            .view(&guild_id, |_, p| p.should_autoplay())
+            .unwrap_or(false)
     }
 
     /// Uses the player's loop mode to determine the next track to play.
@@ -919,48 +2000,22 @@ impl PlayerManager {
             return Ok(());
         }
 
-        let Some(mut player) = self.players.get_mut(&guild_id) else {
+        let Some(update) = self
+            .players
+            .get_mut(&guild_id)
+            .and_then(|mut p| p.next_track())
+        else {
             return Ok(());
         };
 
-        let (new_index, should_pause, need_sync) = match player.loop_mode {
-            LoopMode::Single => (player.current_track, false, true),
-            LoopMode::All => ((player.current_track + 1) % player.queue.len(), false, true),
-            LoopMode::AutoPause => {
-                if player.current_track + 1 >= player.queue.len() {
-                    (player.queue.len() - 1, true, false)
-                } else {
-                    (player.current_track + 1, true, false)
-                }
-            }
-            _ => {
-                if player.current_track + 1 >= player.queue.len() {
-                    (player.queue.len() - 1, false, false)
-                } else {
-                    (player.current_track + 1, false, true)
-                }
-            }
-        };
-
-        player.current_track = new_index;
-        player.paused = should_pause;
-
-        drop(player);
-
-        if need_sync {
-            self.sync(guild_id).await?;
-        } else {
-            self.update_message(guild_id).await;
-        }
-
-        Ok(())
+        self.apply_queue_update(guild_id, update).await
     }
 
     /// Autoplay the next track using YouTube Mix, returning `true` if it was successful.
     async fn autoplay(&self, guild_id: GuildId) -> Result<bool> {
         if self
             .players
-            .view(&guild_id, |_, p| p.queue.len() >= HYDROGEN_QUEUE_LIMIT)
+            .view(&guild_id, |_, p| p.queue.len() >= p.queue_limit)
             .unwrap_or(true)
         {
             return Ok(false);
@@ -984,8 +2039,13 @@ impl PlayerManager {
             tracks: vec![track],
         };
 
-        let add_queue_result =
-            self.add_queue(guild_id, fetch_result, self.user_id, AddQueueOperation::End)?;
+        let add_queue_result = self.add_queue(
+            guild_id,
+            fetch_result,
+            self.user_id,
+            AddQueueOperation::End,
+            false,
+        )?;
 
         self.forced_update_sync(
             guild_id,
@@ -1008,37 +2068,270 @@ impl PlayerManager {
                 .await
                 .unwrap_or(true);
 
-            let (channel_id, message_id) =
+            let (channel_id, message_id, nowplaying_message_id) =
                 update_message(self, guild_id, &player_state, is_playing, false).await;
 
             self.players.alter(&guild_id, |_, p| Player {
                 channel_id,
                 message_id,
+                nowplaying_message_id,
                 ..p
             });
+
+            self.start_split_view_updater(guild_id);
+        }
+    }
+
+    /// Start the task updating the progress in the now-playing message, if the split view is enabled and no other task
+    /// is updating a now-playing message.
+    fn start_split_view_updater(&self, guild_id: GuildId) {
+        let Some(mut player) = self.players.get_mut(&guild_id) else {
+            return;
+        };
+
+        if !player.needs_split_view_updater() {
+            return;
+        }
+
+        let manager = self.clone();
+
+        player.nowplaying_handle = Some(tokio::spawn(async move {
+            loop {
+                sleep(HYDROGEN_SPLIT_VIEW_UPDATE_INTERVAL).await;
+
+                let Some(player_state) = manager.get_player_state(guild_id) else {
+                    break;
+                };
+
+                if !player_state.split_view {
+                    break;
+                }
+
+                if !player_state.paused && player_state.track.is_some() {
+                    update_nowplaying_message(&manager, guild_id, &player_state).await;
+                }
+            }
+        }));
+    }
+
+    /// Toggle the split view for the player, re-sending the player messages.
+    pub async fn toggle_split_view(&self, guild_id: GuildId) -> Result<bool> {
+        let (split_view, channel_id, message_ids) = {
+            let mut player = self
+                .players
+                .get_mut(&guild_id)
+                .ok_or(Error::PlayerNotFound)?;
+
+            player.split_view = !player.split_view;
+
+            (
+                player.split_view,
+                player.channel_id,
+                [
+                    player.nowplaying_message_id.take(),
+                    player.message_id.take(),
+                ],
+            )
+        };
+
+        if let Some(channel_id) = channel_id {
+            for message_id in message_ids.into_iter().flatten() {
+                if let Err(e) = self.http.delete_message(channel_id, message_id, None).await {
+                    event!(
+                        Level::INFO,
+                        error = %e,
+                        guild_id = ?guild_id,
+                        "cannot delete the old player message"
+                    );
+                }
+            }
+        }
+
+        self.update_message(guild_id).await;
+
+        Ok(split_view)
+    }
+
+    /// Set the task updating the live now-playing message, aborting the previous one.
+    pub fn set_nowplaying_handle(&self, guild_id: GuildId, handle: JoinHandle<()>) {
+        let Some(mut player) = self.players.get_mut(&guild_id) else {
+            handle.abort();
+            return;
+        };
+
+        if let Some(old_handle) = player.nowplaying_handle.replace(handle) {
+            old_handle.abort();
         }
     }
 
-    /// Shuffle the player's queue.
-    pub fn shuffle(&self, guild_id: GuildId) -> Result<()> {
+    /// Toggle if the filters are kept when a new track starts.
+    pub fn toggle_filters_sticky(&self, guild_id: GuildId) -> Result<bool> {
         let mut player = self
             .players
             .get_mut(&guild_id)
             .ok_or(Error::PlayerNotFound)?;
 
-        let old_index = player.current_track;
+        player.filters_sticky = !player.filters_sticky;
 
-        let current_track = player.queue.swap_remove(old_index);
+        Ok(player.filters_sticky)
+    }
+
+    /// Toggle if tracks with explicit content are rejected when adding them to the queue.
+    pub fn toggle_block_explicit(&self, guild_id: GuildId) -> Result<bool> {
+        let mut player = self
+            .players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?;
 
-        player.queue.shuffle(&mut rand::rng());
+        player.block_explicit = !player.block_explicit;
 
-        player.queue.insert(0, current_track);
+        Ok(player.block_explicit)
+    }
 
-        player.current_track = 0;
+    /// Vote to skip the current track, skipping it when enough listeners voted.
+    ///
+    /// The requester of the current track, a user alone with the player or any user when voting is disabled skips the
+    /// track immediately.
+    pub async fn vote_skip(
+        &self,
+        guild_id: GuildId,
+        user: UserId,
+        listeners: usize,
+    ) -> Result<VoteSkipOutcome> {
+        if let Some(percentage) = self.vote_skip {
+            let mut player = self
+                .players
+                .get_mut(&guild_id)
+                .ok_or(Error::PlayerNotFound)?;
+
+            let is_requester = player
+                .queue
+                .get(player.current_track)
+                .is_some_and(|t| t.requester == user);
+
+            if !is_requester && listeners > 1 {
+                player.skip_votes.insert(user);
+
+                let votes = player.skip_votes.len();
+                let required = (listeners * percentage as usize / 100 + 1).min(listeners);
+
+                if votes < required {
+                    return Ok(VoteSkipOutcome::Voted { votes, required });
+                }
+            }
+        }
+
+        self.skip(guild_id)
+            .await
+            .map(|t| VoteSkipOutcome::Skipped(t.map(Box::new)))
+    }
+
+    /// Clear the votes to skip the current track.
+    fn clear_skip_votes(&self, guild_id: GuildId) {
+        if let Some(mut player) = self.players.get_mut(&guild_id) {
+            player.skip_votes.clear();
+        }
+    }
+
+    /// Send a message to the player's text channel about the exception thrown by the track.
+    ///
+    /// Exceptions thrown within [HYDROGEN_TRACK_EXCEPTION_COOLDOWN] of the last message are ignored, so a failing playlist doesn't spam the channel.
+    async fn report_track_exception(&self, guild_id: GuildId, exception: &Exception) {
+        let Some((channel_id, locale)) = self.players.get_mut(&guild_id).and_then(|mut p| {
+            if p.last_exception
+                .is_some_and(|v| v.elapsed() < HYDROGEN_TRACK_EXCEPTION_COOLDOWN)
+            {
+                return None;
+            }
+
+            p.last_exception = Some(Instant::now());
+            p.channel_id.map(|c| (c, p.locale.clone()))
+        }) else {
+            return;
+        };
+
+        let severity = match exception.severity {
+            Severity::Common => t(&locale, "error.severity_common"),
+            Severity::Suspicious => t(&locale, "error.severity_suspicious"),
+            Severity::Fault => t(&locale, "error.severity_fault"),
+        };
+
+        let message = exception.message.as_deref().unwrap_or(&exception.cause);
+
+        if let Err(e) = channel_id
+            .say(
+                &self.http,
+                t_vars(&locale, "error.track_exception", [message, severity]).as_ref(),
+            )
+            .await
+        {
+            event!(Level::INFO, error = %e, guild_id = ?guild_id, "cannot send the track exception message");
+        }
+    }
+
+    /// Set if the player is destroyed when the current track ends, instead of playing the next one.
+    pub fn set_stop_after_current(&self, guild_id: GuildId, stop: bool) -> Result<()> {
+        self.players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .stop_after_current = stop;
+
+        Ok(())
+    }
+
+    /// Check if the player should be destroyed after the current track, resetting the flag.
+    fn take_stop_after_current(&self, guild_id: GuildId) -> bool {
+        self.players
+            .get_mut(&guild_id)
+            .is_some_and(|mut p| mem::take(&mut p.stop_after_current))
+    }
+
+    /// Set if the player stays in the voice chat even when no one else is connected, cancelling any scheduled destroy
+    /// when enabled, and scheduling it when disabled while no one else is connected.
+    pub async fn set_stay(&self, guild_id: GuildId, stay: bool) -> Result<()> {
+        let empty_timeout = {
+            let mut player = self
+                .players
+                .get_mut(&guild_id)
+                .ok_or(Error::PlayerNotFound)?;
+
+            player.stay = stay;
+            player.empty_timeout
+        };
+
+        if stay {
+            self.cancel_destroy(guild_id);
+        } else if let Some(channel_id) = self.get_voice_channel_id(guild_id).await {
+            if matches!(self.voice_member_count(guild_id, channel_id), Ok(Some(count)) if count <= 1)
+            {
+                self.timed_destroy(guild_id, empty_timeout).await;
+            }
+        }
+
+        self.update_message(guild_id).await;
 
         Ok(())
     }
 
+    /// Shuffle the player's queue, returning `false` if the queue is empty.
+    pub fn shuffle(&self, guild_id: GuildId) -> Result<bool> {
+        Ok(self
+            .players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .shuffle())
+    }
+
+    /// Shuffle only the tracks after the current one, keeping the played tracks and the current track in place,
+    /// returning `false` if the queue is empty.
+    pub fn shuffle_remaining(&self, guild_id: GuildId) -> Result<bool> {
+        Ok(self
+            .players
+            .get_mut(&guild_id)
+            .ok_or(Error::PlayerNotFound)?
+            .shuffle_remaining())
+    }
+
     /// Convert the load result to the identifier of the first track.
     fn get_identifier(&self, load_result: LoadResult) -> Option<String> {
         match load_result {
@@ -1058,7 +2351,10 @@ impl PlayerManager {
             .search_connected_node()
             .ok_or(Error::NoAvailableLavalink)?;
 
-        let node = &self.lavalink.nodes()[node_id];
+        let node = self
+            .lavalink
+            .node(node_id)
+            .ok_or(Error::NoAvailableLavalink)?;
 
         let result = node
             .load_track(&format!("ytsearch:{}", query))
@@ -1075,7 +2371,10 @@ impl PlayerManager {
             .search_connected_node()
             .ok_or(Error::NoAvailableLavalink)?;
 
-        let node = &self.lavalink.nodes()[node_id];
+        let node = self
+            .lavalink
+            .node(node_id)
+            .ok_or(Error::NoAvailableLavalink)?;
 
         let result = node
             .load_track(&format!("ytsearch:\"{}\"", isrc))
@@ -1156,7 +2455,10 @@ impl PlayerManager {
             .search_connected_node()
             .ok_or(Error::NoAvailableLavalink)?;
 
-        let node = &self.lavalink.nodes()[node_id];
+        let node = self
+            .lavalink
+            .node(node_id)
+            .ok_or(Error::NoAvailableLavalink)?;
 
         let result = node
             .load_track(&format!(
@@ -1273,6 +2575,71 @@ impl CacheHttp for PlayerManager {
     }
 }
 
+/// Get the source manager required by a link, or [None] if it isn't a link from a known source.
+fn url_source(query: &str) -> Option<&'static str> {
+    let host = query
+        .strip_prefix("https://")
+        .or_else(|| query.strip_prefix("http://"))?
+        .split(['/', '?', '#', ':'])
+        .next()?
+        .to_lowercase();
+
+    HYDROGEN_URL_SOURCES
+        .iter()
+        .find(|(domain, _)| {
+            host == *domain
+                || host
+                    .strip_suffix(domain)
+                    .is_some_and(|prefix| prefix.ends_with('.'))
+        })
+        .map(|(_, source)| *source)
+}
+
+/// Get the current Unix timestamp in milliseconds.
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Check if Songbird has both the voice connection info and the voice channel of a call.
+fn is_connected(connection: Option<&ConnectionInfo>, channel_id: Option<VoiceChannelId>) -> bool {
+    connection.is_some() && channel_id.is_some()
+}
+
+/// Delete the player messages, returning how many were deleted.
+///
+/// The channel may not be accessible anymore, so the failures are only logged, never stopping the other messages from
+/// being deleted or the player from being destroyed.
+async fn delete_player_messages<F, Fut>(
+    guild_id: GuildId,
+    message_ids: impl IntoIterator<Item = MessageId>,
+    mut delete: F,
+) -> usize
+where
+    F: FnMut(MessageId) -> Fut,
+    Fut: Future<Output = serenity::Result<()>>,
+{
+    let mut deleted = 0;
+
+    for message_id in message_ids {
+        match delete(message_id).await {
+            Ok(()) => deleted += 1,
+            Err(e) => {
+                event!(
+                    Level::INFO,
+                    error = %e,
+                    guild_id = ?guild_id,
+                    "cannot delete the player message"
+                );
+            }
+        }
+    }
+
+    deleted
+}
+
 /// Result type for the player manager.
 pub type Result<T> = StdResult<T, Error>;
 
@@ -1293,6 +2660,18 @@ pub enum Error {
     GuildChannelNotFound,
     /// There's no player for the guild.
     PlayerNotFound,
+    /// The current track doesn't allow seeking.
+    TrackNotSeekable,
+    /// The player couldn't start and was destroyed, as it was created only for this request.
+    StartFailed(Box<Error>),
+    /// The index is out of the bounds of the queue.
+    IndexOutOfBounds,
+    /// The source of the track failed to load it.
+    LoadFailed(Exception),
+    /// The Lavalink node doesn't have the source manager required by the link.
+    UnsupportedSource(&'static str),
+    /// The Lavalink node doesn't have a filter enabled.
+    UnsupportedFilter(&'static str),
 }
 
 impl Display for Error {
@@ -1305,6 +2684,28 @@ impl Display for Error {
             Self::InvalidGuildId => write!(f, "Invalid guild ID"),
             Self::GuildChannelNotFound => write!(f, "Guild channel was not found"),
             Self::PlayerNotFound => write!(f, "Player not found"),
+            Self::TrackNotSeekable => write!(f, "The current track can't be seeked"),
+            Self::StartFailed(e) => write!(f, "Cannot start the player: {}", e),
+            Self::IndexOutOfBounds => write!(f, "The index is out of the bounds of the queue"),
+            Self::LoadFailed(e) => write!(
+                f,
+                "Cannot load the track: {}",
+                e.message.as_deref().unwrap_or(&e.cause)
+            ),
+            Self::UnsupportedSource(source) => {
+                write!(
+                    f,
+                    "The Lavalink node doesn't support the source: {}",
+                    source
+                )
+            }
+            Self::UnsupportedFilter(filter) => {
+                write!(
+                    f,
+                    "The Lavalink node doesn't support the filter: {}",
+                    filter
+                )
+            }
         }
     }
 }
@@ -1328,3 +2729,53 @@ impl From<serenity::Error> for Error {
 }
 
 impl StdError for Error {}
+
+#[cfg(test)]
+mod tests {
+    use songbird::id::{GuildId as VoiceGuildId, UserId as VoiceUserId};
+
+    use super::*;
+
+    #[test]
+    fn connection_needs_info_and_channel() {
+        let connection = ConnectionInfo {
+            channel_id: None,
+            endpoint: "voice.discord.media".to_owned(),
+            guild_id: VoiceGuildId::from(GuildId::new(1)),
+            session_id: "session".to_owned(),
+            token: "token".to_owned(),
+            user_id: VoiceUserId::from(UserId::new(2)),
+        };
+        let channel_id = Some(VoiceChannelId::from(ChannelId::new(3)));
+
+        assert!(is_connected(Some(&connection), channel_id));
+        assert!(!is_connected(Some(&connection), None));
+        assert!(!is_connected(None, channel_id));
+        assert!(!is_connected(None, None));
+    }
+
+    #[tokio::test]
+    async fn failed_message_deletion_continues() {
+        let mut attempts = Vec::new();
+
+        let deleted = delete_player_messages(
+            GuildId::new(1),
+            [MessageId::new(2), MessageId::new(3)],
+            |message_id| {
+                attempts.push(message_id);
+
+                async move {
+                    if message_id == MessageId::new(2) {
+                        Err(serenity::Error::Other("missing access"))
+                    } else {
+                        Ok(())
+                    }
+                }
+            },
+        )
+        .await;
+
+        assert_eq!(deleted, 1);
+        assert_eq!(attempts, [MessageId::new(2), MessageId::new(3)]);
+    }
+}
@@ -1,18 +1,39 @@
 //! Player information and structures.
 
-use std::fmt::{self, Display, Formatter};
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter},
+    time::{Duration, Instant},
+};
 
-use hydrolink::Track as LavalinkTrack;
+use hydrolink::{
+    Exception, Filters, LoadResult, Player as LavalinkPlayer, PlayerState as LavalinkPlayerState,
+    Track as LavalinkTrack, UpdatePlayer, UpdatePlayerTrack,
+};
+use rand::prelude::SliceRandom;
+use serde_json::{Value, json};
 use serenity::all::{ChannelId, GuildId, MessageId, ReactionType, UserId};
 use tokio::task::JoinHandle;
 
+use super::unix_millis;
+use crate::utils::constants::{
+    HYDROGEN_DEFAULT_VOLUME, HYDROGEN_EMPTY_CHAT_TIMEOUT, HYDROGEN_QUEUE_LIMIT, HYDROGEN_RPG_VOLUME,
+};
+
 #[derive(Debug)]
 /// Player information.
 pub struct Player {
     /// The text channel where the player is sending messages.
     pub channel_id: Option<ChannelId>,
     /// The message ID of the player.
+    ///
+    /// When the split view is enabled, this is the message with the controls.
     pub message_id: Option<MessageId>,
+    /// The message ID of the now-playing message, only used when the split view is enabled.
+    pub nowplaying_message_id: Option<MessageId>,
+    /// If the player message is split into a now-playing message and a controls message.
+    pub split_view: bool,
     /// The queue of tracks.
     pub queue: Vec<Track>,
     /// The current track being played.
@@ -27,6 +48,34 @@ pub struct Player {
     pub locale: String,
     /// The handle for the player's destroy task.
     pub destroy_handle: Option<JoinHandle<()>>,
+    /// The last player state received from Lavalink.
+    pub state: Option<LavalinkPlayerState>,
+    /// If the filters should be kept when a new track starts.
+    pub filters_sticky: bool,
+    /// The filters applied to the player.
+    pub filters: Filters,
+    /// The handle for the task updating the live now-playing message.
+    pub nowplaying_handle: Option<JoinHandle<()>>,
+    /// If the voice state was sent again after Lavalink reported the voice connection as lost.
+    pub voice_recovery: bool,
+    /// If tracks with explicit content are rejected when adding them to the queue.
+    pub block_explicit: bool,
+    /// The volume of the player, in percentage.
+    pub volume: u16,
+    /// If the player stays in the voice chat even when no one else is connected.
+    pub stay: bool,
+    /// The users who voted to skip the current track.
+    pub skip_votes: HashSet<UserId>,
+    /// If the voice connection is being re-established, so leaving the voice chat doesn't destroy the player.
+    pub voice_reconnecting: bool,
+    /// If the player is destroyed when the current track ends, instead of playing the next one.
+    pub stop_after_current: bool,
+    /// How many tracks can be stored in the queue.
+    pub queue_limit: usize,
+    /// The time to wait before leaving the voice chat when no one else is connected.
+    pub empty_timeout: Duration,
+    /// When the last message about a track exception was sent, used to avoid spamming the text channel.
+    pub last_exception: Option<Instant>,
 }
 
 impl Player {
@@ -37,10 +86,13 @@ impl Player {
         channel_id: ChannelId,
         loop_mode: LoopMode,
         paused: bool,
+        volume: u16,
     ) -> Self {
         Self {
             channel_id: Some(channel_id),
             message_id: None,
+            nowplaying_message_id: None,
+            split_view: false,
             queue: Vec::new(),
             current_track: 0,
             loop_mode,
@@ -48,10 +100,523 @@ impl Player {
             node_id,
             locale: locale.to_owned(),
             destroy_handle: None,
+            state: None,
+            filters_sticky: true,
+            filters: Filters::default(),
+            nowplaying_handle: None,
+            voice_recovery: false,
+            block_explicit: false,
+            volume,
+            stay: false,
+            skip_votes: HashSet::new(),
+            voice_reconnecting: false,
+            stop_after_current: false,
+            queue_limit: HYDROGEN_QUEUE_LIMIT,
+            empty_timeout: Duration::from_secs(HYDROGEN_EMPTY_CHAT_TIMEOUT),
+            last_exception: None,
+        }
+    }
+
+    /// Get the estimated position of the current track in milliseconds, using the last player state received from Lavalink.
+    pub fn position(&self) -> Option<u64> {
+        self.state.as_ref().map(|state| {
+            if self.paused {
+                state.position
+            } else {
+                state.position + unix_millis().saturating_sub(state.time)
+            }
+        })
+    }
+
+    /// Cache a pause change at the time in milliseconds, moving the cached position to that time when the player was
+    /// playing.
+    pub fn cache_pause(&mut self, paused: bool, now: u64) {
+        if let Some(state) = self.state.as_mut() {
+            if !self.paused {
+                state.position += now.saturating_sub(state.time);
+            }
+
+            state.time = now;
+        }
+
+        self.paused = paused;
+    }
+
+    /// If a task should be started to update the progress in the now-playing message, what only happens when the split
+    /// view is enabled and no other task is updating a now-playing message.
+    pub fn needs_split_view_updater(&self) -> bool {
+        self.split_view
+            && self
+                .nowplaying_handle
+                .as_ref()
+                .is_none_or(|handle| handle.is_finished())
+    }
+
+    /// If the filters should be reset when a new track starts, what only happens when they aren't sticky.
+    pub fn should_reset_filters(&self) -> bool {
+        // Without filters applied, there's nothing to reset.
+        !self.filters_sticky && !self.filters.is_empty()
+    }
+
+    /// If the current track can be seeked, what livestreams and some sources don't allow.
+    ///
+    /// Without a current track there's nothing to reject, so it's considered seekable.
+    pub fn can_seek(&self) -> bool {
+        self.queue
+            .get(self.current_track)
+            .is_none_or(|t| t.seekable && !t.is_stream)
+    }
+
+    /// Create the update starting the current track at the position, keeping the pause state and the volume of the
+    /// player, or [None] if there's no current track.
+    ///
+    /// The voice state isn't included, as it comes from the voice connection.
+    pub fn start_update(&self, position: u64) -> Option<UpdatePlayer> {
+        let track = self.queue.get(self.current_track)?;
+
+        let mut update_player = UpdatePlayer::default()
+            .set_track(UpdatePlayerTrack::default().set_encoded(&track.track))
+            .set_paused(self.paused)
+            .set_volume(self.volume);
+
+        if position > 0 {
+            update_player = update_player.set_position(position);
+        }
+
+        if self.should_reset_filters() {
+            update_player = update_player.set_filters(Filters::default());
+        }
+
+        Some(update_player)
+    }
+
+    /// Dump the player information as JSON, used by `/debug` to diagnose the player.
+    pub fn debug_dump(&self, guild_id: GuildId, voice_channel_id: Option<ChannelId>) -> Value {
+        json!({
+            "guild_id": guild_id.to_string(),
+            "node_id": self.node_id,
+            "voice_channel_id": voice_channel_id.map(|c| c.to_string()),
+            "channel_id": self.channel_id.map(|c| c.to_string()),
+            "message_id": self.message_id.map(|m| m.to_string()),
+            "nowplaying_message_id": self.nowplaying_message_id.map(|m| m.to_string()),
+            "split_view": self.split_view,
+            "queue_length": self.queue.len(),
+            "current_track": self.current_track,
+            "loop_mode": format!("{:?}", self.loop_mode),
+            "paused": self.paused,
+            "filters": self.filters,
+            "filters_sticky": self.filters_sticky,
+            "block_explicit": self.block_explicit,
+            "stay": self.stay,
+            "stop_after_current": self.stop_after_current,
+            "skip_votes": self.skip_votes.len(),
+            "volume": self.volume,
+            "locale": self.locale,
+            "destroy_scheduled": self.destroy_handle.is_some(),
+            "nowplaying_live": self.nowplaying_handle.is_some(),
+            "state": self.state.as_ref().map(|s| json!({
+                "time": s.time,
+                "position": s.position,
+                "connected": s.connected,
+                "ping": s.ping,
+            })),
+        })
+    }
+
+    /// Store the player state received from Lavalink, returning what must be done to recover the voice connection.
+    ///
+    /// When Lavalink reports the voice connection as lost for a player with a track, the voice state is sent again
+    /// once, and if it's still lost in the next update, the player is destroyed.
+    pub fn apply_player_update(&mut self, state: LavalinkPlayerState) -> VoiceRecovery {
+        let connected = state.connected;
+        let was_connected = self.state.as_ref().is_none_or(|s| s.connected);
+        let voice_recovery = self.voice_recovery;
+        let active = self.queue.get(self.current_track).is_some();
+
+        self.state = Some(state);
+        self.voice_recovery = !connected && (voice_recovery || (was_connected && active));
+
+        if connected {
+            VoiceRecovery::None
+        } else if voice_recovery {
+            VoiceRecovery::Destroy
+        } else if was_connected && active {
+            VoiceRecovery::Resend
+        } else {
+            VoiceRecovery::None
+        }
+    }
+
+    /// Remove the tracks added to the queue by a request that has failed, restoring the previous current track.
+    pub fn rollback_tracks(&mut self, add_queue_result: &AddQueueResult, current_track: usize) {
+        let start = add_queue_result.first_track_index.min(self.queue.len());
+        let end = (start + add_queue_result.count).min(self.queue.len());
+
+        self.queue.drain(start..end);
+        self.current_track = current_track.min(self.queue.len().saturating_sub(1));
+    }
+
+    /// Replace the encoded track of the queue entries using it, after the track was loaded again by the node.
+    pub fn replace_encoded(&mut self, old: &str, new: String) {
+        for track in self.queue.iter_mut().filter(|t| t.track == old) {
+            track.track.clone_from(&new);
+        }
+    }
+
+    /// If the player is at its last track with the [LoopMode::Autoplay] loop mode.
+    pub fn should_autoplay(&self) -> bool {
+        self.loop_mode == LoopMode::Autoplay && self.current_track + 1 >= self.queue.len()
+    }
+
+    /// If the queue has stopped at its last track and the loop mode would continue it.
+    pub fn can_restart_queue(&self) -> bool {
+        matches!(
+            self.loop_mode,
+            LoopMode::Single | LoopMode::All | LoopMode::Autoplay
+        ) && !self.queue.is_empty()
+            && self.current_track + 1 >= self.queue.len()
+    }
+
+    /// Restart the finished queue, going back to the first track with [LoopMode::All] and keeping the last track with
+    /// the other loop modes, which replay it or use it to find the next one.
+    pub fn restart_queue(&mut self) {
+        if self.loop_mode == LoopMode::All {
+            self.current_track = 0;
+        }
+
+        self.paused = false;
+    }
+
+    /// Reconcile the player with the player in the Lavalink node, returning if the node is playing a track.
+    ///
+    /// The current track is moved to the track playing in the node when it's in the queue, and the cached state is
+    /// cleared if the node isn't playing anything.
+    pub fn reconcile(&mut self, node_player: Option<LavalinkPlayer>) -> bool {
+        let Some((encoded, node_player)) = node_player
+            .and_then(|node_player| Some((node_player.track.clone()?.encoded, node_player)))
+        else {
+            self.state = None;
+            return false;
+        };
+
+        let current_matches = self
+            .queue
+            .get(self.current_track)
+            .is_some_and(|t| t.track == encoded);
+
+        if !current_matches {
+            if let Some(index) = self.queue.iter().position(|t| t.track == encoded) {
+                self.current_track = index;
+            }
+        }
+
+        self.paused = node_player.paused;
+        self.state = Some(node_player.state);
+
+        true
+    }
+
+    /// Add the fetched tracks to the queue, skipping the explicit tracks when they're blocked and the tracks already in
+    /// the queue when deduplicating, and truncating them to the queue limit.
+    pub fn add_tracks(
+        &mut self,
+        fetch_result: FetchResult,
+        requester: UserId,
+        operation: AddQueueOperation,
+        dedup: bool,
+    ) -> AddQueueResult {
+        let old_queue_size = self.queue.len();
+
+        let first_track_index = match operation {
+            AddQueueOperation::End => self.queue.len(),
+            AddQueueOperation::Next => (self.current_track + 1).min(self.queue.len()),
+        };
+
+        let available_size = self.queue_limit.saturating_sub(old_queue_size);
+
+        if available_size == 0 {
+            return AddQueueResult {
+                count: 0,
+                truncated: true,
+                first_track_index: old_queue_size,
+                selected: None,
+                rejected: 0,
+                skipped_duplicates: 0,
+            };
+        }
+
+        let block_explicit = self.block_explicit;
+
+        let mut queued = if dedup {
+            self.queue
+                .iter()
+                .map(|t| t.track.clone())
+                .collect::<HashSet<_>>()
+        } else {
+            HashSet::new()
+        };
+
+        let original_selected = fetch_result.selected;
+        let mut selected = original_selected;
+        let mut rejected = 0;
+        let mut skipped_duplicates = 0;
+
+        // The selected index is moved to keep pointing to the same track, or removed if that track was rejected.
+        let tracks = fetch_result
+            .tracks
+            .into_iter()
+            .map(|t| Track::from_track(t, requester))
+            .enumerate()
+            .filter_map(|(i, t)| {
+                if block_explicit && t.explicit {
+                    rejected += 1;
+                } else if dedup && !queued.insert(t.track.clone()) {
+                    skipped_duplicates += 1;
+                } else {
+                    return Some(t);
+                }
+
+                match original_selected {
+                    Some(s) if s == i => selected = None,
+                    Some(s) if s > i => selected = selected.map(|v| v - 1),
+                    _ => {}
+                }
+
+                None
+            })
+            .collect::<Vec<_>>();
+
+        let old_tracks_size = tracks.len();
+
+        let tracks = tracks.into_iter().take(available_size).collect::<Vec<_>>();
+
+        let truncated = tracks.len() < old_tracks_size;
+
+        let tracks_size = tracks.len();
+
+        match operation {
+            AddQueueOperation::End => self.queue.extend(tracks),
+            AddQueueOperation::Next => {
+                self.queue
+                    .splice(first_track_index..first_track_index, tracks);
+            }
+        }
+
+        let selected = selected.map(|i| {
+            if let Some(new_index) = first_track_index.checked_add(i) {
+                if new_index < self.queue.len() {
+                    new_index
+                } else {
+                    first_track_index
+                }
+            } else {
+                first_track_index
+            }
+        });
+
+        AddQueueResult {
+            count: tracks_size,
+            truncated,
+            first_track_index,
+            selected,
+            rejected,
+            skipped_duplicates,
+        }
+    }
+
+    /// Go to the next track in the queue, returning what must be done with the player, or `None` if the queue is empty.
+    ///
+    /// Without loop, skipping the last track ends the queue instead of playing it again, which is also done with
+    /// [LoopMode::Autoplay] when autoplay can't find the next track.
+    pub fn skip(&mut self) -> Option<QueueUpdate> {
+        if self.queue.is_empty() {
+            return None;
+        }
+
+        if self.current_track + 1 >= self.queue.len()
+            && matches!(
+                self.loop_mode,
+                LoopMode::None | LoopMode::AutoPause | LoopMode::Autoplay
+            )
+        {
+            return Some(QueueUpdate::Stop);
+        }
+
+        self.current_track = (self.current_track + 1) % self.queue.len();
+
+        Some(QueueUpdate::Sync)
+    }
+
+    /// Use the loop mode to go to the track played after the current one ends, returning what must be done with the
+    /// player, or `None` if the queue is empty.
+    pub fn next_track(&mut self) -> Option<QueueUpdate> {
+        if self.queue.is_empty() {
+            return None;
+        }
+
+        let (new_index, should_pause, need_sync) = match self.loop_mode {
+            LoopMode::Single => (self.current_track, false, true),
+            LoopMode::All => ((self.current_track + 1) % self.queue.len(), false, true),
+            LoopMode::AutoPause => {
+                if self.current_track + 1 >= self.queue.len() {
+                    (self.queue.len() - 1, true, false)
+                } else {
+                    (self.current_track + 1, true, false)
+                }
+            }
+            _ => {
+                if self.current_track + 1 >= self.queue.len() {
+                    (self.queue.len() - 1, false, false)
+                } else {
+                    (self.current_track + 1, false, true)
+                }
+            }
+        };
+
+        self.current_track = new_index;
+        self.paused = should_pause;
+
+        Some(if need_sync {
+            QueueUpdate::Sync
+        } else {
+            QueueUpdate::Message
+        })
+    }
+
+    /// Remove the track at the index from the queue, returning it and what must be done with the player, or `None` if
+    /// the index is out of bounds.
+    pub fn remove_track(&mut self, index: usize) -> Option<(Track, QueueUpdate)> {
+        if index >= self.queue.len() {
+            return None;
+        }
+
+        let track = self.queue.remove(index);
+
+        let update = match index.cmp(&self.current_track) {
+            Ordering::Less => {
+                self.current_track -= 1;
+                QueueUpdate::Message
+            }
+            Ordering::Equal => self.play_from(index),
+            Ordering::Greater => QueueUpdate::Message,
+        };
+
+        Some((track, update))
+    }
+
+    /// Remove the tracks in the range `[start, end)` from the queue, returning how many tracks were removed and what must
+    /// be done with the player, or `None` if the range is invalid.
+    ///
+    /// If the current track is removed, the first track after the range is played.
+    pub fn remove_range(&mut self, start: usize, end: usize) -> Option<(usize, QueueUpdate)> {
+        if start > end || end > self.queue.len() {
+            return None;
+        }
+
+        let count = end - start;
+
+        if count == 0 {
+            return Some((0, QueueUpdate::Message));
+        }
+
+        self.queue.drain(start..end);
+
+        let update = if self.current_track < start {
+            QueueUpdate::Message
+        } else if self.current_track >= end {
+            self.current_track -= count;
+            QueueUpdate::Message
+        } else {
+            // The first track after the range is now at the start of the range.
+            self.play_from(start)
+        };
+
+        Some((count, update))
+    }
+
+    /// Point the current track to the track at the index after the current track was removed, going back to the start
+    /// of the queue with the loop of the queue, or stopping at the end of the queue otherwise.
+    fn play_from(&mut self, index: usize) -> QueueUpdate {
+        if index < self.queue.len() {
+            self.current_track = index;
+            QueueUpdate::Sync
+        } else if self.loop_mode == LoopMode::All && !self.queue.is_empty() {
+            self.current_track = 0;
+            QueueUpdate::Sync
+        } else {
+            self.current_track = self.queue.len().saturating_sub(1);
+            QueueUpdate::Stop
+        }
+    }
+
+    /// Shuffle only the tracks after the current one, keeping the played tracks and the current track in place, or
+    /// returning `false` if the queue is empty.
+    pub fn shuffle_remaining(&mut self) -> bool {
+        if self.queue.is_empty() {
+            return false;
         }
+
+        let start = (self.current_track + 1).min(self.queue.len());
+
+        self.queue[start..].shuffle(&mut rand::rng());
+
+        true
+    }
+
+    /// Move the track from an index to another in the queue, keeping the current track pointing to the same track, or
+    /// returning `false` if any of the indexes is out of bounds.
+    pub fn move_track(&mut self, from: usize, to: usize) -> bool {
+        if from >= self.queue.len() || to >= self.queue.len() {
+            return false;
+        }
+
+        let track = self.queue.remove(from);
+        self.queue.insert(to, track);
+
+        let current_track = self.current_track;
+
+        self.current_track = if current_track == from {
+            to
+        } else if from < current_track && to >= current_track {
+            current_track - 1
+        } else if from > current_track && to <= current_track {
+            current_track + 1
+        } else {
+            current_track
+        };
+
+        true
+    }
+
+    /// Shuffle the queue, moving the current track to the start of it, returning `false` if the queue is empty.
+    pub fn shuffle(&mut self) -> bool {
+        if self.current_track >= self.queue.len() {
+            return false;
+        }
+
+        let current_track = self.queue.swap_remove(self.current_track);
+
+        self.queue.shuffle(&mut rand::rng());
+
+        self.queue.insert(0, current_track);
+
+        self.current_track = 0;
+
+        true
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What must be done with the player after its queue changed.
+pub enum QueueUpdate {
+    /// Only the player message must be updated.
+    Message,
+    /// The current track changed and must be played.
+    Sync,
+    /// There's no track to play anymore, the current one must be stopped.
+    Stop,
+}
+
 #[derive(Debug, Clone)]
 /// Represents the state of the player.
 pub struct PlayerState {
@@ -63,6 +628,12 @@ pub struct PlayerState {
     pub text_channel: Option<ChannelId>,
     /// The message ID of the player.
     pub message_id: Option<MessageId>,
+    /// The message ID of the now-playing message.
+    pub nowplaying_message_id: Option<MessageId>,
+    /// Whether the player message is split.
+    pub split_view: bool,
+    /// The estimated position of the current track in milliseconds.
+    pub position: Option<u64>,
     /// The locale of the player.
     pub locale: String,
     /// The track currently playing.
@@ -71,6 +642,10 @@ pub struct PlayerState {
     pub node_id: usize,
     /// The loop mode of the player.
     pub loop_mode: LoopMode,
+    /// Whether the player stays in the voice chat even when no one else is connected.
+    pub stay: bool,
+    /// The time to wait before leaving the voice chat when no one else is connected.
+    pub empty_timeout: Duration,
 }
 
 impl From<&Player> for PlayerState {
@@ -80,10 +655,15 @@ impl From<&Player> for PlayerState {
             has_destroy_handle: player.destroy_handle.is_some(),
             text_channel: player.channel_id,
             message_id: player.message_id,
+            nowplaying_message_id: player.nowplaying_message_id,
+            split_view: player.split_view,
+            position: player.position(),
             locale: player.locale.clone(),
             track: player.queue.get(player.current_track).cloned(),
             node_id: player.node_id,
             loop_mode: player.loop_mode,
+            stay: player.stay,
+            empty_timeout: player.empty_timeout,
         }
     }
 }
@@ -96,15 +676,22 @@ impl From<Player> for PlayerState {
             None
         };
 
+        let position = player.position();
+
         Self {
             paused: player.paused,
             has_destroy_handle: player.destroy_handle.is_some(),
             text_channel: player.channel_id,
             message_id: player.message_id,
+            nowplaying_message_id: player.nowplaying_message_id,
+            split_view: player.split_view,
+            position,
             locale: player.locale,
             track,
             node_id: player.node_id,
             loop_mode: player.loop_mode,
+            stay: player.stay,
+            empty_timeout: player.empty_timeout,
         }
     }
 }
@@ -169,14 +756,26 @@ pub struct Track {
     pub requester: UserId,
     /// The track's duration.
     pub duration: u64,
-    /// The track's URL for the user.
+    /// If the track is a livestream, without a finite duration.
+    pub is_stream: bool,
+    /// The track's URL, as provided by the source.
     pub url: Option<String>,
+    /// The track's identifier in the source.
+    pub identifier: String,
+    /// The name of the track's source.
+    pub source_name: Option<String>,
+    /// If the track allows seeking.
+    pub seekable: bool,
+    /// If the track has explicit content, as reported by the plugins.
+    pub explicit: bool,
     /// The track's thumbnail.
     pub thumbnail: Option<String>,
     /// The track's identifier from the YouTube source.
     pub youtube_id: Option<String>,
     /// The track's ISRC (International Standard Recording Code).
     pub isrc: Option<String>,
+    /// When the track was added to the queue.
+    pub added_at: Instant,
 }
 
 impl Track {
@@ -188,18 +787,60 @@ impl Track {
             None
         };
 
+        let explicit = is_explicit(&track.plugin_info);
+
         Self {
             track: track.encoded,
             title: track.info.title,
             author: track.info.author,
             requester,
             duration: track.info.length,
+            is_stream: track.info.is_stream,
             url: track.info.uri,
+            identifier: track.info.identifier,
+            source_name: track.info.source_name,
+            seekable: track.info.is_seekable,
+            explicit,
             thumbnail: track.info.artwork_url,
             isrc: track.info.isrc,
             youtube_id,
+            added_at: Instant::now(),
         }
     }
+
+    /// Get the URL to be shown to the user, built from the source when possible.
+    pub fn public_url(&self) -> Option<String> {
+        let source_url = match self.source_name.as_deref() {
+            Some("youtube") => Some(format!("https://youtu.be/{}", self.identifier)),
+            Some("deezer") => Some(format!("https://www.deezer.com/track/{}", self.identifier)),
+            Some("spotify") => Some(format!(
+                "https://open.spotify.com/track/{}",
+                self.identifier
+            )),
+            Some("applemusic") => Some(format!("https://music.apple.com/song/{}", self.identifier)),
+            // SoundCloud identifiers are internal, but its URIs are the public links.
+            _ => None,
+        };
+
+        // Some sources use internal URIs, which can't be opened by the user.
+        source_url.or_else(|| {
+            self.url
+                .as_ref()
+                .filter(|url| url.starts_with("https://") || url.starts_with("http://"))
+                .cloned()
+        })
+    }
+}
+
+/// Check if the plugin information marks the track as explicit, considering it non-explicit without the information.
+fn is_explicit(plugin_info: &HashMap<String, Value>) -> bool {
+    ["isExplicit", "explicit"]
+        .iter()
+        .any(|key| plugin_info.get(*key).and_then(Value::as_bool) == Some(true))
+        || plugin_info
+            .get("contentRating")
+            .and_then(Value::as_str)
+            .is_some_and(|rating| rating.eq_ignore_ascii_case("explicit"))
 }
 
 #[derive(Debug, Clone)]
@@ -213,16 +854,27 @@ pub struct PlayResult {
     pub playing: bool,
     /// If the queue was truncated.
     pub truncated: bool,
+    /// The position in the queue of the track, only available when the player hasn't started playing it.
+    pub queue_position: Option<usize>,
+    /// The amount of tracks that were rejected for having explicit content.
+    pub rejected: usize,
+    /// The amount of tracks that were skipped for being already in the queue.
+    pub skipped_duplicates: usize,
 }
 
 impl PlayResult {
     /// Merge the results of adding tracks and syncing the player.
     pub fn merge(add_queue_result: AddQueueResult, sync_result: SyncResult) -> Self {
+        let queue_position = (!sync_result.playing).then_some(add_queue_result.start_index());
+
         Self {
             track: sync_result.track,
             count: add_queue_result.count,
             playing: sync_result.playing,
             truncated: add_queue_result.truncated,
+            queue_position,
+            rejected: add_queue_result.rejected,
+            skipped_duplicates: add_queue_result.skipped_duplicates,
         }
     }
 }
@@ -234,6 +886,33 @@ pub struct SeekResult {
     pub position: u64,
     /// The total duration of the track in milliseconds.
     pub total: u64,
+    /// If the track is a livestream, without a finite duration.
+    pub is_stream: bool,
+}
+
+#[derive(Debug, Clone)]
+/// Outcome of searching for a track with multiple prefixes.
+pub enum SearchOutcome {
+    /// A source returned the tracks.
+    Found(LoadResult),
+    /// No source found any track.
+    NotFound,
+    /// A source failed to load the tracks.
+    Errored(Exception),
+}
+
+#[derive(Debug, Clone)]
+/// Outcome of a vote to skip the current track.
+pub enum VoteSkipOutcome {
+    /// The skip passed, containing the track playing now, if any.
+    Skipped(Option<Box<Track>>),
+    /// The vote was registered, but more votes are needed to skip.
+    Voted {
+        /// The amount of votes registered.
+        votes: usize,
+        /// The amount of votes required to skip.
+        required: usize,
+    },
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -247,7 +926,7 @@ pub enum PlayerTemplate {
     Queue,
     /// Player for manual control with automatic pause.
     Manual,
-    /// Player for RPG music with single loop and paused by default.
+    /// Player for RPG music with single loop, paused by default and a lower volume.
     Rpg,
     /// Player with autoplay enabled.
     Autoplay,
@@ -270,9 +949,24 @@ impl PlayerTemplate {
         }
     }
 
+    /// The volume for the player, in percentage.
+    pub fn volume(&self) -> u16 {
+        match self {
+            Self::Rpg => HYDROGEN_RPG_VOLUME,
+            _ => HYDROGEN_DEFAULT_VOLUME,
+        }
+    }
+
     /// Convert the template into a player.
     pub fn into_player(self, node_id: usize, locale: &str, channel_id: ChannelId) -> Player {
-        Player::new(node_id, locale, channel_id, self.loop_mode(), self.pause())
+        Player::new(
+            node_id,
+            locale,
+            channel_id,
+            self.loop_mode(),
+            self.pause(),
+            self.volume(),
+        )
     }
 }
 
@@ -291,6 +985,43 @@ pub struct FetchResult {
     pub tracks: Vec<LavalinkTrack>,
 }
 
+impl FetchResult {
+    /// Convert the result of loading a query into the tracks to add to the queue, or `None` if nothing was found.
+    ///
+    /// Only the first search result is used, and a playlist with a single track is handled as a track load, as some
+    /// sources return them for single tracks (e.g. an album with one song).
+    pub fn from_load_result(load_result: LoadResult) -> Option<Self> {
+        match load_result {
+            LoadResult::Search(tracks) => tracks.into_iter().nth(0).map(|t| Self {
+                selected: None,
+                tracks: vec![t],
+            }),
+            LoadResult::Playlist(playlist)
+                if playlist.tracks.len() == 1 && playlist.info.selected_track <= 0 =>
+            {
+                Some(Self {
+                    selected: None,
+                    tracks: playlist.tracks,
+                })
+            }
+            LoadResult::Playlist(playlist) => Some(Self {
+                selected: if playlist.info.selected_track >= 0 {
+                    Some(playlist.info.selected_track as usize)
+                } else {
+                    None
+                },
+
+                tracks: playlist.tracks.into_iter().collect(),
+            }),
+            LoadResult::Track(music) => Some(Self {
+                selected: None,
+                tracks: vec![*music],
+            }),
+            LoadResult::Empty | LoadResult::Error(_) => None,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 /// Result of adding tracks to the queue.
 pub struct AddQueueResult {
@@ -302,6 +1033,41 @@ pub struct AddQueueResult {
     pub count: usize,
     /// If the queue was truncated.
     pub truncated: bool,
+    /// The amount of tracks that were rejected for having explicit content.
+    pub rejected: usize,
+    /// The amount of tracks that were skipped for being already in the queue.
+    pub skipped_duplicates: usize,
+}
+
+impl AddQueueResult {
+    /// Get the index of the track played from the added tracks, the selected one or the first one.
+    pub fn start_index(&self) -> usize {
+        self.selected.unwrap_or(self.first_track_index)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// What must be done with the voice connection after a player update from Lavalink.
+pub enum VoiceRecovery {
+    /// The voice connection is fine, or there's nothing playing to recover.
+    None,
+    /// The voice connection was just lost, so the voice state is sent again.
+    Resend,
+    /// The voice connection is still lost after sending the voice state again, so the player is destroyed.
+    Destroy,
+}
+
+#[derive(Debug, Clone)]
+/// Result of importing tracks to the queue.
+pub struct ImportQueueResult {
+    /// The amount of tracks that were added.
+    pub count: usize,
+    /// The amount of tracks that couldn't be decoded.
+    pub failed: usize,
+    /// If the queue was truncated.
+    pub truncated: bool,
+    /// The amount of tracks that were rejected for having explicit content.
+    pub rejected: usize,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -339,6 +1105,21 @@ pub enum PlayMode {
     PlayNow,
 }
 
+impl PlayMode {
+    /// Get the operation used to add the tracks to the queue.
+    pub fn add_queue_operation(self) -> AddQueueOperation {
+        match self {
+            Self::AddToEnd => AddQueueOperation::End,
+            Self::AddToNext | Self::PlayNow => AddQueueOperation::Next,
+        }
+    }
+
+    /// If the added tracks are played even when the loop mode wouldn't play them.
+    pub fn forces_sync(self, queue_was_empty: bool) -> bool {
+        self == Self::PlayNow || queue_was_empty
+    }
+}
+
 impl Default for PlayMode {
     fn default() -> Self {
         Self::AddToEnd
@@ -361,4 +1142,1101 @@ pub struct PlayRequest<'a> {
     pub player_template: PlayerTemplate,
     /// The mode to play the track.
     pub play_mode: PlayMode,
+    /// The position to start the played track at, ignored if the track is only added to the queue.
+    pub start_position: Option<Duration>,
+    /// If the tracks already in the queue are skipped.
+    pub dedup: bool,
+}
+
+#[derive(Debug, Clone)]
+/// Request to play tracks already resolved by the caller.
+pub struct PlayTracksRequest<'a> {
+    /// The tracks to add to the queue.
+    pub tracks: Vec<LavalinkTrack>,
+    /// The index of the track to be played, like the selected track from a playlist.
+    pub selected: Option<usize>,
+    /// The requester of the tracks.
+    pub requester: UserId,
+    /// The guild ID of the player.
+    pub guild_id: GuildId,
+    /// The text channel of the player.
+    pub text_channel: ChannelId,
+    /// Locale for the player's messages.
+    pub locale: &'a str,
+    /// The player template to use.
+    pub player_template: PlayerTemplate,
+    /// The mode to play the tracks.
+    pub play_mode: PlayMode,
+    /// The position to start the played track at, ignored if the track is only added to the queue.
+    pub start_position: Option<Duration>,
+    /// If the tracks already in the queue are skipped.
+    pub dedup: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use hydrolink::{LoadResultPlaylist, PlaylistInfo, TrackInfo, VoiceState};
+
+    use super::*;
+
+    /// Create a track with the title.
+    fn track(title: &str) -> Track {
+        Track {
+            track: title.to_owned(),
+            author: String::new(),
+            title: title.to_owned(),
+            requester: UserId::new(1),
+            duration: 0,
+            is_stream: false,
+            url: None,
+            identifier: title.to_owned(),
+            source_name: None,
+            seekable: true,
+            explicit: false,
+            thumbnail: None,
+            youtube_id: None,
+            isrc: None,
+            added_at: Instant::now(),
+        }
+    }
+
+    /// Create a player with the tracks in the queue, playing the track at `current_track`.
+    fn new_player(titles: &[&str], current_track: usize, loop_mode: LoopMode) -> Player {
+        let mut player = Player::new(0, "en-US", ChannelId::new(1), loop_mode, false, 100);
+        player.queue = titles.iter().map(|title| track(title)).collect();
+        player.current_track = current_track;
+        player
+    }
+
+    /// Get the titles of the tracks in the queue.
+    fn titles(player: &Player) -> Vec<&str> {
+        player.queue.iter().map(|t| t.title.as_str()).collect()
+    }
+
+    /// Create a track loaded from Lavalink with the title.
+    fn lavalink_track(title: &str) -> LavalinkTrack {
+        LavalinkTrack {
+            encoded: title.to_owned(),
+            info: TrackInfo {
+                identifier: title.to_owned(),
+                is_seekable: true,
+                author: String::new(),
+                length: 1000,
+                is_stream: false,
+                position: 0,
+                title: title.to_owned(),
+                uri: None,
+                artwork_url: None,
+                isrc: None,
+                source_name: None,
+            },
+            plugin_info: HashMap::new(),
+            user_data: HashMap::new(),
+        }
+    }
+
+    /// Create a playlist load result with the tracks.
+    fn playlist(titles: &[&str], selected_track: i32) -> LoadResult {
+        LoadResult::Playlist(LoadResultPlaylist {
+            info: PlaylistInfo {
+                name: "playlist".to_owned(),
+                selected_track,
+            },
+            plugin_info: HashMap::new(),
+            tracks: titles.iter().map(|title| lavalink_track(title)).collect(),
+        })
+    }
+
+    #[test]
+    fn cache_pause_freezes_the_position() {
+        let mut player = new_player(&["a"], 0, LoopMode::None);
+        player.state = Some(LavalinkPlayerState {
+            time: 5000,
+            position: 1000,
+            connected: true,
+            ping: 0,
+        });
+
+        player.cache_pause(true, 7000);
+
+        assert!(player.paused);
+        assert_eq!(player.position(), Some(3000));
+
+        player.cache_pause(false, 9000);
+
+        let state = player.state.as_ref().unwrap();
+        assert!(!player.paused);
+        assert_eq!((state.position, state.time), (3000, 9000));
+    }
+
+    #[tokio::test]
+    async fn split_view_updater_is_started_once() {
+        let mut player = new_player(&["a"], 0, LoopMode::None);
+
+        assert!(!player.needs_split_view_updater());
+
+        player.split_view = true;
+
+        assert!(player.needs_split_view_updater());
+
+        player.nowplaying_handle = Some(tokio::spawn(std::future::pending()));
+
+        assert!(!player.needs_split_view_updater());
+
+        let handle = player.nowplaying_handle.take().unwrap();
+        handle.abort();
+        let _ = handle.await;
+        player.nowplaying_handle = Some(tokio::spawn(async {}));
+        while !player.nowplaying_handle.as_ref().unwrap().is_finished() {
+            tokio::task::yield_now().await;
+        }
+
+        assert!(player.needs_split_view_updater());
+    }
+
+    #[test]
+    fn reset_filters_unless_sticky() {
+        let mut player = new_player(&["a"], 0, LoopMode::None);
+
+        player.filters_sticky = false;
+        assert!(!player.should_reset_filters());
+
+        player.filters = Filters::bass_boost(0.5);
+        assert!(player.should_reset_filters());
+
+        player.filters_sticky = true;
+        assert!(!player.should_reset_filters());
+    }
+
+    #[test]
+    fn one_track_playlist_is_a_track() {
+        for selected_track in [-1, 0] {
+            let fetch_result =
+                FetchResult::from_load_result(playlist(&["a"], selected_track)).unwrap();
+
+            assert_eq!(fetch_result.selected, None);
+            assert_eq!(fetch_result.tracks.len(), 1);
+
+            let mut player = new_player(&[], 0, LoopMode::None);
+            let add_queue_result =
+                player.add_tracks(fetch_result, UserId::new(1), AddQueueOperation::End, false);
+
+            assert_eq!(titles(&player), ["a"]);
+            assert_eq!(add_queue_result.count, 1);
+            assert_eq!(add_queue_result.selected, None);
+
+            let play_result = PlayResult::merge(
+                add_queue_result,
+                SyncResult {
+                    track: player.queue.first().cloned(),
+                    playing: true,
+                },
+            );
+
+            assert_eq!(play_result.count, 1);
+            assert_eq!(play_result.queue_position, None);
+            assert_eq!(play_result.track.map(|t| t.title), Some("a".to_owned()));
+        }
+    }
+
+    #[test]
+    fn playlist_keeps_selected_track() {
+        let fetch_result = FetchResult::from_load_result(playlist(&["a", "b", "c"], 1)).unwrap();
+
+        assert_eq!(fetch_result.selected, Some(1));
+        assert_eq!(fetch_result.tracks.len(), 3);
+
+        let fetch_result = FetchResult::from_load_result(playlist(&["a", "b"], -1)).unwrap();
+
+        assert_eq!(fetch_result.selected, None);
+        assert_eq!(fetch_result.tracks.len(), 2);
+
+        assert!(FetchResult::from_load_result(LoadResult::Empty).is_none());
+    }
+
+    /// Add the tracks to the player and merge the result, as if the player was already playing when not empty.
+    fn play(
+        player: &mut Player,
+        titles: &[&str],
+        selected: Option<usize>,
+        operation: AddQueueOperation,
+    ) -> PlayResult {
+        let playing = player.queue.is_empty();
+
+        let add_queue_result = player.add_tracks(
+            FetchResult {
+                selected,
+                tracks: titles.iter().map(|title| lavalink_track(title)).collect(),
+            },
+            UserId::new(1),
+            operation,
+            false,
+        );
+
+        PlayResult::merge(
+            add_queue_result,
+            SyncResult {
+                track: player.queue.get(player.current_track).cloned(),
+                playing,
+            },
+        )
+    }
+
+    #[test]
+    fn play_result_empty_queue() {
+        let mut player = new_player(&[], 0, LoopMode::None);
+
+        let play_result = play(&mut player, &["a", "b"], None, AddQueueOperation::End);
+
+        assert_eq!(play_result.count, 2);
+        assert!(play_result.playing);
+        assert_eq!(play_result.queue_position, None);
+        assert_eq!(play_result.track.map(|t| t.title), Some("a".to_owned()));
+    }
+
+    #[test]
+    fn play_result_non_empty_queue() {
+        let mut player = new_player(&["a", "b", "c"], 1, LoopMode::None);
+
+        let play_result = play(&mut player, &["d"], None, AddQueueOperation::End);
+
+        assert!(!play_result.playing);
+        assert_eq!(play_result.queue_position, Some(3));
+
+        let play_result = play(&mut player, &["e"], None, AddQueueOperation::Next);
+
+        assert_eq!(play_result.queue_position, Some(2));
+        assert_eq!(titles(&player), ["a", "b", "e", "c", "d"]);
+
+        let play_result = play(
+            &mut player,
+            &["f", "g", "h"],
+            Some(1),
+            AddQueueOperation::End,
+        );
+
+        assert_eq!(play_result.count, 3);
+        assert_eq!(play_result.queue_position, Some(6));
+    }
+
+    #[test]
+    fn play_result_multiple_merges() {
+        let mut player = new_player(&[], 0, LoopMode::None);
+
+        let positions = [
+            play(&mut player, &["a"], None, AddQueueOperation::End),
+            play(&mut player, &["b", "c"], None, AddQueueOperation::End),
+            play(&mut player, &["d"], None, AddQueueOperation::End),
+            play(&mut player, &["e"], None, AddQueueOperation::Next),
+        ]
+        .map(|play_result| play_result.queue_position);
+
+        assert_eq!(positions, [None, Some(1), Some(3), Some(1)]);
+        assert_eq!(titles(&player), ["a", "e", "b", "c", "d"]);
+    }
+
+    /// Create a track from the source with the identifier and URI.
+    fn source_track(source_name: &str, identifier: &str, url: Option<&str>) -> Track {
+        Track {
+            identifier: identifier.to_owned(),
+            source_name: Some(source_name.to_owned()),
+            url: url.map(str::to_owned),
+            ..track("a")
+        }
+    }
+
+    #[test]
+    fn public_url_from_source() {
+        let cases = [
+            (
+                source_track("youtube", "dQw4w9WgXcQ", None),
+                Some("https://youtu.be/dQw4w9WgXcQ"),
+            ),
+            (
+                source_track(
+                    "soundcloud",
+                    "O:https://api-v2.soundcloud.com/media/soundcloud:tracks:1/stream/hls",
+                    Some("https://soundcloud.com/artist/track"),
+                ),
+                Some("https://soundcloud.com/artist/track"),
+            ),
+            (
+                source_track("spotify", "4uLU6hMCjMI75M1A2tKUQC", Some("spotify:track:1")),
+                Some("https://open.spotify.com/track/4uLU6hMCjMI75M1A2tKUQC"),
+            ),
+            (
+                source_track("deezer", "3135556", None),
+                Some("https://www.deezer.com/track/3135556"),
+            ),
+            (
+                source_track("applemusic", "1440857781", None),
+                Some("https://music.apple.com/song/1440857781"),
+            ),
+            (
+                source_track(
+                    "http",
+                    "https://example.com/a.mp3",
+                    Some("https://example.com/a.mp3"),
+                ),
+                Some("https://example.com/a.mp3"),
+            ),
+            (source_track("unknown", "a", None), None),
+            (source_track("unknown", "a", Some("internal:a")), None),
+        ];
+
+        for (track, expected) in cases {
+            assert_eq!(
+                track.public_url().as_deref(),
+                expected,
+                "{:?}",
+                track.source_name
+            );
+        }
+    }
+
+    #[test]
+    fn seek_needs_seekable_track() {
+        let mut player = new_player(&[], 0, LoopMode::None);
+        assert!(player.can_seek());
+
+        player.queue = vec![track("a")];
+        assert!(player.can_seek());
+
+        player.queue[0].seekable = false;
+        assert!(!player.can_seek());
+
+        player.queue[0] = Track {
+            is_stream: true,
+            ..track("a")
+        };
+        assert!(!player.can_seek());
+    }
+
+    /// Add the pre-resolved tracks to the player with the play mode, returning if the player is forced to play them and
+    /// the index of the track played.
+    fn play_tracks(
+        player: &mut Player,
+        titles: &[&str],
+        selected: Option<usize>,
+        play_mode: PlayMode,
+    ) -> (bool, usize) {
+        let queue_was_empty = player.queue.is_empty();
+
+        let add_queue_result = player.add_tracks(
+            FetchResult {
+                selected,
+                tracks: titles.iter().map(|title| lavalink_track(title)).collect(),
+            },
+            UserId::new(1),
+            play_mode.add_queue_operation(),
+            false,
+        );
+
+        (
+            play_mode.forces_sync(queue_was_empty),
+            add_queue_result.start_index(),
+        )
+    }
+
+    #[test]
+    fn play_tracks_starts_fresh_queue() {
+        for play_mode in [PlayMode::AddToEnd, PlayMode::AddToNext, PlayMode::PlayNow] {
+            let mut player = new_player(&[], 0, LoopMode::None);
+
+            assert_eq!(
+                play_tracks(&mut player, &["a", "b", "c"], Some(1), play_mode),
+                (true, 1),
+                "{:?}",
+                play_mode
+            );
+            assert_eq!(titles(&player), ["a", "b", "c"]);
+
+            let mut player = new_player(&[], 0, LoopMode::None);
+
+            assert_eq!(
+                play_tracks(&mut player, &["a", "b"], None, play_mode),
+                (true, 0),
+                "{:?}",
+                play_mode
+            );
+        }
+    }
+
+    #[test]
+    fn play_tracks_non_empty_queue() {
+        let mut player = new_player(&["a", "b"], 0, LoopMode::None);
+
+        assert_eq!(
+            play_tracks(&mut player, &["c"], None, PlayMode::AddToEnd),
+            (false, 2)
+        );
+        assert_eq!(
+            play_tracks(&mut player, &["d"], None, PlayMode::AddToNext),
+            (false, 1)
+        );
+        assert_eq!(
+            play_tracks(&mut player, &["e", "f"], Some(1), PlayMode::PlayNow),
+            (true, 2)
+        );
+        assert_eq!(titles(&player), ["a", "e", "f", "d", "b", "c"]);
+    }
+
+    #[test]
+    fn rpg_player_starts_paused() {
+        let mut player = PlayerTemplate::Rpg.into_player(0, "en-US", ChannelId::new(1));
+
+        assert!(player.start_update(0).is_none());
+
+        let add_queue_result = player.add_tracks(
+            FetchResult {
+                selected: None,
+                tracks: vec![lavalink_track("a")],
+            },
+            UserId::new(1),
+            PlayMode::AddToEnd.add_queue_operation(),
+            false,
+        );
+
+        assert!(PlayMode::AddToEnd.forces_sync(true));
+        assert_eq!(add_queue_result.start_index(), 0);
+
+        let update_player = player.start_update(0).unwrap();
+
+        assert_eq!(update_player.paused, Some(true));
+        assert_eq!(update_player.volume, Some(HYDROGEN_RPG_VOLUME));
+        assert_eq!(update_player.position, None);
+        assert!(update_player.track.is_some());
+        assert!(PlayerState::from(&player).paused);
+    }
+
+    #[test]
+    fn debug_dump_keys() {
+        let mut player = new_player(&["a"], 0, LoopMode::None);
+        player.filters = Filters::bass_boost(0.5);
+
+        let dump = player.debug_dump(GuildId::new(1), None);
+        let mut keys = dump
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        keys.sort_unstable();
+
+        assert_eq!(
+            keys,
+            [
+                "block_explicit",
+                "channel_id",
+                "current_track",
+                "destroy_scheduled",
+                "filters",
+                "filters_sticky",
+                "guild_id",
+                "locale",
+                "loop_mode",
+                "message_id",
+                "node_id",
+                "nowplaying_live",
+                "nowplaying_message_id",
+                "paused",
+                "queue_length",
+                "skip_votes",
+                "split_view",
+                "state",
+                "stay",
+                "stop_after_current",
+                "voice_channel_id",
+                "volume",
+            ]
+        );
+        assert_eq!(
+            dump["filters"],
+            serde_json::to_value(&player.filters).unwrap()
+        );
+        assert!(dump["filters"]["equalizer"].is_array());
+    }
+
+    /// Create a player state from Lavalink with the voice connection state.
+    fn lavalink_state(connected: bool) -> LavalinkPlayerState {
+        LavalinkPlayerState {
+            time: 0,
+            position: 0,
+            connected,
+            ping: 0,
+        }
+    }
+
+    #[test]
+    fn lost_voice_connection_is_resent_once() {
+        let mut player = new_player(&["a"], 0, LoopMode::None);
+
+        assert_eq!(
+            player.apply_player_update(lavalink_state(true)),
+            VoiceRecovery::None
+        );
+        assert_eq!(
+            player.apply_player_update(lavalink_state(false)),
+            VoiceRecovery::Resend
+        );
+        assert!(player.voice_recovery);
+        assert_eq!(
+            player.apply_player_update(lavalink_state(false)),
+            VoiceRecovery::Destroy
+        );
+    }
+
+    #[test]
+    fn recovered_voice_connection_is_kept() {
+        let mut player = new_player(&["a"], 0, LoopMode::None);
+
+        assert_eq!(
+            player.apply_player_update(lavalink_state(false)),
+            VoiceRecovery::Resend
+        );
+        assert_eq!(
+            player.apply_player_update(lavalink_state(true)),
+            VoiceRecovery::None
+        );
+        assert!(!player.voice_recovery);
+        assert_eq!(
+            player.apply_player_update(lavalink_state(false)),
+            VoiceRecovery::Resend
+        );
+    }
+
+    #[test]
+    fn lost_voice_connection_without_track() {
+        let mut player = new_player(&[], 0, LoopMode::None);
+
+        assert_eq!(
+            player.apply_player_update(lavalink_state(false)),
+            VoiceRecovery::None
+        );
+        assert!(!player.voice_recovery);
+        assert!(player.state.as_ref().is_some_and(|s| !s.connected));
+    }
+
+    #[test]
+    fn rollback_added_tracks() {
+        for play_mode in [PlayMode::AddToEnd, PlayMode::AddToNext, PlayMode::PlayNow] {
+            let mut player = new_player(&["a", "b", "c"], 1, LoopMode::None);
+
+            let add_queue_result = player.add_tracks(
+                FetchResult {
+                    selected: None,
+                    tracks: vec![lavalink_track("d"), lavalink_track("e")],
+                },
+                UserId::new(1),
+                play_mode.add_queue_operation(),
+                false,
+            );
+
+            // The failed sync has already moved to the added track.
+            player.current_track = add_queue_result.start_index();
+            player.rollback_tracks(&add_queue_result, 1);
+
+            assert_eq!(titles(&player), ["a", "b", "c"], "{:?}", play_mode);
+            assert_eq!(player.current_track, 1, "{:?}", play_mode);
+        }
+    }
+
+    #[test]
+    fn rollback_without_added_tracks() {
+        let mut player = new_player(&["a"], 0, LoopMode::None);
+
+        player.rollback_tracks(
+            &AddQueueResult {
+                selected: None,
+                first_track_index: 1,
+                count: 0,
+                truncated: true,
+                rejected: 0,
+                skipped_duplicates: 0,
+            },
+            0,
+        );
+
+        assert_eq!(titles(&player), ["a"]);
+        assert_eq!(player.current_track, 0);
+    }
+
+    #[test]
+    fn restart_finished_queue_by_loop_mode() {
+        let cases = [
+            (LoopMode::None, None),
+            (LoopMode::AutoPause, None),
+            (LoopMode::Single, Some(2)),
+            (LoopMode::All, Some(0)),
+            (LoopMode::Autoplay, Some(2)),
+        ];
+
+        for (loop_mode, expected) in cases {
+            let mut player = new_player(&["a", "b", "c"], 2, loop_mode);
+            player.paused = true;
+
+            let restarted = player.can_restart_queue();
+
+            if restarted {
+                player.restart_queue();
+            }
+
+            assert_eq!(
+                restarted.then_some(player.current_track),
+                expected,
+                "{:?}",
+                loop_mode
+            );
+            assert_eq!(player.paused, !restarted, "{:?}", loop_mode);
+        }
+    }
+
+    #[test]
+    fn restart_unfinished_queue() {
+        let player = new_player(&["a", "b", "c"], 1, LoopMode::All);
+        assert!(!player.can_restart_queue());
+
+        let player = new_player(&[], 0, LoopMode::All);
+        assert!(!player.can_restart_queue());
+    }
+
+    #[test]
+    fn explicit_from_plugin_info() {
+        let cases = [
+            (json!({ "isExplicit": true }), true),
+            (json!({ "explicit": true }), true),
+            (json!({ "contentRating": "Explicit" }), true),
+            (json!({ "isExplicit": false }), false),
+            (
+                json!({ "explicit": false, "contentRating": "clean" }),
+                false,
+            ),
+            (json!({}), false),
+            (json!({ "isExplicit": "true" }), false),
+            (json!({ "explicit": 1 }), false),
+            (json!({ "contentRating": true }), false),
+        ];
+
+        for (plugin_info, expected) in cases {
+            let plugin_info =
+                serde_json::from_value::<HashMap<String, Value>>(plugin_info.clone()).unwrap();
+
+            assert_eq!(is_explicit(&plugin_info), expected, "{:?}", plugin_info);
+        }
+    }
+
+    #[test]
+    fn block_explicit_rejects_tracks() {
+        let explicit_track = |title: &str| LavalinkTrack {
+            plugin_info: HashMap::from([("isExplicit".to_owned(), Value::Bool(true))]),
+            ..lavalink_track(title)
+        };
+
+        for block_explicit in [false, true] {
+            let mut player = new_player(&["a"], 0, LoopMode::None);
+            player.block_explicit = block_explicit;
+
+            let add_queue_result = player.add_tracks(
+                FetchResult {
+                    selected: Some(2),
+                    tracks: vec![
+                        explicit_track("b"),
+                        lavalink_track("c"),
+                        explicit_track("d"),
+                    ],
+                },
+                UserId::new(1),
+                AddQueueOperation::End,
+                false,
+            );
+
+            if block_explicit {
+                assert_eq!(titles(&player), ["a", "c"]);
+                assert_eq!(add_queue_result.count, 1);
+                assert_eq!(add_queue_result.rejected, 2);
+                assert_eq!(add_queue_result.selected, None);
+            } else {
+                assert_eq!(titles(&player), ["a", "b", "c", "d"]);
+                assert_eq!(add_queue_result.count, 3);
+                assert_eq!(add_queue_result.rejected, 0);
+                assert_eq!(add_queue_result.selected, Some(3));
+            }
+        }
+    }
+
+    /// Create a player from the Lavalink node playing the track.
+    fn node_player(title: Option<&str>, paused: bool, position: u64) -> LavalinkPlayer {
+        LavalinkPlayer {
+            guild_id: "1".to_owned(),
+            track: title.map(lavalink_track),
+            volume: 100,
+            paused,
+            state: LavalinkPlayerState {
+                time: 0,
+                position,
+                connected: true,
+                ping: 0,
+            },
+            voice: VoiceState::new("token", "endpoint", "session"),
+            filters: Filters::default(),
+        }
+    }
+
+    #[test]
+    fn reconcile_divergent_track() {
+        let mut player = new_player(&["a", "b", "c"], 0, LoopMode::None);
+
+        assert!(player.reconcile(Some(node_player(Some("c"), true, 5000))));
+        assert_eq!(player.current_track, 2);
+        assert!(player.paused);
+        assert_eq!(player.position(), Some(5000));
+    }
+
+    #[test]
+    fn reconcile_unknown_track() {
+        let mut player = new_player(&["a", "b"], 1, LoopMode::None);
+
+        assert!(player.reconcile(Some(node_player(Some("z"), false, 1000))));
+        assert_eq!(player.current_track, 1);
+        assert!(!player.paused);
+        assert!(player.state.is_some());
+    }
+
+    #[test]
+    fn reconcile_without_node_track() {
+        for node_player in [None, Some(node_player(None, false, 0))] {
+            let mut player = new_player(&["a"], 0, LoopMode::None);
+            player.state = Some(LavalinkPlayerState {
+                time: 0,
+                position: 1000,
+                connected: true,
+                ping: 0,
+            });
+
+            assert!(!player.reconcile(node_player));
+            assert_eq!(player.current_track, 0);
+            assert!(player.state.is_none());
+        }
+    }
+
+    #[test]
+    fn should_autoplay_last_track() {
+        assert!(new_player(&["a", "b"], 1, LoopMode::Autoplay).should_autoplay());
+        assert!(!new_player(&["a", "b"], 0, LoopMode::Autoplay).should_autoplay());
+
+        for loop_mode in [
+            LoopMode::None,
+            LoopMode::AutoPause,
+            LoopMode::Single,
+            LoopMode::All,
+        ] {
+            assert!(
+                !new_player(&["a", "b"], 1, loop_mode).should_autoplay(),
+                "{:?}",
+                loop_mode
+            );
+        }
+    }
+
+    #[test]
+    fn replace_encoded_track() {
+        let mut player = new_player(&["a", "b", "a"], 0, LoopMode::None);
+
+        player.replace_encoded("a", "c".to_owned());
+
+        let encoded = player
+            .queue
+            .iter()
+            .map(|t| t.track.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(encoded, ["c", "b", "c"]);
+    }
+
+    #[test]
+    fn skip_empty_queue() {
+        for loop_mode in [LoopMode::None, LoopMode::All] {
+            let mut player = new_player(&[], 0, loop_mode);
+
+            assert_eq!(player.skip(), None);
+            assert_eq!(player.next_track(), None);
+            assert_eq!(player.current_track, 0);
+        }
+    }
+
+    #[test]
+    fn skip_single_track() {
+        let cases = [
+            (LoopMode::None, QueueUpdate::Stop),
+            (LoopMode::AutoPause, QueueUpdate::Stop),
+            (LoopMode::Single, QueueUpdate::Sync),
+            (LoopMode::All, QueueUpdate::Sync),
+            (LoopMode::Autoplay, QueueUpdate::Stop),
+        ];
+
+        for (loop_mode, expected) in cases {
+            let mut player = new_player(&["a"], 0, loop_mode);
+
+            assert_eq!(player.skip(), Some(expected), "{:?}", loop_mode);
+            assert_eq!(player.current_track, 0, "{:?}", loop_mode);
+        }
+    }
+
+    #[test]
+    fn skip_last_track_does_not_replay() {
+        let mut player = new_player(&["a", "b", "c"], 2, LoopMode::None);
+
+        assert_eq!(player.skip(), Some(QueueUpdate::Stop));
+        assert_eq!(player.current_track, 2);
+        assert_eq!(titles(&player), ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn skip_to_next_track() {
+        let mut player = new_player(&["a", "b", "c"], 1, LoopMode::None);
+        assert_eq!(player.skip(), Some(QueueUpdate::Sync));
+        assert_eq!(player.current_track, 2);
+        assert_eq!(player.skip(), Some(QueueUpdate::Stop));
+        assert_eq!(player.current_track, 2);
+
+        let mut player = new_player(&["a", "b", "c"], 2, LoopMode::All);
+        assert_eq!(player.skip(), Some(QueueUpdate::Sync));
+        assert_eq!(player.current_track, 0);
+    }
+
+    #[test]
+    fn next_track_single_track() {
+        let cases = [
+            (LoopMode::None, QueueUpdate::Message, false),
+            (LoopMode::AutoPause, QueueUpdate::Message, true),
+            (LoopMode::Single, QueueUpdate::Sync, false),
+            (LoopMode::All, QueueUpdate::Sync, false),
+        ];
+
+        for (loop_mode, expected, paused) in cases {
+            let mut player = new_player(&["a"], 0, loop_mode);
+
+            assert_eq!(player.next_track(), Some(expected), "{:?}", loop_mode);
+            assert_eq!(player.current_track, 0, "{:?}", loop_mode);
+            assert_eq!(player.paused, paused, "{:?}", loop_mode);
+        }
+    }
+
+    #[test]
+    fn next_track_uses_loop_mode() {
+        let cases = [
+            (LoopMode::None, 1, 2, QueueUpdate::Sync),
+            (LoopMode::None, 2, 2, QueueUpdate::Message),
+            (LoopMode::AutoPause, 1, 2, QueueUpdate::Message),
+            (LoopMode::Single, 1, 1, QueueUpdate::Sync),
+            (LoopMode::All, 1, 2, QueueUpdate::Sync),
+            (LoopMode::All, 2, 0, QueueUpdate::Sync),
+        ];
+
+        for (loop_mode, current_track, expected_track, expected) in cases {
+            let mut player = new_player(&["a", "b", "c"], current_track, loop_mode);
+
+            assert_eq!(player.next_track(), Some(expected), "{:?}", loop_mode);
+            assert_eq!(player.current_track, expected_track, "{:?}", loop_mode);
+        }
+    }
+
+    #[test]
+    fn remove_track_empty_queue() {
+        let mut player = new_player(&[], 0, LoopMode::None);
+
+        assert!(player.remove_track(0).is_none());
+        assert_eq!(player.current_track, 0);
+    }
+
+    #[test]
+    fn remove_track_keeps_current_track() {
+        let mut player = new_player(&["a", "b", "c"], 1, LoopMode::None);
+
+        let (track, update) = player.remove_track(0).unwrap();
+        assert_eq!(track.title, "a");
+        assert_eq!(update, QueueUpdate::Message);
+        assert_eq!(titles(&player)[player.current_track], "b");
+
+        let (_, update) = player.remove_track(1).unwrap();
+        assert_eq!(update, QueueUpdate::Message);
+        assert_eq!(titles(&player)[player.current_track], "b");
+    }
+
+    #[test]
+    fn remove_current_track() {
+        let mut player = new_player(&["a", "b", "c"], 1, LoopMode::None);
+        assert_eq!(player.remove_track(1).unwrap().1, QueueUpdate::Sync);
+        assert_eq!(titles(&player)[player.current_track], "c");
+
+        let mut player = new_player(&["a", "b", "c"], 2, LoopMode::None);
+        assert_eq!(player.remove_track(2).unwrap().1, QueueUpdate::Stop);
+        assert_eq!(player.current_track, 1);
+
+        let mut player = new_player(&["a", "b", "c"], 2, LoopMode::All);
+        assert_eq!(player.remove_track(2).unwrap().1, QueueUpdate::Sync);
+        assert_eq!(player.current_track, 0);
+    }
+
+    #[test]
+    fn remove_only_track() {
+        for loop_mode in [LoopMode::None, LoopMode::All] {
+            let mut player = new_player(&["a"], 0, loop_mode);
+
+            assert_eq!(player.remove_track(0).unwrap().1, QueueUpdate::Stop);
+            assert!(player.queue.is_empty());
+            assert_eq!(player.current_track, 0);
+            assert!(!player.shuffle());
+        }
+    }
+
+    #[test]
+    fn shuffle_remaining_keeps_played_tracks() {
+        let mut player = new_player(&["a", "b", "c", "d", "e", "f"], 2, LoopMode::None);
+
+        assert!(player.shuffle_remaining());
+        assert_eq!(player.current_track, 2);
+        assert_eq!(titles(&player)[..3], ["a", "b", "c"]);
+
+        let mut remaining = titles(&player)[3..].to_vec();
+        remaining.sort_unstable();
+        assert_eq!(remaining, ["d", "e", "f"]);
+    }
+
+    #[test]
+    fn shuffle_remaining_last_track() {
+        let mut player = new_player(&["a", "b", "c"], 2, LoopMode::None);
+
+        assert!(player.shuffle_remaining());
+        assert_eq!(titles(&player), ["a", "b", "c"]);
+        assert_eq!(player.current_track, 2);
+
+        let mut player = new_player(&[], 0, LoopMode::None);
+        assert!(!player.shuffle_remaining());
+    }
+
+    #[test]
+    fn move_track_keeps_current_track() {
+        // Moves before, across, and after the current track, in both directions.
+        let moves = [
+            (0, 1),
+            (1, 0),
+            (0, 3),
+            (3, 0),
+            (2, 4),
+            (4, 1),
+            (3, 4),
+            (4, 3),
+            (1, 2),
+            (2, 1),
+        ];
+
+        for (from, to) in moves {
+            let mut player = new_player(&["a", "b", "c", "d", "e"], 2, LoopMode::None);
+
+            assert!(player.move_track(from, to));
+            assert_eq!(titles(&player)[player.current_track], "c", "{from} -> {to}");
+        }
+    }
+
+    #[test]
+    fn move_track_reorders_queue() {
+        let mut player = new_player(&["a", "b", "c", "d"], 1, LoopMode::None);
+
+        assert!(player.move_track(0, 3));
+        assert_eq!(titles(&player), ["b", "c", "d", "a"]);
+        assert_eq!(player.current_track, 0);
+
+        assert!(player.move_track(3, 1));
+        assert_eq!(titles(&player), ["b", "a", "c", "d"]);
+        assert_eq!(player.current_track, 0);
+    }
+
+    #[test]
+    fn move_track_out_of_bounds() {
+        let mut player = new_player(&["a", "b"], 1, LoopMode::None);
+
+        assert!(!player.move_track(2, 0));
+        assert!(!player.move_track(0, 2));
+        assert_eq!(titles(&player), ["a", "b"]);
+        assert_eq!(player.current_track, 1);
+    }
+
+    #[test]
+    fn remove_range_keeps_current_track() {
+        let mut player = new_player(&["a", "b", "c", "d", "e"], 2, LoopMode::None);
+        assert_eq!(player.remove_range(3, 5), Some((2, QueueUpdate::Message)));
+        assert_eq!(titles(&player), ["a", "b", "c"]);
+        assert_eq!(player.current_track, 2);
+
+        let mut player = new_player(&["a", "b", "c"], 2, LoopMode::None);
+        assert_eq!(player.remove_range(0, 2), Some((2, QueueUpdate::Message)));
+        assert_eq!(titles(&player), ["c"]);
+        assert_eq!(player.current_track, 0);
+    }
+
+    #[test]
+    fn remove_range_with_current_track() {
+        let mut player = new_player(&["a", "b", "c", "d", "e"], 2, LoopMode::None);
+        assert_eq!(player.remove_range(1, 4), Some((3, QueueUpdate::Sync)));
+        assert_eq!(titles(&player), ["a", "e"]);
+        assert_eq!(titles(&player)[player.current_track], "e");
+
+        let mut player = new_player(&["a", "b", "c", "d", "e"], 2, LoopMode::None);
+        assert_eq!(player.remove_range(2, 5), Some((3, QueueUpdate::Stop)));
+        assert_eq!(titles(&player), ["a", "b"]);
+        assert_eq!(player.current_track, 1);
+
+        let mut player = new_player(&["a", "b", "c", "d", "e"], 2, LoopMode::All);
+        assert_eq!(player.remove_range(2, 5), Some((3, QueueUpdate::Sync)));
+        assert_eq!(player.current_track, 0);
+    }
+
+    #[test]
+    fn remove_range_empties_queue() {
+        for loop_mode in [LoopMode::None, LoopMode::All] {
+            let mut player = new_player(&["a", "b", "c"], 1, loop_mode);
+
+            assert_eq!(player.remove_range(0, 3), Some((3, QueueUpdate::Stop)));
+            assert!(player.queue.is_empty());
+            assert_eq!(player.current_track, 0);
+            assert!(player.remove_track(0).is_none());
+            assert_eq!(player.skip(), None);
+        }
+    }
+
+    #[test]
+    fn remove_range_invalid() {
+        let mut player = new_player(&["a", "b", "c"], 1, LoopMode::None);
+
+        assert_eq!(player.remove_range(2, 1), None);
+        assert_eq!(player.remove_range(0, 4), None);
+        assert_eq!(player.remove_range(1, 1), Some((0, QueueUpdate::Message)));
+        assert_eq!(titles(&player), ["a", "b", "c"]);
+        assert_eq!(player.current_track, 1);
+
+        let mut player = new_player(&[], 0, LoopMode::None);
+        assert_eq!(player.remove_range(0, 0), Some((0, QueueUpdate::Message)));
+        assert_eq!(player.remove_range(0, 1), None);
+    }
+
+    #[test]
+    fn shuffle_empty_queue() {
+        let mut player = new_player(&[], 0, LoopMode::None);
+
+        assert!(!player.shuffle());
+        assert!(player.queue.is_empty());
+    }
+
+    #[test]
+    fn shuffle_moves_current_track_to_start() {
+        let mut player = new_player(&["a", "b", "c", "d"], 2, LoopMode::None);
+
+        assert!(player.shuffle());
+        assert_eq!(player.current_track, 0);
+        assert_eq!(titles(&player)[0], "c");
+
+        let mut sorted = titles(&player);
+        sorted.sort_unstable();
+        assert_eq!(sorted, ["a", "b", "c", "d"]);
+    }
 }
@@ -1,19 +1,27 @@
 //! Shared behavior and logic between commands and components.
 
-use serenity::all::{CommandInteraction, ComponentInteraction, GuildId, Message, User};
+use serenity::all::{
+    CommandInteraction, ComponentInteraction, EditInteractionResponse, GuildId, Http, Message,
+    ModalInteraction, User,
+};
 
 pub mod pause;
 pub mod prev;
+pub mod queue;
+pub mod search;
+pub mod seek;
 pub mod shuffle;
 pub mod skip;
 pub mod stop;
 
-/// A wrapper for both [ComponentInteraction] and [CommandInteraction].
+/// A wrapper for [ComponentInteraction], [CommandInteraction] and [ModalInteraction].
 pub enum SharedInteraction<'a> {
     /// Wraps a [ComponentInteraction].
     Component(&'a ComponentInteraction),
     /// Wraps a [CommandInteraction].
     Command(&'a CommandInteraction),
+    /// Wraps a [ModalInteraction].
+    Modal(&'a ModalInteraction),
 }
 
 impl SharedInteraction<'_> {
@@ -22,6 +30,7 @@ impl SharedInteraction<'_> {
         match self {
             SharedInteraction::Component(i) => i.guild_id,
             SharedInteraction::Command(i) => i.guild_id,
+            SharedInteraction::Modal(i) => i.guild_id,
         }
     }
 
@@ -30,6 +39,7 @@ impl SharedInteraction<'_> {
         match self {
             SharedInteraction::Component(i) => &i.locale,
             SharedInteraction::Command(i) => &i.locale,
+            SharedInteraction::Modal(i) => &i.locale,
         }
     }
 
@@ -38,14 +48,29 @@ impl SharedInteraction<'_> {
         match self {
             SharedInteraction::Component(i) => &i.user,
             SharedInteraction::Command(i) => &i.user,
+            SharedInteraction::Modal(i) => &i.user,
         }
     }
 
-    /// Gets the message of the interaction if it is a [ComponentInteraction].
+    /// Edits the response of the interaction.
+    pub async fn edit_response(
+        &self,
+        http: &Http,
+        builder: EditInteractionResponse,
+    ) -> serenity::Result<Message> {
+        match self {
+            SharedInteraction::Component(i) => i.edit_response(http, builder).await,
+            SharedInteraction::Command(i) => i.edit_response(http, builder).await,
+            SharedInteraction::Modal(i) => i.edit_response(http, builder).await,
+        }
+    }
+
+    /// Gets the message of the interaction if it is a [ComponentInteraction] or a [ModalInteraction] from a component.
     pub fn message(&self) -> Option<&Message> {
         match self {
             SharedInteraction::Component(i) => Some(&i.message),
             SharedInteraction::Command(_) => None,
+            SharedInteraction::Modal(i) => i.message.as_deref(),
         }
     }
 }
@@ -1,6 +1,6 @@
 //! This module contains the shared behavior for the `previous` command and component.
 
-use crate::i18n::{t, t_vars};
+use crate::i18n::{LocalizeError, err, t_vars};
 use crate::music::Track;
 use crate::shared::SharedInteraction;
 use crate::utils::delete_player_message;
@@ -13,12 +13,12 @@ use tracing::{Level, event};
 pub async fn execute<'a>(context: &Context, interaction: &SharedInteraction<'_>) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id() else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(interaction.locale(), "error.not_in_guild"));
+        return err(interaction.locale(), "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(interaction.locale(), "error.unknown"));
+        return err(interaction.locale(), "error.unknown");
     };
 
     let voice_channel_id = match utils::get_voice_channel(
@@ -39,28 +39,28 @@ pub async fn execute<'a>(context: &Context, interaction: &SharedInteraction<'_>)
                 Ok(v) => v,
                 Err(e) => {
                     event!(Level::ERROR, error = ?e, "cannot go to the previous track");
-                    return Cow::borrowed(t(interaction.locale(), "error.unknown"));
+                    return e.localize(interaction.locale());
                 }
             };
 
             let Some(music) = music else {
-                return Cow::borrowed(t(interaction.locale(), "error.empty_queue"));
+                return err(interaction.locale(), "error.empty_queue");
             };
 
             get_message(music, interaction)
         } else {
-            Cow::borrowed(t(interaction.locale(), "error.not_in_voice_channel"))
+            err(interaction.locale(), "error.not_in_voice_chat")
         }
     } else {
         delete_player_message(context, interaction).await;
 
-        Cow::borrowed(t(interaction.locale(), "error.player_not_exists"))
+        err(interaction.locale(), "error.player_not_exists")
     }
 }
 
 /// Get the message to send to the user.
 fn get_message<'a>(track: Track, interaction: &SharedInteraction<'_>) -> Cow<'a, str> {
-    if let Some(uri) = track.url {
+    if let Some(uri) = track.public_url() {
         t_vars(
             interaction.locale(),
             "prev.returning_url",
@@ -0,0 +1,193 @@
+//! This module contains the shared behavior for the `queue` command and components.
+
+use crate::i18n::{err, t, t_vars};
+use crate::music::Track;
+use crate::shared::SharedInteraction;
+use crate::utils::constants::{HYDROGEN_PRIMARY_COLOR, HYDROGEN_QUEUE_PAGE_SIZE};
+use crate::utils::{relative_time, time_to_string};
+use crate::{PLAYER_MANAGER, utils};
+use beef::lean::Cow;
+use serenity::all::{
+    ButtonStyle, Context, CreateActionRow, CreateButton, CreateEmbed, CreateEmbedFooter,
+    EditInteractionResponse,
+};
+use tracing::{Level, event};
+
+/// Executes the `queue` shared behavior, showing the page with the current track if no page is provided.
+pub async fn execute<'a>(
+    context: &Context,
+    interaction: &SharedInteraction<'_>,
+    page: Option<usize>,
+) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id() else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(interaction.locale(), "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(interaction.locale(), "error.unknown");
+    };
+
+    let voice_channel_id = match utils::get_voice_channel(
+        context,
+        interaction.locale(),
+        guild_id,
+        interaction.user().id,
+    ) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let Some(my_channel_id) = manager.get_voice_channel_id(guild_id).await else {
+        return err(interaction.locale(), "error.player_not_exists");
+    };
+
+    if my_channel_id != voice_channel_id {
+        return err(interaction.locale(), "error.not_in_voice_chat");
+    }
+
+    let Some((current_track, queue_length)) = manager.get_queue_position(guild_id) else {
+        return err(interaction.locale(), "error.player_not_exists");
+    };
+
+    if queue_length == 0 {
+        return Cow::borrowed(t(interaction.locale(), "queue.empty"));
+    }
+
+    let page_count = page_count(queue_length);
+    // The queue may have changed since the page was requested.
+    let page = page.unwrap_or(page_of(current_track)).min(page_count - 1);
+    let offset = page * HYDROGEN_QUEUE_PAGE_SIZE;
+
+    let Some(tracks) = manager.get_queue(guild_id, offset, HYDROGEN_QUEUE_PAGE_SIZE) else {
+        return err(interaction.locale(), "error.player_not_exists");
+    };
+
+    let duration = manager.queue_duration(guild_id).unwrap_or_default();
+
+    let description = tracks
+        .into_iter()
+        .enumerate()
+        .map(|(i, track)| {
+            generate_entry(
+                interaction.locale(),
+                offset + i,
+                offset + i == current_track,
+                track,
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let embed = CreateEmbed::new()
+        .title(t(interaction.locale(), "queue.title"))
+        .description(description)
+        .color(HYDROGEN_PRIMARY_COLOR)
+        .footer(CreateEmbedFooter::new(
+            t_vars(
+                interaction.locale(),
+                "queue.page",
+                [
+                    (page + 1).to_string(),
+                    page_count.to_string(),
+                    time_to_string(duration.as_secs()),
+                ],
+            )
+            .into_owned(),
+        ));
+
+    let components = vec![CreateActionRow::Buttons(vec![
+        CreateButton::new(format!("queue_prev:{}", page.saturating_sub(1)))
+            .disabled(page == 0)
+            .emoji('⬅')
+            .style(ButtonStyle::Secondary),
+        CreateButton::new("queue_current")
+            .emoji('🎵')
+            .style(ButtonStyle::Primary),
+        CreateButton::new("queue_page")
+            .disabled(page_count <= 1)
+            .emoji('🔢')
+            .style(ButtonStyle::Secondary),
+        CreateButton::new(format!("queue_next:{}", page + 1))
+            .disabled(page + 1 >= page_count)
+            .emoji('➡')
+            .style(ButtonStyle::Secondary),
+    ])];
+
+    if let Err(e) = interaction
+        .edit_response(
+            &context.http,
+            EditInteractionResponse::new()
+                .embed(embed)
+                .components(components),
+        )
+        .await
+    {
+        event!(Level::ERROR, error = %e, "cannot send the queue");
+        return err(interaction.locale(), "error.discord");
+    }
+
+    Cow::borrowed("")
+}
+
+/// Gets the page of the queue containing the track at the index.
+fn page_of(index: usize) -> usize {
+    index / HYDROGEN_QUEUE_PAGE_SIZE
+}
+
+/// Gets how many pages are needed to show the queue.
+fn page_count(queue_length: usize) -> usize {
+    queue_length.div_ceil(HYDROGEN_QUEUE_PAGE_SIZE)
+}
+
+/// Generates the line of a track in the queue.
+fn generate_entry(locale: &str, index: usize, current: bool, track: Track) -> String {
+    t_vars(
+        locale,
+        if current {
+            "queue.current_entry"
+        } else {
+            "queue.entry"
+        },
+        [
+            (index + 1).to_string(),
+            track.title,
+            track.author,
+            if track.is_stream {
+                t(locale, "player.live").to_owned()
+            } else {
+                time_to_string(track.duration / 1000)
+            },
+            relative_time(locale, track.added_at.elapsed()),
+        ],
+    )
+    .into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_of_index() {
+        assert_eq!(page_of(0), 0);
+        assert_eq!(page_of(HYDROGEN_QUEUE_PAGE_SIZE - 1), 0);
+        assert_eq!(page_of(HYDROGEN_QUEUE_PAGE_SIZE), 1);
+        assert_eq!(page_of(HYDROGEN_QUEUE_PAGE_SIZE * 3 + 1), 3);
+    }
+
+    #[test]
+    fn page_count_of_queue() {
+        assert_eq!(page_count(1), 1);
+        assert_eq!(page_count(HYDROGEN_QUEUE_PAGE_SIZE), 1);
+        assert_eq!(page_count(HYDROGEN_QUEUE_PAGE_SIZE + 1), 2);
+    }
+
+    #[test]
+    fn last_track_is_in_the_last_page() {
+        for length in 1..HYDROGEN_QUEUE_PAGE_SIZE * 3 {
+            assert_eq!(page_of(length - 1), page_count(length) - 1);
+        }
+    }
+}
@@ -0,0 +1,25 @@
+//! This module contains the choices shared between the `search` command and the `search_select` component.
+
+use std::sync::LazyLock;
+
+use hydrolink::Track as LavalinkTrack;
+use moka::sync::Cache;
+use serenity::all::{MessageId, UserId};
+
+use crate::utils::constants::HYDROGEN_SEARCH_TIMEOUT;
+
+/// Cache of the choices sent by `/search`, keyed by the message with the select menu.
+pub static SEARCH_CHOICES: LazyLock<Cache<MessageId, SearchChoices>> = LazyLock::new(|| {
+    Cache::builder()
+        .time_to_live(HYDROGEN_SEARCH_TIMEOUT)
+        .build()
+});
+
+#[derive(Debug, Clone)]
+/// The tracks that can be chosen from a search.
+pub struct SearchChoices {
+    /// The user who searched the tracks.
+    pub requester: UserId,
+    /// The tracks found by the search.
+    pub tracks: Vec<LavalinkTrack>,
+}
@@ -0,0 +1,79 @@
+//! This module contains the shared behavior for the `forward` and `rewind` commands.
+
+use crate::i18n::{LocalizeError, err, t, t_vars};
+use crate::music::Error;
+use crate::shared::SharedInteraction;
+use crate::utils::time_parsers::parse_duration;
+use crate::utils::{progress_bar, time_to_string};
+use crate::{PLAYER_MANAGER, utils};
+use beef::lean::Cow;
+use serenity::all::Context;
+use tracing::{Level, event};
+
+/// Executes the relative seek shared behavior, seeking forward or back by the time provided.
+pub async fn execute<'a>(
+    context: &Context,
+    interaction: &SharedInteraction<'_>,
+    time: Option<&str>,
+    forward: bool,
+) -> Cow<'a, str> {
+    let Some(guild_id) = interaction.guild_id() else {
+        event!(Level::WARN, "interaction.guild_id is None");
+        return err(interaction.locale(), "error.not_in_guild");
+    };
+
+    let Some(manager) = PLAYER_MANAGER.get() else {
+        event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
+        return err(interaction.locale(), "error.unknown");
+    };
+
+    let Some(delta) = time.and_then(parse_duration) else {
+        event!(Level::INFO, syntax = time, "invalid syntax provided");
+        return Cow::borrowed(t(interaction.locale(), "time.invalid_syntax"));
+    };
+
+    let delta = delta.as_millis() as i64;
+
+    let voice_channel_id = match utils::get_voice_channel(
+        context,
+        interaction.locale(),
+        guild_id,
+        interaction.user().id,
+    ) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let Some(my_channel_id) = manager.get_voice_channel_id(guild_id).await else {
+        return err(interaction.locale(), "error.player_not_exists");
+    };
+
+    if my_channel_id != voice_channel_id {
+        return err(interaction.locale(), "error.not_in_voice_chat");
+    }
+
+    let seek_result = match manager
+        .seek_relative(guild_id, if forward { delta } else { -delta })
+        .await
+    {
+        Ok(Some(v)) => v,
+        Ok(None) => return err(interaction.locale(), "error.empty_queue"),
+        Err(Error::TrackNotSeekable) => {
+            return Cow::borrowed(t(interaction.locale(), "time.not_seekable"));
+        }
+        Err(e) => {
+            event!(Level::ERROR, error = ?e, "cannot seek the player");
+            return e.localize(interaction.locale());
+        }
+    };
+
+    t_vars(
+        interaction.locale(),
+        "time.result",
+        [
+            time_to_string(seek_result.position / 1000),
+            time_to_string(seek_result.total / 1000),
+            progress_bar(seek_result.position, seek_result.total),
+        ],
+    )
+}
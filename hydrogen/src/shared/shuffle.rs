@@ -1,6 +1,6 @@
 //! This module contains the shared behavior for the `shuffle` command and component.
 
-use crate::i18n::t;
+use crate::i18n::{err, t};
 use crate::shared::SharedInteraction;
 use crate::utils::player_not_exists;
 use crate::{PLAYER_MANAGER, utils};
@@ -8,16 +8,20 @@ use beef::lean::Cow;
 use serenity::all::Context;
 use tracing::{Level, event};
 
-/// Executes the `shuffle` shared behavior.
-pub async fn execute<'a>(context: &Context, interaction: &SharedInteraction<'_>) -> Cow<'a, str> {
+/// Executes the `shuffle` shared behavior, shuffling only the tracks after the current one when `remaining` is true.
+pub async fn execute<'a>(
+    context: &Context,
+    interaction: &SharedInteraction<'_>,
+    remaining: bool,
+) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id() else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(interaction.locale(), "error.not_in_guild"));
+        return err(interaction.locale(), "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(interaction.locale(), "error.unknown"));
+        return err(interaction.locale(), "error.unknown");
     };
 
     let voice_channel_id = match utils::get_voice_channel(
@@ -34,15 +38,23 @@ pub async fn execute<'a>(context: &Context, interaction: &SharedInteraction<'_>)
 
     if let Some(my_channel_id) = player_state {
         if my_channel_id == voice_channel_id {
-            if let Err(e) = manager.shuffle(guild_id) {
-                event!(Level::WARN, error = %e, "cannot shuffle queue");
-
-                player_not_exists(context, interaction).await
+            let result = if remaining {
+                manager.shuffle_remaining(guild_id)
             } else {
-                Cow::borrowed(t(interaction.locale(), "shuffle.result"))
+                manager.shuffle(guild_id)
+            };
+
+            match result {
+                Ok(true) => Cow::borrowed(t(interaction.locale(), "shuffle.result")),
+                Ok(false) => err(interaction.locale(), "error.empty_queue"),
+                Err(e) => {
+                    event!(Level::WARN, error = %e, "cannot shuffle queue");
+
+                    player_not_exists(context, interaction).await
+                }
             }
         } else {
-            Cow::borrowed(t(interaction.locale(), "error.not_in_voice_channel"))
+            err(interaction.locale(), "error.not_in_voice_chat")
         }
     } else {
         player_not_exists(context, interaction).await
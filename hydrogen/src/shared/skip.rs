@@ -1,7 +1,7 @@
 //! This module contains the shared behavior for the `skip` command and component.
 
-use crate::i18n::{t, t_vars};
-use crate::music::Track;
+use crate::i18n::{LocalizeError, err, t, t_vars};
+use crate::music::{Track, VoteSkipOutcome};
 use crate::shared::SharedInteraction;
 use crate::utils::delete_player_message;
 use crate::{PLAYER_MANAGER, utils};
@@ -13,12 +13,12 @@ use tracing::{Level, event};
 pub async fn execute<'a>(context: &Context, interaction: &SharedInteraction<'_>) -> Cow<'a, str> {
     let Some(guild_id) = interaction.guild_id() else {
         event!(Level::WARN, "interaction.guild_id is None");
-        return Cow::borrowed(t(interaction.locale(), "error.not_in_guild"));
+        return err(interaction.locale(), "error.not_in_guild");
     };
 
     let Some(manager) = PLAYER_MANAGER.get() else {
         event!(Level::ERROR, "PLAYER_MANAGER.get() returned None");
-        return Cow::borrowed(t(interaction.locale(), "error.unknown"));
+        return err(interaction.locale(), "error.unknown");
     };
 
     let voice_channel_id = match utils::get_voice_channel(
@@ -35,32 +35,40 @@ pub async fn execute<'a>(context: &Context, interaction: &SharedInteraction<'_>)
 
     if let Some(my_channel_id) = my_channel_id {
         if my_channel_id == voice_channel_id {
-            let music = match manager.skip(guild_id).await {
-                Ok(v) => v,
+            let listeners = utils::count_listeners(context, guild_id, my_channel_id);
+
+            let music = match manager
+                .vote_skip(guild_id, interaction.user().id, listeners)
+                .await
+            {
+                Ok(VoteSkipOutcome::Skipped(v)) => v,
+                Ok(VoteSkipOutcome::Voted { votes, required }) => {
+                    return t_vars(interaction.locale(), "skip.voted", [votes, required]);
+                }
                 Err(e) => {
                     event!(Level::ERROR, error = ?e, "cannot go to the next track");
-                    return Cow::borrowed(t(interaction.locale(), "error.unknown"));
+                    return e.localize(interaction.locale());
                 }
             };
 
             let Some(music) = music else {
-                return Cow::borrowed(t(interaction.locale(), "error.empty_queue"));
+                return Cow::borrowed(t(interaction.locale(), "skip.queue_ended"));
             };
 
-            get_message(music, interaction)
+            get_message(*music, interaction)
         } else {
-            Cow::borrowed(t(interaction.locale(), "error.not_in_voice_channel"))
+            err(interaction.locale(), "error.not_in_voice_chat")
         }
     } else {
         delete_player_message(context, interaction).await;
 
-        Cow::borrowed(t(interaction.locale(), "error.player_not_exists"))
+        err(interaction.locale(), "error.player_not_exists")
     }
 }
 
 /// Get the message to send to the user.
 fn get_message<'a>(track: Track, interaction: &SharedInteraction<'_>) -> Cow<'a, str> {
-    if let Some(uri) = track.url {
+    if let Some(uri) = track.public_url() {
         t_vars(
             interaction.locale(),
             "skip.skipping_url",
@@ -8,18 +8,83 @@ pub const HYDROGEN_PRIMARY_COLOR: i32 = 0x0db363;
 /// Time in seconds to wait before exit from an empty voice channel.
 pub const HYDROGEN_EMPTY_CHAT_TIMEOUT: u64 = 10;
 
-/// How many music tracks can be stored in the queue.
+/// How many music tracks can be stored in the queue by default.
 pub const HYDROGEN_QUEUE_LIMIT: usize = 1000;
 
+/// The maximum queue limit that can be configured for a player.
+pub const HYDROGEN_MAX_QUEUE_LIMIT: usize = 10000;
+
+/// The maximum size, in bytes, of a playlist file that can be imported.
+pub const HYDROGEN_PLAYLIST_FILE_LIMIT: u32 = 1024 * 1024;
+
+/// How many music tracks are shown in each page of the queue.
+pub const HYDROGEN_QUEUE_PAGE_SIZE: usize = 10;
+
+/// The default volume of the players, in percentage.
+pub const HYDROGEN_DEFAULT_VOLUME: u16 = 100;
+
+/// The default volume of the players created with the RPG template, in percentage.
+pub const HYDROGEN_RPG_VOLUME: u16 = 40;
+
+/// The maximum volume allowed by Lavalink, in percentage.
+pub const HYDROGEN_MAX_VOLUME: u16 = 1000;
+
+/// The domains of the links handled by each Lavalink source manager.
+pub static HYDROGEN_URL_SOURCES: [(&str, &str); 10] = [
+    ("youtube.com", "youtube"),
+    ("youtu.be", "youtube"),
+    ("soundcloud.com", "soundcloud"),
+    ("bandcamp.com", "bandcamp"),
+    ("twitch.tv", "twitch"),
+    ("vimeo.com", "vimeo"),
+    ("spotify.com", "spotify"),
+    ("music.apple.com", "applemusic"),
+    ("deezer.com", "deezer"),
+    ("music.yandex.ru", "yandexmusic"),
+];
+
 /// The search prefixes for the music.
 pub static HYDROGEN_SEARCH_PREFIXES: [&str; 3] = ["ytsearch:", "dzsearch:", "scsearch:"];
 
+/// The amount of tracks shown as choices by `/search`.
+pub const HYDROGEN_SEARCH_CHOICES: usize = 5;
+
+/// How long the choices from `/search` can be selected.
+pub const HYDROGEN_SEARCH_TIMEOUT: Duration = Duration::from_secs(60);
+
 /// Connection timeout for the Lavalink node in seconds.
 pub const LAVALINK_RECONNECTION_DELAY: u64 = 5;
 
+/// The voice close code sent when the voice session is no longer valid.
+pub const DISCORD_VOICE_SESSION_INVALID: u32 = 4006;
+
+/// The voice close code sent when the voice session has timed out.
+pub const DISCORD_VOICE_SESSION_TIMEOUT: u32 = 4009;
+
+/// The voice close code sent when the bot is disconnected from the voice channel.
+pub const DISCORD_VOICE_DISCONNECTED: u32 = 4014;
+
+/// The maximum length of an embed description.
+pub const DISCORD_EMBED_DESCRIPTION_LIMIT: usize = 4096;
+
+/// The minimum length of the `/play` query to search for autocomplete choices.
+pub const HYDROGEN_AUTOCOMPLETE_MIN_LENGTH: usize = 3;
+
+/// The maximum amount of choices in an autocomplete response.
+pub const DISCORD_AUTOCOMPLETE_CHOICES: usize = 25;
+
+/// The maximum length of the name and the value of an autocomplete choice.
+pub const DISCORD_AUTOCOMPLETE_CHOICE_LIMIT: usize = 100;
+
+/// The maximum length of the label and the description of a select menu option.
+pub const DISCORD_SELECT_OPTION_LIMIT: usize = 100;
+
 /// The user agent used for the Lavalink node.
 pub static HYDROGEN_USER_AGENT: &str = concat!("Hydrogen/", env!("CARGO_PKG_VERSION"),);
 
+/// The time to wait for Hydrogen to be initialized before handling an interaction.
+pub const HYDROGEN_READY_TIMEOUT: Duration = Duration::from_secs(2);
+
 /// The time in milliseconds to consider a ready event as slow.
 pub const HYDROGEN_READY_THRESHOLD: Duration = Duration::from_millis(600);
 
@@ -34,3 +99,27 @@ pub const HYDROGEN_UPDATE_VOICE_SERVER_THRESHOLD: Duration = Duration::from_mill
 
 /// The time in milliseconds to consider a lavalink event as slow.
 pub const HYDROGEN_LAVALINK_EVENT_THRESHOLD: Duration = Duration::from_millis(1000);
+
+/// The time to wait for the players to be migrated from a disconnected node before recreating them.
+pub const HYDROGEN_MIGRATION_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How many workers process the Lavalink messages.
+pub const HYDROGEN_LAVALINK_WORKERS: usize = 4;
+
+/// How many Lavalink messages can wait to be processed before stop reading new ones.
+pub const HYDROGEN_LAVALINK_QUEUE_SIZE: usize = 64;
+
+/// The time to wait before sending another message about a track exception in the same player.
+pub const HYDROGEN_TRACK_EXCEPTION_COOLDOWN: Duration = Duration::from_secs(5);
+
+/// How many players are paused or resumed at the same time by `PlayerManager::pause_all` and `PlayerManager::resume_all`.
+pub const HYDROGEN_PAUSE_ALL_CONCURRENCY: usize = 8;
+
+/// How often the live now-playing message is updated.
+pub const HYDROGEN_NOWPLAYING_LIVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// How often the progress in the now-playing message is updated when the split view is enabled.
+pub const HYDROGEN_SPLIT_VIEW_UPDATE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// How long the live now-playing message is updated, interaction tokens are only valid for 15 minutes.
+pub const HYDROGEN_NOWPLAYING_LIVE_LIMIT: Duration = Duration::from_secs(10 * 60);
@@ -1,43 +1,114 @@
 //! Utilities that can be shared between commands and components.
 
-use crate::i18n::t;
+use crate::i18n::{err, t_vars};
 use crate::shared::SharedInteraction;
 use beef::lean::Cow;
 use serenity::all::{ChannelId, Context, GuildId, UserId};
-use songbird::Songbird;
+use songbird::{Call, Songbird};
 use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
 use tracing::{Level, event};
 
 pub mod constants;
 pub mod time_parsers;
 
-/// Converts a time in seconds to a string.
+/// Converts a time in seconds to a string, using the `00:00`, `00:00:00`, or `0d 00:00:00` format depending on its length.
 pub fn time_to_string(seconds: u64) -> String {
-    if seconds < 60 {
-        return format!("00:{:02}", seconds);
-    } else if seconds < 60 * 60 {
-        let time = seconds as f32;
-        let minutes = (time / 60.0).floor();
-        let seconds = time - minutes * 60.0;
-        return format!("{:02}:{:02}", minutes as u32, seconds as u32);
+    let days = seconds / (60 * 60 * 24);
+    let hours = seconds / (60 * 60) % 24;
+    let minutes = seconds / 60 % 60;
+    let seconds = seconds % 60;
+
+    if days > 0 {
+        format!("{}d {:02}:{:02}:{:02}", days, hours, minutes, seconds)
+    } else if hours > 0 {
+        format!("{:02}:{:02}:{:02}", hours, minutes, seconds)
+    } else {
+        format!("{:02}:{:02}", minutes, seconds)
     }
+}
 
-    let time = seconds as f32;
-    let hours = (time / 60.0 / 60.0).floor();
-    let minutes = (time - hours * 60.0 * 60.0).floor();
-    let seconds = time - minutes * 60.0 - hours * 60.0 * 60.0;
-    format!(
-        "{:02}:{:02}:{:02}",
-        hours as u32, minutes as u32, seconds as u32
-    )
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// The appearance of a progress bar.
+pub struct ProgressBarStyle {
+    /// The amount of cells in the bar, without the caps.
+    pub width: usize,
+    /// The character used for the cells already passed.
+    pub filled: char,
+    /// The character used for the remaining cells.
+    pub empty: char,
+    /// The character at the start of the bar.
+    pub left_cap: char,
+    /// The character at the end of the bar.
+    pub right_cap: char,
+}
+
+impl Default for ProgressBarStyle {
+    fn default() -> Self {
+        Self {
+            width: 30,
+            filled: '▓',
+            empty: '░',
+            left_cap: '╣',
+            right_cap: '╠',
+        }
+    }
 }
 
-/// Creates a progress bar.
+/// Creates a progress bar with the default style.
 pub fn progress_bar(current: u64, total: u64) -> String {
-    let item_total = 30usize;
-    let item_count = (current as f32 / (total as f32 / item_total as f32)).round();
-    let bar = "▓".repeat(item_count as usize);
-    format!("╣{:░<width$.width$}╠", bar, width = item_total)
+    progress_bar_styled(current, total, &ProgressBarStyle::default())
+}
+
+/// Creates a progress bar with a custom style, showing an empty bar if the total is zero.
+pub fn progress_bar_styled(current: u64, total: u64, style: &ProgressBarStyle) -> String {
+    let filled_count = if total == 0 {
+        0
+    } else {
+        ((current as f64 / total as f64) * style.width as f64)
+            .round()
+            .min(style.width as f64) as usize
+    };
+
+    let mut bar = String::with_capacity((style.width + 2) * 3);
+    bar.push(style.left_cap);
+    bar.extend(std::iter::repeat_n(style.filled, filled_count));
+    bar.extend(std::iter::repeat_n(style.empty, style.width - filled_count));
+    bar.push(style.right_cap);
+    bar
+}
+
+/// Truncates a text to a maximum amount of characters, ending it with an ellipsis when truncated.
+pub fn truncate(text: &str, limit: usize) -> String {
+    if text.chars().count() <= limit {
+        return text.to_owned();
+    }
+
+    let mut truncated = text
+        .chars()
+        .take(limit.saturating_sub(1))
+        .collect::<String>();
+    truncated.push('…');
+
+    truncated
+}
+
+/// Converts an elapsed time to a localized relative time. (e.g. "2m ago")
+pub fn relative_time(locale: &str, elapsed: Duration) -> String {
+    let seconds = elapsed.as_secs();
+
+    let (key, value) = if seconds < 60 {
+        ("relative_time.seconds", seconds)
+    } else if seconds < 60 * 60 {
+        ("relative_time.minutes", seconds / 60)
+    } else if seconds < 60 * 60 * 24 {
+        ("relative_time.hours", seconds / 60 / 60)
+    } else {
+        ("relative_time.days", seconds / 60 / 60 / 24)
+    };
+
+    t_vars(locale, key, [value]).into_owned()
 }
 
 /// Gets the voice essentials for a user.
@@ -51,12 +122,55 @@ pub async fn get_voice_essentials<'a>(
 
     let Some(voice_manager) = songbird::get(context).await else {
         event!(Level::ERROR, "songbird::get() returned None");
-        return Err(Cow::borrowed(t(locale, "error.unknown")));
+        return Err(err(locale, "error.unknown"));
     };
 
     Ok((voice_manager, voice_channel_id))
 }
 
+/// Joins the voice channel of a user, making sure the bot isn't connected to another voice channel.
+pub async fn join_voice_channel<'a>(
+    context: &Context,
+    locale: &str,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> Result<(), Cow<'a, str>> {
+    let (voice_manager, voice_channel_id) =
+        get_voice_essentials(context, locale, guild_id, user_id).await?;
+
+    let call = match voice_manager.get(guild_id) {
+        Some(v) if v.lock().await.current_connection().is_some() => v,
+        _ => join_gateway(&voice_manager, guild_id, voice_channel_id, locale).await?,
+    };
+
+    if let Some(connection_info) = call.lock().await.current_connection() {
+        if let Some(channel_id) = connection_info.channel_id {
+            if channel_id != voice_channel_id.into() {
+                return Err(err(locale, "error.not_in_voice_chat"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Joins the voice channel through the gateway.
+async fn join_gateway<'a>(
+    voice_manager: &Arc<Songbird>,
+    guild_id: GuildId,
+    voice_channel_id: ChannelId,
+    locale: &str,
+) -> Result<Arc<Mutex<Call>>, Cow<'a, str>> {
+    voice_manager
+        .join_gateway(guild_id, voice_channel_id)
+        .await
+        .map(|e| e.1)
+        .map_err(|e| {
+            event!(Level::INFO, voice_channel_id = %voice_channel_id, error = ?e, "cannot join the voice channel");
+            err(locale, "error.cant_connect")
+        })
+}
+
 /// Gets the voice channel of a user.
 pub fn get_voice_channel<'a>(
     context: &Context,
@@ -71,12 +185,33 @@ pub fn get_voice_channel<'a>(
             .and_then(|voice_state| voice_state.channel_id)
     }) else {
         event!(Level::INFO, "user voice state is None");
-        return Err(Cow::borrowed(t(locale, "error.unknown_voice_state")));
+        return Err(err(locale, "error.unknown_voice_state"));
     };
 
     Ok(voice_channel_id)
 }
 
+/// Counts the users connected to the voice channel that aren't bots.
+pub fn count_listeners(context: &Context, guild_id: GuildId, channel_id: ChannelId) -> usize {
+    context
+        .cache
+        .guild(guild_id)
+        .map(|guild| {
+            guild
+                .voice_states
+                .values()
+                .filter(|voice_state| voice_state.channel_id == Some(channel_id))
+                .filter(|voice_state| {
+                    !guild
+                        .members
+                        .get(&voice_state.user_id)
+                        .is_some_and(|member| member.user.bot)
+                })
+                .count()
+        })
+        .unwrap_or_default()
+}
+
 /// Deletes the old player message.
 pub async fn delete_player_message(context: &Context, interaction: &SharedInteraction<'_>) {
     if let Some(message) = interaction.message() {
@@ -93,5 +228,65 @@ pub async fn player_not_exists<'a>(
 ) -> Cow<'a, str> {
     delete_player_message(context, interaction).await;
 
-    Cow::borrowed(t(interaction.locale(), "error.player_not_exists"))
+    err(interaction.locale(), "error.player_not_exists")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_to_string_formats() {
+        let cases = [
+            (0, "00:00"),
+            (59, "00:59"),
+            (60, "01:00"),
+            (3599, "59:59"),
+            (3600, "01:00:00"),
+            (86399, "23:59:59"),
+            (86400, "1d 00:00:00"),
+            (86401, "1d 00:00:01"),
+            (90061, "1d 01:01:01"),
+            (3 * 86400 + 7200, "3d 02:00:00"),
+            (100 * 86400 + 86399, "100d 23:59:59"),
+        ];
+
+        for (seconds, expected) in cases {
+            assert_eq!(time_to_string(seconds), expected, "{}", seconds);
+        }
+    }
+
+    #[test]
+    fn relative_time_boundaries() {
+        let cases = [
+            (0, "0s ago"),
+            (1, "1s ago"),
+            (2, "2s ago"),
+            (59, "59s ago"),
+            (60, "1m ago"),
+            (119, "1m ago"),
+            (120, "2m ago"),
+            (3599, "59m ago"),
+            (3600, "1h ago"),
+            (7200, "2h ago"),
+            (86399, "23h ago"),
+            (86400, "1d ago"),
+            (172800, "2d ago"),
+        ];
+
+        for (seconds, expected) in cases {
+            assert_eq!(
+                relative_time("en-US", Duration::from_secs(seconds)),
+                expected,
+                "{}",
+                seconds
+            );
+        }
+    }
+
+    #[test]
+    fn relative_time_is_translated() {
+        assert_eq!(relative_time("pt-BR", Duration::from_secs(1)), "há 1s");
+        assert_eq!(relative_time("es-ES", Duration::from_secs(7200)), "hace 2h");
+    }
 }
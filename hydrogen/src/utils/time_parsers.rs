@@ -64,3 +64,154 @@ pub fn semicolon_syntax(data: &str) -> Option<Duration> {
         }
     }
 }
+
+/// Regex for the `0h0m0s` syntax, where every part is optional but they must be in this order.
+static TIME_COMPOUND_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?:([0-9]{1,3})[hH])?(?:([0-9]{1,3})[mM])?(?:([0-9]{1,3})[sS])?$")
+        .expect("failed to compile the regex for time compound parser")
+});
+
+/// Parses the `0h0m0s` syntax, like `1h30m` or `2m30s`.
+pub fn compound_syntax(data: &str) -> Option<Duration> {
+    if data.is_empty() {
+        return None;
+    }
+
+    let captures = TIME_COMPOUND_REGEX.captures(data)?;
+
+    let mut seconds = 0;
+
+    for (index, multiplier) in [(1, 60 * 60), (2, 60), (3, 1)] {
+        if let Some(value) = captures.get(index) {
+            seconds += value.as_str().parse::<u64>().ok()? * multiplier;
+        }
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses a duration written in any of the supported syntaxes: `90`, `45s`, `2m`, `1h`, `1h30m`, `01:30`, and `01:00:30`.
+pub fn parse_duration(data: &str) -> Option<Duration> {
+    let data = data.trim();
+
+    suffix_syntax(data)
+        .or_else(|| semicolon_syntax(data))
+        .or_else(|| compound_syntax(data))
+}
+
+/// Regex for the timestamp parameters (`t` and `start`) in YouTube URLs.
+static YOUTUBE_TIMESTAMP_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"^https?://(?:(?:www|m|music)\.)?(?:youtube\.com|youtu\.be)/[^#]*[?&](?:t|start)=(?:([0-9]+)h)?(?:([0-9]+)m)?(?:([0-9]+)s?)?(?:&|#|$)",
+    )
+    .expect("failed to compile the regex for YouTube timestamp parser")
+});
+
+/// Parses the timestamp from a YouTube URL, supporting the `t=90`, `t=1m30s`, and `start=90` syntaxes.
+pub fn youtube_timestamp(url: &str) -> Option<Duration> {
+    let captures = YOUTUBE_TIMESTAMP_REGEX.captures(url.trim())?;
+
+    let mut seconds = 0;
+
+    // The values aren't limited in length, so huge timestamps are rejected instead of overflowing.
+    for (index, multiplier) in [(1, 60 * 60), (2, 60), (3, 1)] {
+        if let Some(value) = captures.get(index) {
+            seconds = value
+                .as_str()
+                .parse::<u64>()
+                .ok()?
+                .checked_mul(multiplier)
+                .and_then(|v| v.checked_add(seconds))?;
+        }
+    }
+
+    (seconds > 0).then(|| Duration::from_secs(seconds))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_duration_syntaxes() {
+        let cases = [
+            ("90", Some(90)),
+            ("90s", Some(90)),
+            ("45S", Some(45)),
+            ("2m", Some(120)),
+            ("1h", Some(3600)),
+            ("1h30m", Some(5400)),
+            ("2m30s", Some(150)),
+            ("1h2m3s", Some(3723)),
+            ("1H2M3S", Some(3723)),
+            ("1h3s", Some(3603)),
+            ("01:30", Some(90)),
+            ("01:00:30", Some(3630)),
+            ("  1m  ", Some(60)),
+            ("", None),
+            ("abc", None),
+            ("1x", None),
+            ("-5", None),
+            ("1.5m", None),
+            ("1234s", None),
+            ("1h30", None),
+            ("3s2m", None),
+            ("1m1h", None),
+            ("1h1h", None),
+            ("01:60", None),
+            ("1:60:00", None),
+            ("1::30", None),
+        ];
+
+        for (data, expected) in cases {
+            assert_eq!(
+                parse_duration(data),
+                expected.map(Duration::from_secs),
+                "{:?}",
+                data
+            );
+        }
+    }
+
+    #[test]
+    fn youtube_timestamp_syntaxes() {
+        let cases = [
+            ("https://www.youtube.com/watch?v=id&t=90", Some(90)),
+            ("https://youtu.be/id?t=1m30s", Some(90)),
+            ("https://www.youtube.com/watch?v=id&start=3600", Some(3600)),
+            ("https://www.youtube.com/watch?v=id&t=1h2m3s", Some(3723)),
+            ("https://www.youtube.com/watch?v=id", None),
+            ("https://www.youtube.com/watch?v=id&t=0", None),
+            ("https://example.com/watch?v=id&t=90", None),
+        ];
+
+        for (url, expected) in cases {
+            assert_eq!(
+                youtube_timestamp(url),
+                expected.map(Duration::from_secs),
+                "{}",
+                url
+            );
+        }
+    }
+
+    #[test]
+    fn youtube_timestamp_overflow() {
+        assert_eq!(
+            youtube_timestamp("https://www.youtube.com/watch?v=id&t=9999999999999999h"),
+            None
+        );
+        assert_eq!(
+            youtube_timestamp("https://www.youtube.com/watch?v=id&t=99999999999999999999"),
+            None
+        );
+        assert_eq!(
+            youtube_timestamp(&format!(
+                "https://www.youtube.com/watch?v=id&t={}h{}s",
+                u64::MAX / 3600,
+                u64::MAX
+            )),
+            None
+        );
+    }
+}
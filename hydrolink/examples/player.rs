@@ -0,0 +1,95 @@
+//! Minimal standalone player, playing a track in a voice channel and printing the player updates.
+//!
+//! The voice state must come from the Discord gateway, as Hydrolink doesn't connect to Discord, so this example reads
+//! it from the environment variables, together with the Lavalink node:
+//!
+//! - `LAVALINK_HOST`: the host of the Lavalink node. (e.g. `localhost:2333`)
+//! - `LAVALINK_PASSWORD`: the password of the Lavalink node.
+//! - `USER_ID`: the bot's user ID.
+//! - `GUILD_ID`: the guild where the bot is connected to a voice channel.
+//! - `VOICE_TOKEN`, `VOICE_ENDPOINT` and `VOICE_SESSION_ID`: the voice state of the bot in the guild.
+//! - `QUERY`: the track to be played, using any identifier supported by Lavalink. (e.g. `ytsearch:never gonna give you up`)
+//!
+//! Run it with `cargo run --example player`.
+
+use std::env;
+
+use hydrolink::{
+    LAVALINK_CLIENT_NAME, Lavalink, LoadResult, Message, Rest, UpdatePlayer, UpdatePlayerTrack,
+    VoiceState,
+};
+
+#[tokio::main]
+async fn main() -> hydrolink::Result<()> {
+    let host = var("LAVALINK_HOST");
+    let password = var("LAVALINK_PASSWORD");
+    let user_id = var("USER_ID");
+    let guild_id = var("GUILD_ID");
+    let query = var("QUERY");
+
+    let voice = VoiceState::new(
+        &var("VOICE_TOKEN"),
+        &var("VOICE_ENDPOINT"),
+        &var("VOICE_SESSION_ID"),
+    );
+
+    let rest = Rest::new(&host, &password, LAVALINK_CLIENT_NAME, false)?;
+    let lavalink = Lavalink::connect_from(rest, &user_id).await?;
+
+    // The session ID is only known after the Ready message, and it's required to create the player.
+    while let Some(message) = lavalink.next().await {
+        if let Message::Ready(ready) = message? {
+            println!("connected with the session ID {}", ready.session_id);
+            break;
+        }
+    }
+
+    let track = match lavalink.client().load_track(&query).await? {
+        LoadResult::Track(track) => *track,
+        LoadResult::Search(tracks) => tracks
+            .into_iter()
+            .next()
+            .expect("the search returned no tracks"),
+        LoadResult::Playlist(playlist) => playlist
+            .tracks
+            .into_iter()
+            .next()
+            .expect("the playlist has no tracks"),
+        LoadResult::Empty => panic!("no track was found for the query"),
+        LoadResult::Error(e) => panic!("cannot load the track: {:?}", e.message),
+    };
+
+    println!("playing {} by {}", track.info.title, track.info.author);
+
+    let update_player = UpdatePlayer {
+        voice: Some(voice),
+        ..Default::default()
+    }
+    .set_track(UpdatePlayerTrack::default().set_encoded(&track.encoded));
+
+    lavalink
+        .update_player(&guild_id, &update_player, false)
+        .await?;
+
+    while let Some(message) = lavalink.next().await {
+        match message? {
+            Message::PlayerUpdate(update) if update.guild_id == guild_id => {
+                println!(
+                    "position: {}ms, connected: {}, ping: {}ms",
+                    update.state.position, update.state.connected, update.state.ping
+                );
+            }
+            Message::Event(event) if event.guild_id() == guild_id => {
+                println!("event: {:?}", event);
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+/// Get a required environment variable.
+fn var(key: &str) -> String {
+    env::var(key).unwrap_or_else(|_| panic!("the environment variable {} is required", key))
+}
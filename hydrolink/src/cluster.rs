@@ -1,42 +1,104 @@
 //! Lavalink implementation to interact with multiple Lavalink nodes.
 
 use std::{
-    collections::HashMap,
+    cmp::Ordering as CmpOrdering,
+    collections::{HashMap, HashSet},
     sync::{
-        Arc,
-        atomic::{AtomicUsize, Ordering},
+        Arc, Weak,
+        atomic::{AtomicBool, AtomicUsize, Ordering},
     },
+    time::{Duration, Instant},
 };
 
-use futures::StreamExt;
+use futures::{StreamExt, future::join_all};
 use tokio::{
     select,
     sync::{Mutex as AsyncMutex, Notify, mpsc},
+    task::JoinHandle,
+    time::{sleep, timeout},
 };
 
 use super::{
     Error, Rest, Result,
     model::*,
-    utils::{RwLock, connect, parse_message},
+    utils::{RwLock, compare_versions, connect, parse_message},
 };
 
+/// The time to wait for a node to answer a health check before considering it unreachable.
+pub const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The result of the health check of a node.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NodeHealth {
+    /// The index of the node.
+    pub index: usize,
+    /// If the node answered the health check before the timeout.
+    pub reachable: bool,
+    /// The time the node took to answer, or the timeout if it's unreachable.
+    pub latency: Duration,
+}
+
+/// The task reading the WebSocket connection of a node.
+#[derive(Debug)]
+struct NodeTask {
+    /// Notifier to stop the task.
+    notifier: Arc<Notify>,
+    /// The handle of the task.
+    handle: JoinHandle<()>,
+}
+
+/// A Lavalink node in the cluster.
+#[derive(Debug)]
+struct Node {
+    /// The REST client of the node.
+    rest: Rest,
+    /// Lock held while connecting the node, allowing only one connection attempt at a time.
+    connecting: AsyncMutex<()>,
+    /// If the WebSocket connection is open, even if the session isn't ready yet.
+    connected: AtomicBool,
+    /// If the session was resumed when the node was last connected.
+    resumed: AtomicBool,
+}
+
+impl Node {
+    /// Create a new disconnected node.
+    fn new(rest: Rest) -> Self {
+        Self {
+            rest,
+            connecting: AsyncMutex::new(()),
+            connected: AtomicBool::new(false),
+            resumed: AtomicBool::new(false),
+        }
+    }
+}
+
 /// Manages multiple Lavalink nodes using a round-robin strategy and a multi-producer, single-consumer channel to receive messages.
 #[derive(Debug)]
 pub struct Cluster {
-    /// List of Lavalink nodes. Need to be immutable to avoid index changes.
-    nodes: Vec<Rest>,
+    /// List of Lavalink nodes. Removed nodes leave an empty slot, keeping the indexes of the other nodes.
+    nodes: RwLock<Vec<Option<Arc<Node>>>>,
     /// Sender to be used by the nodes to send messages.
     sender: mpsc::Sender<(usize, Option<Result<Message>>)>,
     /// Receiver to receive messages from the nodes.
     receiver: AsyncMutex<mpsc::Receiver<(usize, Option<Result<Message>>)>>,
-    /// Notifier to notify the tasks when the connection needs to be closed.
-    notifier: Arc<Notify>,
+    /// The task reading the WebSocket connection of each node, with the notifier used to stop it.
+    tasks: RwLock<HashMap<usize, NodeTask>>,
     /// Index for the round-robin strategy.
     index: AtomicUsize,
     /// The session ID from each node connection.
     session_id: Arc<RwLock<HashMap<usize, String>>>,
+    /// The session ID from the last connection of each node, used to resume it when reconnecting.
+    last_session_id: Arc<RwLock<HashMap<usize, String>>>,
     /// The user ID to be used by the nodes.
     user_id: String,
+    /// If the REST connection should be established when connecting a node.
+    warmup: bool,
+    /// The information from each node, fetched when the node is connected.
+    info: RwLock<HashMap<usize, Info>>,
+    /// The latest stats sent by each node.
+    stats: Arc<RwLock<HashMap<usize, Stats>>>,
+    /// The nodes that failed their last health check.
+    unhealthy: RwLock<HashSet<usize>>,
 }
 
 impl Cluster {
@@ -45,29 +107,68 @@ impl Cluster {
         let (sender, receiver) = mpsc::channel(1);
 
         Self {
-            nodes,
+            nodes: RwLock::new(
+                nodes
+                    .into_iter()
+                    .map(|rest| Some(Arc::new(Node::new(rest))))
+                    .collect(),
+            ),
             sender,
             receiver: AsyncMutex::new(receiver),
             index: AtomicUsize::new(0),
-            notifier: Arc::new(Notify::new()),
+            tasks: RwLock::new(HashMap::new()),
             session_id: Arc::new(RwLock::new(HashMap::new())),
+            last_session_id: Arc::new(RwLock::new(HashMap::new())),
             user_id: user_id.to_owned(),
+            warmup: false,
+            info: RwLock::new(HashMap::new()),
+            stats: Arc::new(RwLock::new(HashMap::new())),
+            unhealthy: RwLock::new(HashSet::new()),
         }
     }
 
+    /// Establish the REST connection when connecting a node, reducing the latency of the first request.
+    pub fn with_warmup(mut self, warmup: bool) -> Self {
+        self.warmup = warmup;
+        self
+    }
+
     /// Connect a node to the Lavalink server if it is not already connected.
+    ///
+    /// If another connection attempt for the same node is in progress, this method waits for it, returning successfully
+    /// if it has connected the node, or trying again if it has failed.
     pub async fn connect(&self, index: usize) -> Result<()> {
-        if self.is_connected(index) {
-            return Err(Error::AlreadyConnected);
+        let node = self.get_node(index)?;
+
+        let (_guard, raced) = match node.connecting.try_lock() {
+            Ok(guard) => (guard, false),
+            Err(_) => (node.connecting.lock().await, true),
+        };
+
+        if node.connected.load(Ordering::Acquire) || self.is_connected(index) {
+            return if raced {
+                Ok(())
+            } else {
+                Err(Error::AlreadyConnected)
+            };
         }
 
         let sender = self.sender.clone();
-        let notifier = self.notifier.clone();
-        let node = &self.nodes[index];
+        let notifier = Arc::new(Notify::new());
+        let task_notifier = notifier.clone();
+        let task_node = node.clone();
         let session_id_storage = self.session_id.clone();
-        let mut connection = connect(node, &self.user_id).await?;
+        let last_session_id = self.last_session_id.clone();
+        let stats = self.stats.clone();
+        let resume_session_id = self.last_session_id.read().get(&index).cloned();
+        let mut connection =
+            connect(&node.rest, &self.user_id, resume_session_id.as_deref()).await?;
+
+        node.connected.store(true, Ordering::Release);
+
+        let task = tokio::spawn(async move {
+            let mut stopped = false;
 
-        tokio::spawn(async move {
             loop {
                 select! {
                     message = connection.next() => {
@@ -76,6 +177,11 @@ impl Cluster {
 
                             if let Some(data) = data.as_ref().ok().and_then(|v| v.as_ready()) {
                                 session_id_storage.write().insert(index, data.session_id.clone());
+                                task_node.resumed.store(data.resumed, Ordering::Release);
+                            }
+
+                            if let Some(data) = data.as_ref().ok().and_then(|v| v.as_stats()) {
+                                stats.write().insert(index, data.clone());
                             }
 
                             if sender.send((index, Some(data))).await.is_err() {
@@ -85,20 +191,120 @@ impl Cluster {
                             break;
                         }
                     },
-                    _ = notifier.notified() => break,
+                    _ = task_notifier.notified() => {
+                        stopped = true;
+                        break;
+                    },
                 }
             }
 
-            session_id_storage.write().remove(&index);
-            _ = sender.send((index, None)).await;
+            if let Some(session_id) = session_id_storage.write().remove(&index) {
+                last_session_id.write().insert(index, session_id);
+            }
+            stats.write().remove(&index);
+            task_node.connected.store(false, Ordering::Release);
+
+            // The disconnection was requested, so it isn't reported as a connection loss.
+            if !stopped {
+                _ = sender.send((index, None)).await;
+            }
         });
 
+        self.tasks.write().insert(
+            index,
+            NodeTask {
+                notifier,
+                handle: task,
+            },
+        );
+
+        if self.warmup {
+            // The warmup is only an optimization, the node is already connected.
+            _ = node.rest.warmup().await;
+        }
+
+        // Without the information, the node is considered as not having any plugin.
+        if let Ok(info) = node.rest.info().await {
+            self.info.write().insert(index, info);
+        }
+
         Ok(())
     }
 
-    /// Get the list of Lavalink nodes.
-    pub fn nodes(&self) -> &Vec<Rest> {
-        &self.nodes
+    /// Add a node to the cluster and connect it, returning its index.
+    ///
+    /// If the node can't be connected, it's removed from the cluster again and the error is returned.
+    pub async fn add_node(&self, rest: Rest) -> Result<usize> {
+        let index = {
+            let mut nodes = self.nodes.write();
+            nodes.push(Some(Arc::new(Node::new(rest))));
+            nodes.len() - 1
+        };
+
+        if let Err(e) = self.connect(index).await {
+            if let Some(node) = self.nodes.write().get_mut(index) {
+                *node = None;
+            }
+
+            return Err(e);
+        }
+
+        Ok(index)
+    }
+
+    /// Remove a node from the cluster, migrating its players to the best of the other connected nodes before
+    /// disconnecting it, and returning the result of the migration for each guild.
+    ///
+    /// The indexes of the other nodes don't change, and the index of the removed node isn't reused.
+    ///
+    /// Players bound to the removed node keep playing in the node they were migrated to, but the cluster doesn't track
+    /// which node each player is bound to, so the caller must bind them to the node returned by [Cluster::best_node],
+    /// as requests to the removed index fail with [Error::NodeNotFound]. If the node isn't connected or there's no other
+    /// connected node, its players aren't migrated and stop playing.
+    pub async fn remove_node(&self, index: usize) -> Result<Vec<(String, Result<Player>)>> {
+        self.get_node(index)?;
+
+        let results = match self.best_node_except(Some(index)) {
+            Some(to) if self.is_connected(index) => self.migrate_players(index, to).await?,
+            _ => Vec::new(),
+        };
+
+        self.disconnect(index).await;
+
+        if let Some(node) = self.nodes.write().get_mut(index) {
+            *node = None;
+        }
+
+        self.info.write().remove(&index);
+        self.unhealthy.write().remove(&index);
+        self.last_session_id.write().remove(&index);
+
+        Ok(results)
+    }
+
+    /// Get the REST client of a node, or [None] if there's no node with this index.
+    pub fn node(&self, index: usize) -> Option<Rest> {
+        self.get_node(index).ok().map(|node| node.rest.clone())
+    }
+
+    /// Get the indexes of the nodes in the cluster.
+    pub fn nodes(&self) -> Vec<usize> {
+        self.nodes
+            .read()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, node)| node.as_ref().map(|_| index))
+            .collect()
+    }
+
+    /// Get a node, failing if there's no node with this index.
+    fn get_node(&self, index: usize) -> Result<Arc<Node>> {
+        self.nodes
+            .read()
+            .get(index)
+            .cloned()
+            .flatten()
+            .ok_or(Error::NodeNotFound)
     }
 
     /// Get the user ID.
@@ -114,16 +320,134 @@ impl Cluster {
     /// Get the list of disconnected nodes.
     pub fn disconnected_nodes(&self) -> Vec<usize> {
         let connected = self.connected_nodes();
-        (0..self.nodes.len())
+        self.nodes()
+            .into_iter()
             .filter(|x| !connected.contains(x))
             .collect()
     }
 
-    /// Check if a node is connected.
+    /// Check if a node is connected, with its session ready to be used.
     pub fn is_connected(&self, index: usize) -> bool {
         self.session_id.read().contains_key(&index)
     }
 
+    /// Get how many nodes are connected, with their sessions ready to be used.
+    pub fn connected_count(&self) -> usize {
+        self.session_id.read().len()
+    }
+
+    /// Check if a node passed its last health check, nodes that were never checked are considered healthy.
+    pub fn is_healthy(&self, index: usize) -> bool {
+        !self.unhealthy.read().contains(&index)
+    }
+
+    /// Check the health of all the nodes concurrently, requesting their version and measuring the latency.
+    ///
+    /// Nodes that don't answer before [HEALTH_CHECK_TIMEOUT] are marked unhealthy, being skipped by
+    /// [Cluster::best_node] and [Cluster::search_connected_node] until they pass a health check again.
+    pub async fn health_check(&self) -> Vec<NodeHealth> {
+        let nodes = self
+            .nodes()
+            .into_iter()
+            .filter_map(|index| self.node(index).map(|rest| (index, rest)))
+            .collect::<Vec<_>>();
+
+        let results = join_all(nodes.into_iter().map(|(index, rest)| async move {
+            let start = Instant::now();
+            let reachable = matches!(
+                timeout(HEALTH_CHECK_TIMEOUT, rest.version()).await,
+                Ok(Ok(_))
+            );
+
+            NodeHealth {
+                index,
+                reachable,
+                latency: start.elapsed(),
+            }
+        }))
+        .await;
+
+        let mut unhealthy = self.unhealthy.write();
+        for health in &results {
+            if health.reachable {
+                unhealthy.remove(&health.index);
+            } else {
+                unhealthy.insert(health.index);
+            }
+        }
+
+        results
+    }
+
+    /// Spawn a task checking the health of the nodes every `interval`, stopping when the cluster is dropped.
+    pub fn spawn_health_check(self: &Arc<Self>, interval: Duration) -> JoinHandle<()> {
+        let cluster = Arc::downgrade(self);
+
+        tokio::spawn(async move {
+            loop {
+                sleep(interval).await;
+
+                let Some(cluster) = Weak::upgrade(&cluster) else {
+                    break;
+                };
+
+                cluster.health_check().await;
+            }
+        })
+    }
+
+    /// Get the information of a node, fetched when it was connected.
+    ///
+    /// This method clones the information to avoid locking the RwLock.
+    pub fn info(&self, index: usize) -> Option<Info> {
+        self.info.read().get(&index).cloned()
+    }
+
+    /// Check if a node has a source manager enabled, nodes without the information are considered as having it, as the
+    /// information can fail to be fetched even when the node is working.
+    pub fn supports_source(&self, index: usize, name: &str) -> bool {
+        self.info
+            .read()
+            .get(&index)
+            .is_none_or(|info| info.source_managers.iter().any(|source| source == name))
+    }
+
+    /// Check if a node has a filter enabled, nodes without the information are considered as having it, as the
+    /// information can fail to be fetched even when the node is working.
+    pub fn supports_filter(&self, index: usize, name: &str) -> bool {
+        self.info
+            .read()
+            .get(&index)
+            .is_none_or(|info| info.filters.iter().any(|filter| filter == name))
+    }
+
+    /// Get the latest stats sent by a node, or [None] if it hasn't sent them since it was connected.
+    ///
+    /// This method clones the stats to avoid locking the RwLock.
+    pub fn node_stats(&self, index: usize) -> Option<Stats> {
+        self.stats.read().get(&index).cloned()
+    }
+
+    /// Check if a node has a plugin, optionally requiring a minimum version.
+    pub fn has_plugin(&self, index: usize, name: &str, min_version: Option<&str>) -> bool {
+        self.info.read().get(&index).is_some_and(|info| {
+            info.plugins.iter().any(|plugin| {
+                plugin.name == name
+                    && min_version.is_none_or(|min_version| {
+                        compare_versions(&plugin.version, min_version) != CmpOrdering::Less
+                    })
+            })
+        })
+    }
+
+    /// Check if the session of a node was resumed when it was last connected.
+    ///
+    /// If it wasn't, the players from the previous session are lost and have to be created again.
+    pub fn resumed(&self, index: usize) -> bool {
+        self.get_node(index)
+            .is_ok_and(|node| node.resumed.load(Ordering::Acquire))
+    }
+
     /// Get the current index.
     pub fn current_index(&self) -> usize {
         self.index.load(Ordering::Relaxed)
@@ -140,18 +464,18 @@ impl Cluster {
     pub fn next_index(&self) -> usize {
         self.index
             .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |x| {
-                Some((x + 1) % self.nodes.len())
+                Some((x + 1) % self.nodes.read().len().max(1))
             })
             .unwrap()
     }
 
     /// Search for a connected node, returning the index if found or [None] if there is no connected node.
     ///
-    /// This method uses the round-robin strategy to search for a connected node.
+    /// This method uses the round-robin strategy to search for a connected node, skipping the unhealthy ones.
     pub fn search_connected_node(&self) -> Option<usize> {
-        for _ in 0..self.nodes.len() {
+        for _ in 0..self.nodes.read().len() {
             let index = self.next_index();
-            if self.is_connected(index) {
+            if self.is_connected(index) && self.is_healthy(index) {
                 return Some(index);
             }
         }
@@ -159,20 +483,84 @@ impl Cluster {
         None
     }
 
+    /// Get the connected node with the lowest Lavalink CPU load, or with the fewest playing players when the loads are
+    /// equal, both relative to the node's capacity.
+    ///
+    /// Nodes that haven't sent their stats yet are considered idle, and ties are broken by the lowest index. Unhealthy
+    /// nodes are skipped.
+    pub fn best_node(&self) -> Option<usize> {
+        self.best_node_except(None)
+    }
+
+    /// Get the best connected node, ignoring a node.
+    fn best_node_except(&self, except: Option<usize>) -> Option<usize> {
+        let connected = self.connected_nodes();
+        let nodes = self.nodes.read();
+        let stats = self.stats.read();
+        let unhealthy = self.unhealthy.read();
+
+        select_best_node(
+            connected
+                .into_iter()
+                .filter(|index| Some(*index) != except && !unhealthy.contains(index))
+                .filter_map(|index| {
+                    let capacity = nodes.get(index)?.as_ref()?.rest.capacity;
+                    let (load, playing) = stats
+                        .get(&index)
+                        .map(|v| (v.cpu.lavalink_load, v.playing_players as f32))
+                        .unwrap_or_default();
+
+                    Some((index, capacity, load, playing))
+                }),
+        )
+    }
+
     /// Get all players in the session.
     pub async fn get_players(&self, index: usize) -> Result<Vec<Player>> {
-        self.nodes[index]
+        self.get_node(index)?
+            .rest
             .get_players(&self.session_id(index).ok_or(Error::NoSessionId)?)
             .await
     }
 
     /// Get the player in the session.
     pub async fn get_player(&self, index: usize, guild_id: &str) -> Result<Option<Player>> {
-        self.nodes[index]
+        self.get_node(index)?
+            .rest
             .get_player(&self.session_id(index).ok_or(Error::NoSessionId)?, guild_id)
             .await
     }
 
+    /// Search all the connected nodes for the player of a guild, returning the index of the node that holds it.
+    ///
+    /// Nodes that fail to respond are skipped, as the player may be in another node after a failover.
+    pub async fn get_player_any(&self, guild_id: &str) -> Option<(usize, Player)> {
+        for index in self.connected_nodes() {
+            if let Ok(Some(player)) = self.get_player(index, guild_id).await {
+                return Some((index, player));
+            }
+        }
+
+        None
+    }
+
+    /// Get the lyrics of the track playing in the player, using the LavaLyrics plugin.
+    pub async fn get_lyrics(
+        &self,
+        index: usize,
+        guild_id: &str,
+        skip_track_source: bool,
+    ) -> Result<Option<Lyrics>> {
+        self.get_node(index)?
+            .rest
+            .get_lyrics(
+                &self.session_id(index).ok_or(Error::NoSessionId)?,
+                guild_id,
+                skip_track_source,
+            )
+            .await
+    }
+
     /// Update the player in the session.
     pub async fn update_player(
         &self,
@@ -181,7 +569,8 @@ impl Cluster {
         player: &UpdatePlayer,
         no_replace: bool,
     ) -> Result<Player> {
-        self.nodes[index]
+        self.get_node(index)?
+            .rest
             .update_player(
                 &self.session_id(index).ok_or(Error::NoSessionId)?,
                 guild_id,
@@ -191,9 +580,84 @@ impl Cluster {
             .await
     }
 
+    /// Update multiple players in the session concurrently, returning the result for each guild.
+    pub async fn update_players(
+        &self,
+        index: usize,
+        updates: &[(String, UpdatePlayer)],
+        no_replace: bool,
+    ) -> Result<Vec<(String, Result<Player>)>> {
+        Ok(self
+            .get_node(index)?
+            .rest
+            .update_players(
+                &self.session_id(index).ok_or(Error::NoSessionId)?,
+                updates,
+                no_replace,
+            )
+            .await)
+    }
+
+    /// Move the players from a node to another, keeping their track, position, pause state, volume, filters and voice
+    /// state, returning the result for each guild.
+    ///
+    /// The players are read from the session of the old node, even if it was lost, so its REST API must be reachable.
+    /// They are destroyed in the old node after being created in the new one.
+    pub async fn migrate_players(
+        &self,
+        from: usize,
+        to: usize,
+    ) -> Result<Vec<(String, Result<Player>)>> {
+        let from_session_id = self
+            .session_id(from)
+            .or_else(|| self.last_session_id.read().get(&from).cloned())
+            .ok_or(Error::NoSessionId)?;
+
+        let from_node = self.get_node(from)?;
+
+        let updates = from_node
+            .rest
+            .get_players(&from_session_id)
+            .await?
+            .into_iter()
+            .map(|player| {
+                let mut update_player = UpdatePlayer {
+                    voice: Some(player.voice),
+                    ..Default::default()
+                }
+                .set_paused(player.paused)
+                .set_volume(player.volume)
+                .set_filters(player.filters);
+
+                if let Some(track) = player.track {
+                    update_player = update_player
+                        .set_track(UpdatePlayerTrack::default().set_encoded(&track.encoded))
+                        .set_position(player.state.position);
+                }
+
+                (player.guild_id, update_player)
+            })
+            .collect::<Vec<_>>();
+
+        let results = self.update_players(to, &updates, false).await?;
+
+        for (guild_id, result) in results.iter() {
+            if result.is_ok() {
+                // The player is already in the new node, a leftover in the old one is only wasting resources.
+                _ = from_node
+                    .rest
+                    .destroy_player(&from_session_id, guild_id)
+                    .await;
+            }
+        }
+
+        Ok(results)
+    }
+
     /// Destroy the player in the session.
     pub async fn destroy_player(&self, index: usize, guild_id: &str) -> Result<()> {
-        self.nodes[index]
+        self.get_node(index)?
+            .rest
             .destroy_player(&self.session_id(index).ok_or(Error::NoSessionId)?, guild_id)
             .await
     }
@@ -204,11 +668,26 @@ impl Cluster {
         index: usize,
         session: &UpdateSessionRequest,
     ) -> Result<UpdateSessionResponse> {
-        self.nodes[index]
+        self.get_node(index)?
+            .rest
             .update_session(&self.session_id(index).ok_or(Error::NoSessionId)?, session)
             .await
     }
 
+    /// Enable resuming for the session, keeping the players for `timeout` seconds after the connection is lost.
+    ///
+    /// The session is resumed automatically when the node is connected again.
+    pub async fn enable_resuming(
+        &self,
+        index: usize,
+        timeout: u32,
+    ) -> Result<UpdateSessionResponse> {
+        self.get_node(index)?
+            .rest
+            .enable_resuming(&self.session_id(index).ok_or(Error::NoSessionId)?, timeout)
+            .await
+    }
+
     /// Receive a message from the nodes.
     ///
     /// WARNING: This method locks the internal receiver mutex.
@@ -216,14 +695,346 @@ impl Cluster {
         self.receiver.lock().await.recv().await
     }
 
-    /// Close all connections to the Lavalink server.
+    /// Disconnect a node, waiting for its task to finish.
+    ///
+    /// The disconnection isn't reported by [Cluster::recv], as it was requested.
+    pub async fn disconnect(&self, index: usize) {
+        let task = self.tasks.write().remove(&index);
+
+        if let Some(task) = task {
+            task.notifier.notify_one();
+            _ = task.handle.await;
+        }
+    }
+
+    /// Disconnect all nodes, waiting for their tasks to finish.
+    pub async fn disconnect_all(&self) {
+        let tasks = self.tasks.write().drain().collect::<Vec<_>>();
+
+        for (_, task) in tasks.iter() {
+            task.notifier.notify_one();
+        }
+
+        for (_, task) in tasks {
+            _ = task.handle.await;
+        }
+    }
+
+    /// Close all connections to the Lavalink server, without waiting for them.
     pub fn close(&self) {
-        self.notifier.notify_waiters();
+        for task in self.tasks.read().values() {
+            task.notifier.notify_one();
+        }
     }
 }
 
 impl Drop for Cluster {
     fn drop(&mut self) {
-        self.notifier.notify_waiters();
+        self.close();
+    }
+}
+
+/// Select the node with the lowest load and players weighted by its capacity, from `(index, capacity, load, players)`.
+///
+/// Ties are broken by the lowest index.
+fn select_best_node(nodes: impl IntoIterator<Item = (usize, f32, f32, f32)>) -> Option<usize> {
+    nodes
+        .into_iter()
+        .map(|(index, capacity, load, playing)| (load / capacity, playing / capacity, index))
+        .min_by(|a, b| {
+            a.0.total_cmp(&b.0)
+                .then(a.1.total_cmp(&b.1))
+                .then(a.2.cmp(&b.2))
+        })
+        .map(|(_, _, index)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::SinkExt;
+    use tokio::{io::AsyncWriteExt, net::TcpListener, sync::broadcast};
+    use tokio_tungstenite::{accept_async, tungstenite::Message as WebSocketMessage};
+
+    use super::*;
+    use crate::RestBuilder;
+
+    #[test]
+    fn best_node_prefers_the_higher_capacity_until_proportional() {
+        let capacities = [2.0, 1.0];
+        let mut players = [0.0; 2];
+
+        for _ in 0..6 {
+            let best = select_best_node(
+                capacities
+                    .iter()
+                    .enumerate()
+                    .map(|(index, capacity)| (index, *capacity, 0.0, players[index])),
+            )
+            .unwrap();
+
+            players[best] += 1.0;
+        }
+
+        assert_eq!(players, [4.0, 2.0]);
+    }
+
+    #[test]
+    fn best_node_weights_the_load_by_capacity() {
+        let nodes = [(0, 1.0, 0.3, 0.0), (1, 4.0, 0.8, 0.0)];
+
+        assert_eq!(select_best_node(nodes), Some(1));
+    }
+
+    #[test]
+    fn best_node_without_nodes() {
+        assert_eq!(select_best_node([]), None);
+    }
+
+    #[tokio::test]
+    async fn supports_without_info() {
+        let cluster = Cluster::new(Vec::new(), "0").await;
+
+        assert!(cluster.supports_source(0, "youtube"));
+        assert!(cluster.supports_filter(0, "timescale"));
+
+        let info = serde_json::from_value(serde_json::json!({
+            "version": {
+                "semver": "4.0.8",
+                "major": 4,
+                "minor": 0,
+                "patch": 8,
+                "preRelease": null,
+                "build": null
+            },
+            "buildTime": 0,
+            "git": {
+                "branch": "main",
+                "commit": "0",
+                "commitTime": 0
+            },
+            "jvm": "17",
+            "lavaplayer": "2.2.1",
+            "sourceManagers": ["youtube"],
+            "filters": ["volume"],
+            "plugins": []
+        }))
+        .unwrap();
+
+        cluster.info.write().insert(0, info);
+
+        assert!(cluster.supports_source(0, "youtube"));
+        assert!(!cluster.supports_source(0, "soundcloud"));
+        assert!(cluster.supports_filter(0, "volume"));
+        assert!(!cluster.supports_filter(0, "timescale"));
+    }
+
+    #[tokio::test]
+    async fn has_plugin_versions() {
+        let cluster = Cluster::new(Vec::new(), "0").await;
+
+        assert!(!cluster.has_plugin(0, "lavasrc", None));
+
+        let info = serde_json::from_value(serde_json::json!({
+            "version": {
+                "semver": "4.0.8",
+                "major": 4,
+                "minor": 0,
+                "patch": 8,
+                "preRelease": null,
+                "build": null
+            },
+            "buildTime": 0,
+            "git": {
+                "branch": "main",
+                "commit": "0",
+                "commitTime": 0
+            },
+            "jvm": "17",
+            "lavaplayer": "2.2.1",
+            "sourceManagers": ["youtube"],
+            "filters": ["volume"],
+            "plugins": [
+                { "name": "lavasrc", "version": "4.2.0" },
+                { "name": "lavalyrics", "version": "snapshot" }
+            ]
+        }))
+        .unwrap();
+
+        cluster.info.write().insert(0, info);
+
+        assert!(!cluster.has_plugin(0, "sponsorblock", None));
+        assert!(!cluster.has_plugin(0, "sponsorblock", Some("1.0.0")));
+
+        assert!(cluster.has_plugin(0, "lavasrc", None));
+        assert!(cluster.has_plugin(0, "lavasrc", Some("4.2.0")));
+        assert!(cluster.has_plugin(0, "lavasrc", Some("4.1.9")));
+        assert!(cluster.has_plugin(0, "lavasrc", Some("4")));
+        assert!(!cluster.has_plugin(0, "lavasrc", Some("4.2.1")));
+        assert!(!cluster.has_plugin(0, "lavasrc", Some("5.0.0")));
+
+        assert!(cluster.has_plugin(0, "lavalyrics", None));
+        assert!(!cluster.has_plugin(0, "lavalyrics", Some("1.0.0")));
+
+        assert!(!cluster.has_plugin(1, "lavasrc", None));
+    }
+
+    /// Start a server accepting the WebSocket connections, returning its address, how many connections it accepted and
+    /// a sender for the messages sent to all the connections.
+    ///
+    /// The other requests are answered with `404 Not Found`.
+    async fn websocket_server() -> (String, Arc<AtomicUsize>, broadcast::Sender<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let connections = Arc::new(AtomicUsize::new(0));
+        let server_connections = connections.clone();
+        let (sender, _) = broadcast::channel(16);
+        let server_sender = sender.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let connections = server_connections.clone();
+                let mut receiver = server_sender.subscribe();
+
+                tokio::spawn(async move {
+                    let mut buffer = [0; 4096];
+
+                    // The request is only peeked, so the WebSocket handshake can still read it.
+                    let request = loop {
+                        let read = socket.peek(&mut buffer).await.unwrap();
+                        let request = String::from_utf8_lossy(&buffer[..read]).to_lowercase();
+
+                        if read == 0 || request.contains("\r\n\r\n") {
+                            break request;
+                        }
+
+                        sleep(Duration::from_millis(5)).await;
+                    };
+
+                    if !request.contains("upgrade: websocket") {
+                        _ = socket
+                            .write_all(
+                                b"HTTP/1.1 404 Not Found\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                            )
+                            .await;
+                        return;
+                    }
+
+                    connections.fetch_add(1, Ordering::SeqCst);
+
+                    let mut websocket = accept_async(socket).await.unwrap();
+
+                    loop {
+                        select! {
+                            message = websocket.next() => {
+                                if !matches!(message, Some(Ok(_))) {
+                                    break;
+                                }
+                            },
+                            message = receiver.recv() => {
+                                let Ok(message) = message else {
+                                    break;
+                                };
+
+                                if websocket.send(WebSocketMessage::text(message)).await.is_err() {
+                                    break;
+                                }
+                            },
+                        }
+                    }
+                });
+            }
+        });
+
+        (address, connections, sender)
+    }
+
+    #[tokio::test]
+    async fn concurrent_connect_connects_once() {
+        let (address, connections, _) = websocket_server().await;
+        let rest = RestBuilder::new(&address, "password").build().unwrap();
+        let cluster = Cluster::new(vec![rest], "0").await;
+
+        let (first, second) = tokio::join!(cluster.connect(0), cluster.connect(0));
+
+        assert!(first.is_ok());
+        assert!(second.is_ok());
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+        assert!(matches!(
+            cluster.connect(0).await,
+            Err(Error::AlreadyConnected)
+        ));
+        assert_eq!(connections.load(Ordering::SeqCst), 1);
+
+        cluster.disconnect(0).await;
+    }
+
+    /// Create a player update message for the guild.
+    fn player_update(guild_id: &str) -> String {
+        serde_json::json!({
+            "op": "playerUpdate",
+            "guildId": guild_id,
+            "state": { "time": 0, "position": 0, "connected": true, "ping": 0 }
+        })
+        .to_string()
+    }
+
+    /// Receive the next message from the cluster, returning the node index and the guild ID of the message.
+    async fn recv_guild_id(cluster: &Cluster) -> (usize, String) {
+        let (index, message) = timeout(Duration::from_secs(5), cluster.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        (
+            index,
+            message
+                .unwrap()
+                .unwrap()
+                .guild_id()
+                .unwrap_or_default()
+                .to_owned(),
+        )
+    }
+
+    #[tokio::test]
+    async fn stopped_node_does_not_affect_others() {
+        let (first_address, _, first_sender) = websocket_server().await;
+        let (second_address, _, second_sender) = websocket_server().await;
+        let cluster = Cluster::new(
+            vec![
+                RestBuilder::new(&first_address, "password")
+                    .build()
+                    .unwrap(),
+                RestBuilder::new(&second_address, "password")
+                    .build()
+                    .unwrap(),
+            ],
+            "0",
+        )
+        .await;
+
+        cluster.connect(0).await.unwrap();
+        cluster.connect(1).await.unwrap();
+
+        first_sender.send(player_update("1")).unwrap();
+        assert_eq!(recv_guild_id(&cluster).await, (0, "1".to_owned()));
+
+        second_sender.send(player_update("2")).unwrap();
+        assert_eq!(recv_guild_id(&cluster).await, (1, "2".to_owned()));
+
+        cluster.disconnect(0).await;
+
+        // The first node is stopped, so its messages aren't received anymore, while the second node keeps working.
+        _ = first_sender.send(player_update("3"));
+        second_sender.send(player_update("4")).unwrap();
+        assert_eq!(recv_guild_id(&cluster).await, (1, "4".to_owned()));
+        assert!(
+            timeout(Duration::from_millis(100), cluster.recv())
+                .await
+                .is_err()
+        );
+
+        cluster.disconnect(1).await;
     }
 }
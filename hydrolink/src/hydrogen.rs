@@ -2,7 +2,7 @@
 
 use regex::Regex;
 
-use super::Rest;
+use super::{Error, Rest, Result};
 
 /// Hydrogen's Lavalink configuration parser.
 pub struct ConfigParser<'a> {
@@ -15,7 +15,7 @@ pub struct ConfigParser<'a> {
 
 impl<'a> ConfigParser<'a> {
     /// Creates a new instance of the parser.
-    pub fn new(user_agent: &'a str) -> Result<Self, regex::Error> {
+    pub fn new(user_agent: &'a str) -> std::result::Result<Self, regex::Error> {
         Ok(Self {
             single_string_regex: Regex::new(
                 r"((?:\[.+]|[^;:\n]+):[0-9]{1,5})@([^/;\n]+)(?:/([^;\n]+))?;?",
@@ -25,25 +25,74 @@ impl<'a> ConfigParser<'a> {
     }
 
     /// Parses the configuration string into a list of [`Rest`] instances.
-    pub fn parse(&self, value: &str) -> Vec<Rest> {
-        self.single_string_regex
-            .captures_iter(value)
-            .filter_map(|cap| {
-                let host = cap.get(1)?;
-                let password = cap.get(2)?;
-
-                if let Some(query) = cap.get(3) {
-                    Rest::new(
-                        host.as_str(),
-                        password.as_str(),
-                        self.user_agent,
-                        query.as_str() == "tls",
-                    )
-                    .ok()
-                } else {
-                    Rest::new(host.as_str(), password.as_str(), self.user_agent, false).ok()
+    ///
+    /// The options are separated by commas, like `tls,capacity=2`, and an invalid capacity returns
+    /// [Error::InvalidCapacity] instead of ignoring the node.
+    pub fn parse(&self, value: &str) -> Result<Vec<Rest>> {
+        let mut nodes = Vec::new();
+
+        for cap in self.single_string_regex.captures_iter(value) {
+            let (Some(host), Some(password)) = (cap.get(1), cap.get(2)) else {
+                continue;
+            };
+
+            let mut tls = false;
+            let mut capacity = 1.0;
+
+            if let Some(query) = cap.get(3) {
+                for option in query.as_str().split(',') {
+                    match option.split_once('=') {
+                        Some(("capacity", value)) => {
+                            capacity = value
+                                .parse::<f32>()
+                                .ok()
+                                .filter(|v| v.is_finite() && *v > 0.0)
+                                .ok_or_else(|| Error::InvalidCapacity(value.to_owned()))?;
+                        }
+                        None if option == "tls" => tls = true,
+                        _ => {}
+                    }
                 }
-            })
-            .collect()
+            }
+
+            let Ok(mut rest) = Rest::new(host.as_str(), password.as_str(), self.user_agent, tls)
+            else {
+                continue;
+            };
+            rest.capacity = capacity;
+
+            nodes.push(rest);
+        }
+
+        Ok(nodes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_capacity() {
+        let parser = ConfigParser::new("test").unwrap();
+        let nodes = parser
+            .parse("localhost:2333@password/capacity=2;localhost:2334@password")
+            .unwrap();
+
+        assert_eq!(nodes.len(), 2);
+        assert_eq!(nodes[0].capacity, 2.0);
+        assert_eq!(nodes[1].capacity, 1.0);
+    }
+
+    #[test]
+    fn parse_invalid_capacity() {
+        let parser = ConfigParser::new("test").unwrap();
+
+        for capacity in ["abc", "0", "-1", "NaN"] {
+            assert!(matches!(
+                parser.parse(&format!("localhost:2333@password/capacity={}", capacity)),
+                Err(Error::InvalidCapacity(v)) if v == capacity
+            ));
+        }
     }
 }
@@ -9,7 +9,7 @@ mod rest;
 pub(crate) mod utils;
 mod websocket;
 
-use http::header::InvalidHeaderValue;
+use http::{StatusCode, header::InvalidHeaderValue};
 use tokio::net::TcpStream;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 
@@ -64,6 +64,26 @@ pub enum Error {
 
     /// The response from the Lavalink server doesn't have a body.
     NoResponseBody,
+
+    /// There's no Lavalink node with the index.
+    NodeNotFound,
+
+    /// The address of the Lavalink server is malformed.
+    InvalidAddress(String),
+
+    /// The capacity of a Lavalink node isn't a positive number.
+    InvalidCapacity(String),
+
+    /// A reconnection attempt, with its number, has failed.
+    ReconnectFailed(u32, Box<Error>),
+
+    /// The Lavalink server, or a proxy in front of it, responded with an error status without a Lavalink error.
+    UnexpectedStatus {
+        /// The status of the response.
+        status: StatusCode,
+        /// The body of the response.
+        body: String,
+    },
 }
 
 impl std::fmt::Display for Error {
@@ -91,6 +111,26 @@ impl std::fmt::Display for Error {
 
             Self::NoResponseBody => write!(f, "Lavalink response had no body"),
 
+            Self::NodeNotFound => write!(f, "Lavalink node not found"),
+
+            Self::InvalidAddress(address) => write!(f, "Invalid Lavalink address: {}", address),
+
+            Self::InvalidCapacity(capacity) => {
+                write!(f, "Invalid Lavalink node capacity: {}", capacity)
+            }
+
+            Self::ReconnectFailed(attempt, e) => {
+                write!(
+                    f,
+                    "Reconnection attempt {} to Lavalink failed: {}",
+                    attempt, e
+                )
+            }
+
+            Self::UnexpectedStatus { status, body } => {
+                write!(f, "Lavalink responded with status {}: {}", status, body)
+            }
+
             #[cfg(feature = "simd-json")]
             Self::SimdJson(e) => e.fmt(f),
         }
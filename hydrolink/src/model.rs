@@ -1,8 +1,10 @@
 //! Models for the Lavalink REST API and WebSocket API.
 
-use std::collections::HashMap;
+use std::{collections::HashMap, ops::RangeInclusive};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer, de::Error as DeError, ser::Error as SerError,
+};
 use serde_json::Value;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,7 +23,7 @@ pub enum Message {
 
 impl Message {
     /// Get the guild id of the message.
-    pub fn guild_id(&self) -> Option<&String> {
+    pub fn guild_id(&self) -> Option<&str> {
         match self {
             Message::PlayerUpdate(player_update) => Some(&player_update.guild_id),
             Message::Event(event) => Some(event.guild_id()),
@@ -251,29 +253,37 @@ pub struct FrameStats {
     pub deficit: i32,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(tag = "type", rename_all = "camelCase")]
+#[derive(Debug, Clone)]
 /// Websocket event received from Lavalink server.
 pub enum Event {
-    #[serde(rename = "TrackStartEvent")]
     /// Dispatched when a track starts playing.
     TrackStart(TrackStartEvent),
 
-    #[serde(rename = "TrackEndEvent")]
     /// Dispatched when a track ends.
     TrackEnd(TrackEndEvent),
 
-    #[serde(rename = "TrackExceptionEvent")]
     /// Dispatched when a track throws an exception.
     TrackException(TrackExceptionEvent),
 
-    #[serde(rename = "TrackStuckEvent")]
     /// Dispatched when a track gets stuck while playing.
     TrackStuck(TrackStuckEvent),
 
-    #[serde(rename = "WebSocketClosedEvent")]
     /// Dispatched when an audio WebSocket (to Discord) is closed. This can happen for various reasons (normal and abnormal), e.g. when using an expired voice server update. 4xxx codes are usually bad. See the [Discord Docs](https://discord.com/developers/docs/topics/opcodes-and-status-codes#voice-voice-close-event-codes).
     WebSocketClosed(WebSocketClosedEvent),
+
+    /// Dispatched by the SponsorBlock plugin when the segments of a track are loaded.
+    SegmentsLoaded(SegmentsLoadedEvent),
+
+    /// Dispatched by the SponsorBlock plugin when a segment is skipped.
+    SegmentSkipped(SegmentSkippedEvent),
+
+    /// An event not known by Hydrolink, like the ones dispatched by plugins, kept as it was received.
+    Unknown {
+        /// The type of the event.
+        kind: String,
+        /// The event as it was received, including its type.
+        raw: Value,
+    },
 }
 
 impl Event {
@@ -285,17 +295,26 @@ impl Event {
             Event::TrackException(_) => EventKind::TrackException,
             Event::TrackStuck(_) => EventKind::TrackStuck,
             Event::WebSocketClosed(_) => EventKind::WebSocketClosed,
+            Event::SegmentsLoaded(_) => EventKind::SegmentsLoaded,
+            Event::SegmentSkipped(_) => EventKind::SegmentSkipped,
+            Event::Unknown { .. } => EventKind::Unknown,
         }
     }
 
-    /// Get the guild id of the event.
-    pub fn guild_id(&self) -> &String {
+    /// Get the guild id of the event, or an empty string if an unknown event doesn't have it.
+    pub fn guild_id(&self) -> &str {
         match self {
             Event::TrackStart(track_start) => &track_start.guild_id,
             Event::TrackEnd(track_end) => &track_end.guild_id,
             Event::TrackException(track_exception) => &track_exception.guild_id,
             Event::TrackStuck(track_stuck) => &track_stuck.guild_id,
             Event::WebSocketClosed(websocket_closed) => &websocket_closed.guild_id,
+            Event::SegmentsLoaded(segments_loaded) => &segments_loaded.guild_id,
+            Event::SegmentSkipped(segment_skipped) => &segment_skipped.guild_id,
+            Event::Unknown { raw, .. } => raw
+                .get("guildId")
+                .and_then(Value::as_str)
+                .unwrap_or_default(),
         }
     }
 
@@ -335,6 +354,21 @@ impl Event {
         matches!(self, Self::WebSocketClosed(_))
     }
 
+    /// Check if the event is a segments loaded.
+    pub fn is_segments_loaded(&self) -> bool {
+        matches!(self, Self::SegmentsLoaded(_))
+    }
+
+    /// Check if the event is a segment skipped.
+    pub fn is_segment_skipped(&self) -> bool {
+        matches!(self, Self::SegmentSkipped(_))
+    }
+
+    /// Check if the event is unknown.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown { .. })
+    }
+
     /// Convert the event to track start.
     pub fn into_track_start(self) -> Option<TrackStartEvent> {
         match self {
@@ -375,6 +409,22 @@ impl Event {
         }
     }
 
+    /// Convert the event to segments loaded.
+    pub fn into_segments_loaded(self) -> Option<SegmentsLoadedEvent> {
+        match self {
+            Event::SegmentsLoaded(segments_loaded) => Some(segments_loaded),
+            _ => None,
+        }
+    }
+
+    /// Convert the event to segment skipped.
+    pub fn into_segment_skipped(self) -> Option<SegmentSkippedEvent> {
+        match self {
+            Event::SegmentSkipped(segment_skipped) => Some(segment_skipped),
+            _ => None,
+        }
+    }
+
     /// Get the track start event.
     pub fn as_track_start(&self) -> Option<&TrackStartEvent> {
         match self {
@@ -414,6 +464,70 @@ impl Event {
             _ => None,
         }
     }
+
+    /// Get the segments loaded event.
+    pub fn as_segments_loaded(&self) -> Option<&SegmentsLoadedEvent> {
+        match self {
+            Event::SegmentsLoaded(segments_loaded) => Some(segments_loaded),
+            _ => None,
+        }
+    }
+
+    /// Get the segment skipped event.
+    pub fn as_segment_skipped(&self) -> Option<&SegmentSkippedEvent> {
+        match self {
+            Event::SegmentSkipped(segment_skipped) => Some(segment_skipped),
+            _ => None,
+        }
+    }
+}
+
+impl Serialize for Event {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let (kind, data) = match self {
+            Self::TrackStart(event) => ("TrackStartEvent", serde_json::to_value(event)),
+            Self::TrackEnd(event) => ("TrackEndEvent", serde_json::to_value(event)),
+            Self::TrackException(event) => ("TrackExceptionEvent", serde_json::to_value(event)),
+            Self::TrackStuck(event) => ("TrackStuckEvent", serde_json::to_value(event)),
+            Self::WebSocketClosed(event) => ("WebSocketClosedEvent", serde_json::to_value(event)),
+            Self::SegmentsLoaded(event) => ("SegmentsLoaded", serde_json::to_value(event)),
+            Self::SegmentSkipped(event) => ("SegmentSkipped", serde_json::to_value(event)),
+            Self::Unknown { raw, .. } => return raw.serialize(serializer),
+        };
+
+        let mut data = data.map_err(SerError::custom)?;
+
+        if let Some(object) = data.as_object_mut() {
+            object.insert("type".to_owned(), Value::String(kind.to_owned()));
+        }
+
+        data.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Event {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = Value::deserialize(deserializer)?;
+        let kind = raw
+            .get("type")
+            .and_then(Value::as_str)
+            .ok_or_else(|| DeError::missing_field("type"))?
+            .to_owned();
+
+        // Unknown events are kept instead of failing, as plugins can dispatch their own events.
+        let event = match kind.as_str() {
+            "TrackStartEvent" => serde_json::from_value(raw).map(Self::TrackStart),
+            "TrackEndEvent" => serde_json::from_value(raw).map(Self::TrackEnd),
+            "TrackExceptionEvent" => serde_json::from_value(raw).map(Self::TrackException),
+            "TrackStuckEvent" => serde_json::from_value(raw).map(Self::TrackStuck),
+            "WebSocketClosedEvent" => serde_json::from_value(raw).map(Self::WebSocketClosed),
+            "SegmentsLoaded" => serde_json::from_value(raw).map(Self::SegmentsLoaded),
+            "SegmentSkipped" => serde_json::from_value(raw).map(Self::SegmentSkipped),
+            _ => return Ok(Self::Unknown { kind, raw }),
+        };
+
+        event.map_err(DeError::custom)
+    }
 }
 
 impl From<TrackStartEvent> for Event {
@@ -446,6 +560,18 @@ impl From<WebSocketClosedEvent> for Event {
     }
 }
 
+impl From<SegmentsLoadedEvent> for Event {
+    fn from(segments_loaded: SegmentsLoadedEvent) -> Self {
+        Self::SegmentsLoaded(segments_loaded)
+    }
+}
+
+impl From<SegmentSkippedEvent> for Event {
+    fn from(segment_skipped: SegmentSkippedEvent) -> Self {
+        Self::SegmentSkipped(segment_skipped)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 /// The kind of event received from the Lavalink server.
 pub enum EventKind {
@@ -459,6 +585,12 @@ pub enum EventKind {
     TrackStuck,
     /// Dispatched when an audio WebSocket (to Discord) is closed.
     WebSocketClosed,
+    /// Dispatched by the SponsorBlock plugin when the segments of a track are loaded.
+    SegmentsLoaded,
+    /// Dispatched by the SponsorBlock plugin when a segment is skipped.
+    SegmentSkipped,
+    /// An event not known by Hydrolink.
+    Unknown,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -521,6 +653,60 @@ pub struct WebSocketClosedEvent {
     pub by_remote: bool,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// Dispatched by the SponsorBlock plugin when the segments of a track are loaded.
+pub struct SegmentsLoadedEvent {
+    /// The guild id.
+    pub guild_id: String,
+    /// The segments loaded.
+    pub segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// Dispatched by the SponsorBlock plugin when a segment is skipped.
+pub struct SegmentSkippedEvent {
+    /// The guild id.
+    pub guild_id: String,
+    /// The segment skipped.
+    pub segment: Segment,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A segment from the SponsorBlock plugin.
+pub struct Segment {
+    /// The category of the segment.
+    pub category: SponsorBlockCategory,
+    /// The start of the segment in milliseconds.
+    pub start: u64,
+    /// The end of the segment in milliseconds.
+    pub end: u64,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+/// The categories of segments skipped by the SponsorBlock plugin.
+pub enum SponsorBlockCategory {
+    /// Paid promotion, paid referrals and direct advertisements.
+    Sponsor,
+    /// Unpaid or self promotion.
+    Selfpromo,
+    /// Reminders to like, subscribe or follow in the middle of the content.
+    Interaction,
+    /// Intermission or intro animation.
+    Intro,
+    /// Endcards or credits.
+    Outro,
+    /// Collection of clips that show what is coming up.
+    Preview,
+    /// Non-music section in music videos.
+    MusicOfftopic,
+    /// Tangential scenes added only for filler or humor.
+    Filler,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 /// Track object.
@@ -639,6 +825,15 @@ pub struct Error {
     pub path: String,
 }
 
+impl Error {
+    /// Check if the error was caused by an encoded track that couldn't be decoded when updating a player.
+    pub fn is_track_decode_error(&self) -> bool {
+        matches!(self.status, 400 | 500)
+            && self.path.contains("/players/")
+            && self.message.to_lowercase().contains("decode")
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 /// A serializable enum containing the expected response or an error from the REST API.
@@ -818,6 +1013,138 @@ pub struct LoadResultPlaylist {
     pub tracks: Vec<Track>,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+/// The kind of results requested from the LavaSearch plugin.
+pub enum SearchType {
+    /// Tracks matching the query.
+    Track,
+    /// Albums matching the query.
+    Album,
+    /// Artists matching the query.
+    Artist,
+    /// Playlists matching the query.
+    Playlist,
+    /// Text suggestions for the query.
+    Text,
+}
+
+impl SearchType {
+    /// Get the name of the search type, as used by the `types` query parameter.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SearchType::Track => "track",
+            SearchType::Album => "album",
+            SearchType::Artist => "artist",
+            SearchType::Playlist => "playlist",
+            SearchType::Text => "text",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// Represents the result of a LavaSearch plugin search.
+pub struct SearchResult {
+    #[serde(default)]
+    /// The tracks found.
+    pub tracks: Vec<Track>,
+
+    #[serde(default)]
+    /// The albums found.
+    pub albums: Vec<LoadResultPlaylist>,
+
+    #[serde(default)]
+    /// The artists found.
+    pub artists: Vec<LoadResultPlaylist>,
+
+    #[serde(default)]
+    /// The playlists found.
+    pub playlists: Vec<LoadResultPlaylist>,
+
+    #[serde(default)]
+    /// The text suggestions found.
+    pub texts: Vec<SearchText>,
+
+    #[serde(default, rename = "plugin")]
+    /// Additional search info provided by plugins.
+    pub plugin_info: HashMap<String, Value>,
+}
+
+impl SearchResult {
+    /// Check if there are no results.
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+            && self.albums.is_empty()
+            && self.artists.is_empty()
+            && self.playlists.is_empty()
+            && self.texts.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A text suggestion from the LavaSearch plugin.
+pub struct SearchText {
+    /// The suggested text.
+    pub text: String,
+
+    #[serde(default, rename = "plugin")]
+    /// Additional text info provided by plugins.
+    pub plugin_info: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// Lyrics of a track, provided by the LavaLyrics plugin.
+pub struct Lyrics {
+    /// The name of the source where the lyrics were found.
+    pub source_name: String,
+
+    /// The provider of the lyrics.
+    pub provider: String,
+
+    /// The plain lyrics, if available.
+    pub text: Option<String>,
+
+    #[serde(default)]
+    /// The synced lyrics lines, empty if not available.
+    pub lines: Vec<LyricLine>,
+
+    #[serde(default, rename = "plugin")]
+    /// Additional lyrics info provided by plugins.
+    pub plugin_info: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A line of synced lyrics.
+pub struct LyricLine {
+    /// The timestamp of the line in milliseconds.
+    pub timestamp: u64,
+
+    /// The duration of the line in milliseconds.
+    pub duration: Option<u64>,
+
+    /// The text of the line.
+    pub line: String,
+
+    #[serde(default, rename = "plugin")]
+    /// Additional line info provided by plugins.
+    pub plugin_info: HashMap<String, Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+/// A lyrics search result from the LavaLyrics plugin.
+pub struct LyricsSearchResult {
+    /// The ID of the video with the lyrics.
+    pub video_id: String,
+
+    /// The title of the video.
+    pub title: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 /// A player in the Lavalink node.
@@ -910,7 +1237,63 @@ pub struct Filters {
     pub plugin_filters: Option<HashMap<String, Value>>,
 }
 
-impl Filters {}
+impl Filters {
+    /// Create filters with an equalizer boosting the low bands (0 to 4) by the gain, leaving the others flat.
+    pub fn bass_boost(gain: f32) -> Self {
+        Self::with_equalizer(|band| if band <= 4 { gain } else { 0.0 })
+    }
+
+    /// Create filters with an equalizer where all the bands have no gain.
+    pub fn flat() -> Self {
+        Self::with_equalizer(|_| 0.0)
+    }
+
+    /// Check if no filter is set, including the plugin filters.
+    pub fn is_empty(&self) -> bool {
+        self.volume.is_none()
+            && self.equalizer.is_none()
+            && self.karaoke.is_none()
+            && self.timescale.is_none()
+            && self.tremolo.is_none()
+            && self.vibrato.is_none()
+            && self.rotation.is_none()
+            && self.distortion.is_none()
+            && self.channel_mix.is_none()
+            && self.low_pass.is_none()
+            && self.plugin_filters.is_none()
+    }
+
+    /// Get the names used by Lavalink for the filters that are set, excluding the plugin filters.
+    pub fn names(&self) -> Vec<&'static str> {
+        [
+            ("volume", self.volume.is_some()),
+            ("equalizer", self.equalizer.is_some()),
+            ("karaoke", self.karaoke.is_some()),
+            ("timescale", self.timescale.is_some()),
+            ("tremolo", self.tremolo.is_some()),
+            ("vibrato", self.vibrato.is_some()),
+            ("rotation", self.rotation.is_some()),
+            ("distortion", self.distortion.is_some()),
+            ("channelMix", self.channel_mix.is_some()),
+            ("lowPass", self.low_pass.is_some()),
+        ]
+        .into_iter()
+        .filter_map(|(name, set)| set.then_some(name))
+        .collect()
+    }
+
+    /// Create filters with an equalizer containing all the bands, clamping the gains into the valid range.
+    fn with_equalizer(gain: impl Fn(u8) -> f32) -> Self {
+        Self {
+            equalizer: Some(
+                Equalizer::band_range()
+                    .map(|band| Equalizer::new(band, Equalizer::clamp_gain(gain(band))))
+                    .collect(),
+            ),
+            ..Default::default()
+        }
+    }
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -927,6 +1310,23 @@ impl Equalizer {
     pub fn new(band: u8, gain: f32) -> Self {
         Self { band, gain }
     }
+
+    /// Create a new equalizer, returning `None` if the band is out of range.
+    pub fn try_new(band: u8, gain: f32) -> Option<Self> {
+        Self::band_range()
+            .contains(&band)
+            .then(|| Self::new(band, gain))
+    }
+
+    /// The range of the valid bands.
+    pub fn band_range() -> RangeInclusive<u8> {
+        0..=14
+    }
+
+    /// Clamp the gain into the valid range. (-0.25 to 1.0)
+    fn clamp_gain(gain: f32) -> f32 {
+        gain.clamp(-0.25, 1.0)
+    }
 }
 
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
@@ -1821,3 +2221,193 @@ pub struct UnmarkRoutePlanner {
     /// The address to unmark.
     pub address: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    /// Serialize the value, deserialize it back, and check that it serializes to the same JSON.
+    fn round_trip<T: Serialize + for<'de> Deserialize<'de>>(value: &T) -> Value {
+        let serialized = serde_json::to_value(value).unwrap();
+        let deserialized = serde_json::from_value::<T>(serialized.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&deserialized).unwrap(), serialized);
+
+        serialized
+    }
+
+    #[test]
+    fn update_player_round_trip() {
+        let update_player = UpdatePlayer::default()
+            .set_track(UpdatePlayerTrack::default().set_encoded("encoded"))
+            .set_position(1000)
+            .set_end_time(5000)
+            .set_volume(100)
+            .set_paused(false)
+            .set_voice(VoiceState::new("token", "endpoint", "session"));
+
+        assert_eq!(
+            round_trip(&update_player),
+            json!({
+                "track": { "encoded": "encoded" },
+                "position": 1000,
+                "endTime": 5000,
+                "volume": 100,
+                "paused": false,
+                "voice": {
+                    "token": "token",
+                    "endpoint": "endpoint",
+                    "sessionId": "session"
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn update_player_nulls() {
+        let update_player = UpdatePlayer::default()
+            .set_track(UpdatePlayerTrack::default().set_stop_player())
+            .reset_end_time();
+
+        assert_eq!(
+            serde_json::to_value(&update_player).unwrap(),
+            json!({ "track": { "encoded": null }, "endTime": null })
+        );
+
+        assert_eq!(
+            serde_json::to_value(UpdatePlayer::default()).unwrap(),
+            json!({})
+        );
+    }
+
+    #[test]
+    fn filters_round_trip() {
+        let filters = Filters {
+            volume: Some(0.5),
+            equalizer: Some(vec![Equalizer::new(0, 0.25)]),
+            timescale: Some(Timescale::new(1.5, 1.0, 1.0)),
+            channel_mix: Some(ChannelMix::new(1.0, 0.0, 0.0, 1.0)),
+            low_pass: Some(LowPass::new(20.0)),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            round_trip(&filters),
+            json!({
+                "volume": 0.5,
+                "equalizer": [{ "band": 0, "gain": 0.25 }],
+                "timescale": { "speed": 1.5, "pitch": 1.0, "rate": 1.0 },
+                "channelMix": {
+                    "leftToLeft": 1.0,
+                    "leftToRight": 0.0,
+                    "rightToLeft": 0.0,
+                    "rightToRight": 1.0
+                },
+                "lowPass": { "smoothing": 20.0 }
+            })
+        );
+
+        assert_eq!(round_trip(&Filters::default()), json!({}));
+    }
+
+    /// Create the JSON of a track.
+    fn track_json() -> Value {
+        json!({
+            "encoded": "encoded",
+            "info": {
+                "identifier": "identifier",
+                "isSeekable": true,
+                "author": "author",
+                "length": 1000,
+                "isStream": false,
+                "position": 0,
+                "title": "title",
+                "uri": "https://example.com",
+                "artworkUrl": null,
+                "isrc": null,
+                "sourceName": "http"
+            },
+            "pluginInfo": {},
+            "userData": {}
+        })
+    }
+
+    #[test]
+    fn load_result_round_trip() {
+        let cases = [
+            (
+                json!({ "loadType": "track", "data": track_json() }),
+                LoadResultKind::Track,
+            ),
+            (
+                json!({
+                    "loadType": "playlist",
+                    "data": {
+                        "info": { "name": "playlist", "selectedTrack": -1 },
+                        "pluginInfo": {},
+                        "tracks": [track_json()]
+                    }
+                }),
+                LoadResultKind::Playlist,
+            ),
+            (
+                json!({ "loadType": "search", "data": [track_json()] }),
+                LoadResultKind::Search,
+            ),
+            (json!({ "loadType": "empty" }), LoadResultKind::Empty),
+            (
+                json!({
+                    "loadType": "error",
+                    "data": { "message": "message", "severity": "common", "cause": "cause" }
+                }),
+                LoadResultKind::Error,
+            ),
+        ];
+
+        for (value, kind) in cases {
+            let load_result = serde_json::from_value::<LoadResult>(value.clone()).unwrap();
+
+            assert_eq!(load_result.kind(), kind);
+            assert_eq!(round_trip(&load_result), value);
+        }
+    }
+
+    /// Create an error from the REST API.
+    fn rest_error(status: u32, message: &str, path: &str) -> Error {
+        Error {
+            timestamp: 0,
+            status,
+            error: String::new(),
+            trace: None,
+            message: message.to_owned(),
+            path: path.to_owned(),
+        }
+    }
+
+    #[test]
+    fn track_decode_error() {
+        let path = "/v4/sessions/session/players/1";
+
+        let cases = [
+            (400, "Failed to decode track", path, true),
+            (500, "Could not decode the track", path, true),
+            (400, "Invalid volume", path, false),
+            (500, "Internal Server Error", path, false),
+            (404, "Failed to decode track", path, false),
+            (400, "Failed to decode track", "/v4/decodetrack", false),
+        ];
+
+        for (status, message, path, expected) in cases {
+            assert_eq!(
+                rest_error(status, message, path).is_track_decode_error(),
+                expected,
+                "{} {} {}",
+                status,
+                message,
+                path
+            );
+        }
+    }
+}
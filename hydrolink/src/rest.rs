@@ -4,12 +4,152 @@ use std::time::Duration;
 
 use super::{ApiResponse, Error, Result, model::*};
 use bytes::Bytes;
-use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri};
+use futures::{StreamExt, stream};
+use http::{HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri, uri::Authority};
 use reqwest::Client;
 use serde::Serialize;
 use serde::de::DeserializeOwned;
+use tokio::time::sleep;
 use url::Url;
 
+/// How many requests can be made at the same time when updating multiple players.
+pub const MAX_CONCURRENT_UPDATES: usize = 8;
+
+/// How many requests can be made at the same time when loading multiple tracks.
+pub const MAX_CONCURRENT_LOADS: usize = 8;
+
+/// The delay before the first retry of a request, doubled for each retry.
+pub const RETRY_BASE_DELAY: Duration = Duration::from_millis(250);
+
+/// Get how long to wait before retrying a request, or [None] if it shouldn't be retried.
+///
+/// The status is the one received from the server, or [None] if the request failed before getting a response.
+fn retry_delay(status: Option<StatusCode>, attempt: u8, retries: u8) -> Option<Duration> {
+    if attempt >= retries || status.is_some_and(|s| !s.is_server_error()) {
+        return None;
+    }
+
+    Some(RETRY_BASE_DELAY.saturating_mul(2u32.saturating_pow(u32::from(attempt))))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Configuration of the HTTP client used by [Rest].
+pub struct RestConfig {
+    /// The timeout for connecting to the server, or [None] to wait forever.
+    pub connect_timeout: Option<Duration>,
+    /// The timeout for each read from the server, or [None] to wait forever.
+    pub read_timeout: Option<Duration>,
+    /// How long idle connections are kept in the pool, or [None] to keep them forever.
+    pub pool_idle_timeout: Option<Duration>,
+    /// The user agent sent in the requests.
+    pub user_agent: String,
+}
+
+impl RestConfig {
+    /// Create a new configuration with the user agent and the default timeouts.
+    pub fn new(user_agent: &str) -> Self {
+        Self {
+            user_agent: user_agent.to_owned(),
+            ..Default::default()
+        }
+    }
+}
+
+impl Default for RestConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout: None,
+            read_timeout: Some(Duration::from_secs(60)),
+            pool_idle_timeout: Some(Duration::from_secs(90)),
+            user_agent: concat!("hydrolink/", env!("CARGO_PKG_VERSION")).to_owned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// Builder for [Rest], normalizing the address of the Lavalink server.
+pub struct RestBuilder {
+    /// The address of the server, as `host:port` or as an URL.
+    address: String,
+    /// The password for the Lavalink REST API.
+    password: String,
+    /// If TLS is used when the address doesn't have a scheme.
+    tls: bool,
+    /// The configuration of the HTTP client.
+    config: RestConfig,
+    /// If HTTP/2 is preferred.
+    http2: bool,
+}
+
+impl RestBuilder {
+    /// Create a new builder, the address can be `host:port` or an URL like `https://host:port`.
+    pub fn new(address: &str, password: &str) -> Self {
+        Self {
+            address: address.to_owned(),
+            password: password.to_owned(),
+            tls: false,
+            config: RestConfig::default(),
+            http2: false,
+        }
+    }
+
+    /// Set if TLS is used, ignored when the address has a scheme, as it's inferred from it.
+    pub fn tls(mut self, tls: bool) -> Self {
+        self.tls = tls;
+        self
+    }
+
+    /// Set the configuration of the HTTP client.
+    pub fn config(mut self, config: RestConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Set the user agent sent in the requests.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.config.user_agent = user_agent.to_owned();
+        self
+    }
+
+    #[cfg(feature = "http2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "http2")))]
+    /// Set if HTTP/2 is preferred, see [Rest::with_http2].
+    pub fn http2(mut self, http2: bool) -> Self {
+        self.http2 = http2;
+        self
+    }
+
+    /// Normalize the address, returning the host with the port and if TLS is used.
+    fn normalize(&self) -> Result<(String, bool)> {
+        let address = self.address.trim();
+
+        let (host, tls) = match address.split_once("://") {
+            Some((scheme, rest)) => match scheme.to_ascii_lowercase().as_str() {
+                "http" | "ws" => (rest, false),
+                "https" | "wss" => (rest, true),
+                _ => return Err(Error::InvalidAddress(self.address.clone())),
+            },
+            None => (address, self.tls),
+        };
+
+        // Only the root path is accepted, as the paths of the Lavalink API are absolute.
+        let host = host.strip_suffix('/').unwrap_or(host);
+
+        if host.is_empty() || host.parse::<Authority>().is_err() || host.contains('@') {
+            return Err(Error::InvalidAddress(self.address.clone()));
+        }
+
+        Ok((host.to_owned(), tls))
+    }
+
+    /// Build the REST client.
+    pub fn build(self) -> Result<Rest> {
+        let (host, tls) = self.normalize()?;
+
+        Rest::build(&host, &self.password, tls, self.config, self.http2)
+    }
+}
+
 #[derive(Debug, Clone)]
 /// REST client for Lavalink.
 pub struct Rest {
@@ -23,11 +163,50 @@ pub struct Rest {
     websocket_uri: Uri,
     /// Enables stack traces in all Lavalink REST API requests.
     pub trace: bool,
+    /// The capacity of the node compared to the other nodes, used to balance the players between them.
+    pub capacity: f32,
+    /// How many times safe requests, like GET, are retried on connection errors and server errors.
+    pub retries: u8,
 }
 
 impl Rest {
-    /// Create a new REST client.
+    /// Create a new REST client, using the default [RestConfig] with the user agent.
+    ///
+    /// The host is normalized by [RestBuilder], so it can be an URL too.
     pub fn new(host: &str, password: &str, user_agent: &str, tls: bool) -> Result<Self> {
+        Self::with_config(host, password, tls, RestConfig::new(user_agent))
+    }
+
+    /// Create a new REST client with the HTTP client configuration.
+    pub fn with_config(host: &str, password: &str, tls: bool, config: RestConfig) -> Result<Self> {
+        RestBuilder::new(host, password)
+            .tls(tls)
+            .config(config)
+            .build()
+    }
+
+    #[cfg(feature = "http2")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "http2")))]
+    /// Create a new REST client preferring HTTP/2.
+    ///
+    /// With TLS, HTTP/2 is negotiated with the server, falling back to HTTP/1.1 if not supported, without TLS, HTTP/2 is
+    /// used directly, requiring the server to support it.
+    pub fn with_http2(host: &str, password: &str, user_agent: &str, tls: bool) -> Result<Self> {
+        RestBuilder::new(host, password)
+            .tls(tls)
+            .user_agent(user_agent)
+            .http2(true)
+            .build()
+    }
+
+    /// Build the REST client.
+    fn build(
+        host: &str,
+        password: &str,
+        tls: bool,
+        config: RestConfig,
+        http2: bool,
+    ) -> Result<Self> {
         let headers = [
             (
                 HeaderName::from_static("authorization"),
@@ -39,12 +218,30 @@ impl Rest {
             ),
         ];
 
-        let client = Client::builder()
-            .user_agent(user_agent)
+        let mut client_builder = Client::builder()
+            .user_agent(config.user_agent)
             .default_headers(HeaderMap::from_iter(headers))
-            .read_timeout(Duration::from_secs(60))
-            .build()
-            .map_err(Error::from)?;
+            .pool_idle_timeout(config.pool_idle_timeout);
+
+        if let Some(connect_timeout) = config.connect_timeout {
+            client_builder = client_builder.connect_timeout(connect_timeout);
+        }
+
+        if let Some(read_timeout) = config.read_timeout {
+            client_builder = client_builder.read_timeout(read_timeout);
+        }
+
+        #[cfg(feature = "http2")]
+        let client_builder = if http2 && !tls {
+            client_builder.http2_prior_knowledge()
+        } else {
+            client_builder
+        };
+
+        #[cfg(not(feature = "http2"))]
+        let _ = http2;
+
+        let client = client_builder.build().map_err(Error::from)?;
 
         let http_url = Url::parse(&format!(
             "{}://{}",
@@ -66,6 +263,8 @@ impl Rest {
             http_url,
             websocket_uri,
             trace: false,
+            capacity: 1.0,
+            retries: 2,
         })
     }
 
@@ -128,14 +327,26 @@ impl Rest {
         &self,
         response: reqwest::Response,
     ) -> Result<Option<T>> {
-        if response.status() == StatusCode::NO_CONTENT || response.status() == StatusCode::NOT_FOUND
-        {
-            Ok(None)
-        } else {
-            Into::<Result<T>>::into(self.deserialize_response::<ApiResponse<T>>(
-                response.bytes().await.map_err(Error::from)?,
-            )?)
-            .map(Some)
+        let status = response.status();
+
+        if status == StatusCode::NO_CONTENT || status == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+
+        let body = response.bytes().await.map_err(Error::from)?;
+
+        if !status.is_client_error() && !status.is_server_error() {
+            return Into::<Result<T>>::into(self.deserialize_response::<ApiResponse<T>>(body)?)
+                .map(Some);
+        }
+
+        // Only error responses keep a copy of the body, they may come from a proxy instead of Lavalink.
+        match self.deserialize_response::<ApiResponse<T>>(body.clone()) {
+            Ok(response) => Into::<Result<T>>::into(response).map(Some),
+            Err(_) => Err(Error::UnexpectedStatus {
+                status,
+                body: String::from_utf8_lossy(&body).into_owned(),
+            }),
         }
     }
 
@@ -181,6 +392,43 @@ impl Rest {
             .map_err(Error::from)
     }
 
+    /// Send a request without a request body, retrying it with exponential backoff on connection errors and server
+    /// errors.
+    ///
+    /// Only safe methods, like GET, are retried, the last response or error is returned when all attempts fail.
+    pub async fn call_with_retry<Q: Serialize + ?Sized>(
+        &self,
+        method: Method,
+        url: Url,
+        query: &Q,
+    ) -> Result<reqwest::Response> {
+        let retries = if method.is_safe() { self.retries } else { 0 };
+        let mut attempt = 0;
+
+        loop {
+            let result = self
+                .client
+                .request(method.clone(), url.clone())
+                .query(query)
+                .send()
+                .await;
+
+            let status = match &result {
+                Ok(response) => Some(response.status()),
+                Err(e) if e.is_connect() || e.is_timeout() || e.is_request() => None,
+                Err(_) => return result.map_err(Error::from),
+            };
+
+            let Some(delay) = retry_delay(status, attempt, retries) else {
+                return result.map_err(Error::from);
+            };
+
+            sleep(delay).await;
+
+            attempt += 1;
+        }
+    }
+
     /// Call the Lavalink REST API without a request body, but with a response body.
     pub async fn call_res<Q: Serialize + ?Sized, O: DeserializeOwned>(
         &self,
@@ -188,13 +436,7 @@ impl Rest {
         url: Url,
         query: &Q,
     ) -> Result<Option<O>> {
-        let response = self
-            .client
-            .request(method, url)
-            .query(query)
-            .send()
-            .await
-            .map_err(Error::from)?;
+        let response = self.call_with_retry(method, url, query).await?;
 
         self.parse_response(response).await
     }
@@ -208,12 +450,8 @@ impl Rest {
         url: Url,
         query: &Q,
     ) -> Result<()> {
-        self.client
-            .request(method, url)
-            .query(query)
-            .send()
-            .await
-            .map_err(Error::from)?
+        self.call_with_retry(method, url, query)
+            .await?
             .error_for_status()
             .map(|_| ())
             .map_err(Error::from)
@@ -234,6 +472,43 @@ impl Rest {
         .unwrap_or(Err(Error::NoResponseBody))
     }
 
+    /// Search using the LavaSearch plugin, returning only the types of results requested.
+    ///
+    /// An empty result is returned when there are no matches.
+    pub async fn load_search(&self, query: &str, types: &[SearchType]) -> Result<SearchResult> {
+        let types = types
+            .iter()
+            .map(SearchType::as_str)
+            .collect::<Vec<_>>()
+            .join(",");
+
+        self.call_res(
+            Method::GET,
+            self.build_url("/v4/loadsearch")?,
+            &[
+                ("query", query),
+                ("types", &types),
+                ("trace", &self.trace.to_string()),
+            ],
+        )
+        .await
+        .map(Option::unwrap_or_default)
+    }
+
+    /// Load multiple tracks concurrently, returning the results in the same order as the identifiers.
+    ///
+    /// At most [MAX_CONCURRENT_LOADS] requests are made at the same time, the first error is returned if any request
+    /// fails.
+    pub async fn load_tracks(&self, identifiers: &[&str]) -> Result<Vec<LoadResult>> {
+        stream::iter(identifiers)
+            .map(|identifier| self.load_track(identifier))
+            .buffered(MAX_CONCURRENT_LOADS)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect()
+    }
+
     /// Decode a base64 track.
     pub async fn decode_track(&self, encoded_track: &str) -> Result<Track> {
         self.call_res(
@@ -284,6 +559,41 @@ impl Rest {
         .await
     }
 
+    /// Get the lyrics of the track playing in the player, using the LavaLyrics plugin.
+    ///
+    /// The plugin serves it at `/v4/sessions/{sessionId}/players/{guildId}/track/lyrics`, [None] is returned when no
+    /// lyrics were found.
+    pub async fn get_lyrics(
+        &self,
+        session_id: &str,
+        guild_id: &str,
+        skip_track_source: bool,
+    ) -> Result<Option<Lyrics>> {
+        self.call_res(
+            Method::GET,
+            self.build_url(&format!(
+                "/v4/sessions/{}/players/{}/track/lyrics",
+                session_id, guild_id
+            ))?,
+            &[
+                ("skipTrackSource", &skip_track_source.to_string()),
+                ("trace", &self.trace.to_string()),
+            ],
+        )
+        .await
+    }
+
+    /// Search for lyrics using the LavaLyrics plugin.
+    pub async fn search_lyrics(&self, query: &str) -> Result<Vec<LyricsSearchResult>> {
+        self.call_res(
+            Method::GET,
+            self.build_url("/v4/lyrics/search")?,
+            &[("query", query), ("trace", &self.trace.to_string())],
+        )
+        .await
+        .map(Option::unwrap_or_default)
+    }
+
     /// Update the player in the session.
     pub async fn update_player(
         &self,
@@ -306,6 +616,34 @@ impl Rest {
         .unwrap_or(Err(Error::NoResponseBody))
     }
 
+    /// Update multiple players in the session concurrently, returning the result for each guild.
+    ///
+    /// Lavalink doesn't have an endpoint to update multiple players, so at most [`MAX_CONCURRENT_UPDATES`] requests
+    /// are made at the same time, avoiding to overwhelm the server.
+    pub async fn update_players(
+        &self,
+        session_id: &str,
+        updates: &[(String, UpdatePlayer)],
+        no_replace: bool,
+    ) -> Vec<(String, Result<Player>)> {
+        // The requests are created before the stream, so the future can be sent between threads.
+        let requests = updates
+            .iter()
+            .map(|(guild_id, player)| async move {
+                (
+                    guild_id.clone(),
+                    self.update_player(session_id, guild_id, player, no_replace)
+                        .await,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        stream::iter(requests)
+            .buffer_unordered(MAX_CONCURRENT_UPDATES)
+            .collect()
+            .await
+    }
+
     /// Destroy the player in the session.
     pub async fn destroy_player(&self, session_id: &str, guild_id: &str) -> Result<()> {
         self.call(
@@ -316,6 +654,60 @@ impl Rest {
         .await
     }
 
+    /// Get the categories of segments skipped by the SponsorBlock plugin in the player.
+    pub async fn get_sponsorblock_categories(
+        &self,
+        session_id: &str,
+        guild_id: &str,
+    ) -> Result<Vec<SponsorBlockCategory>> {
+        self.call_res(
+            Method::GET,
+            self.build_url(&format!(
+                "/v4/sessions/{}/players/{}/sponsorblock/categories",
+                session_id, guild_id
+            ))?,
+            &[("trace", &self.trace.to_string())],
+        )
+        .await
+        .map(Option::unwrap_or_default)
+    }
+
+    /// Set the categories of segments skipped by the SponsorBlock plugin in the player.
+    pub async fn set_sponsorblock_categories(
+        &self,
+        session_id: &str,
+        guild_id: &str,
+        categories: &[SponsorBlockCategory],
+    ) -> Result<()> {
+        self.call_req(
+            Method::PUT,
+            self.build_url(&format!(
+                "/v4/sessions/{}/players/{}/sponsorblock/categories",
+                session_id, guild_id
+            ))?,
+            &[("trace", &self.trace.to_string())],
+            categories,
+        )
+        .await
+    }
+
+    /// Remove the categories of segments skipped by the SponsorBlock plugin in the player, disabling it.
+    pub async fn delete_sponsorblock_categories(
+        &self,
+        session_id: &str,
+        guild_id: &str,
+    ) -> Result<()> {
+        self.call(
+            Method::DELETE,
+            self.build_url(&format!(
+                "/v4/sessions/{}/players/{}/sponsorblock/categories",
+                session_id, guild_id
+            ))?,
+            &[("trace", &self.trace.to_string())],
+        )
+        .await
+    }
+
     /// Update the session.
     pub async fn update_session(
         &self,
@@ -333,6 +725,16 @@ impl Rest {
         .unwrap_or(Err(Error::NoResponseBody))
     }
 
+    /// Enable resuming for the session, keeping the players for `timeout` seconds after the connection is lost.
+    pub async fn enable_resuming(
+        &self,
+        session_id: &str,
+        timeout: u32,
+    ) -> Result<UpdateSessionResponse> {
+        self.update_session(session_id, &UpdateSessionRequest::new(true, timeout))
+            .await
+    }
+
     /// Get information about the Lavalink server.
     pub async fn info(&self) -> Result<Info> {
         self.call_res(
@@ -357,6 +759,11 @@ impl Rest {
             .map_err(Error::from)
     }
 
+    /// Establish the pooled connection to the Lavalink server, requesting its version.
+    pub async fn warmup(&self) -> Result<()> {
+        self.version().await.map(|_| ())
+    }
+
     /// Get the status of the Route Planner.
     pub async fn routeplanner_status(&self) -> Result<Option<RoutePlanner>> {
         self.call_res(
@@ -390,3 +797,222 @@ impl Rest {
         .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+
+    #[test]
+    fn retry_service_unavailable() {
+        let status = Some(StatusCode::SERVICE_UNAVAILABLE);
+
+        assert_eq!(retry_delay(status, 0, 2), Some(RETRY_BASE_DELAY));
+        assert_eq!(retry_delay(status, 1, 2), Some(RETRY_BASE_DELAY * 2));
+        assert_eq!(retry_delay(status, 1, 3), Some(RETRY_BASE_DELAY * 2));
+        assert_eq!(retry_delay(status, 2, 3), Some(RETRY_BASE_DELAY * 4));
+        assert_eq!(retry_delay(status, 2, 2), None);
+        assert_eq!(retry_delay(status, 0, 0), None);
+    }
+
+    #[test]
+    fn retry_only_server_errors() {
+        assert_eq!(retry_delay(None, 0, 2), Some(RETRY_BASE_DELAY));
+        assert_eq!(retry_delay(Some(StatusCode::OK), 0, 2), None);
+        assert_eq!(retry_delay(Some(StatusCode::NOT_FOUND), 0, 2), None);
+        assert_eq!(
+            retry_delay(Some(StatusCode::INTERNAL_SERVER_ERROR), 0, 2),
+            Some(RETRY_BASE_DELAY)
+        );
+    }
+
+    #[test]
+    fn builder_normalizes_address() {
+        let cases = [
+            (
+                "host:2333",
+                false,
+                "http://host:2333/",
+                "ws://host:2333/v4/websocket",
+            ),
+            (
+                "host:2333",
+                true,
+                "https://host:2333/",
+                "wss://host:2333/v4/websocket",
+            ),
+            (
+                "https://host",
+                false,
+                "https://host/",
+                "wss://host/v4/websocket",
+            ),
+            (
+                "http://host:2333",
+                true,
+                "http://host:2333/",
+                "ws://host:2333/v4/websocket",
+            ),
+            (
+                "wss://host:2333/",
+                false,
+                "https://host:2333/",
+                "wss://host:2333/v4/websocket",
+            ),
+            ("host/", false, "http://host/", "ws://host/v4/websocket"),
+            (
+                " host:2333 ",
+                false,
+                "http://host:2333/",
+                "ws://host:2333/v4/websocket",
+            ),
+        ];
+
+        for (address, tls, http_url, websocket_uri) in cases {
+            let rest = RestBuilder::new(address, "password")
+                .tls(tls)
+                .build()
+                .unwrap();
+
+            assert_eq!(rest.http_url().as_str(), http_url, "{}", address);
+            assert_eq!(
+                rest.websocket_uri().to_string(),
+                websocket_uri,
+                "{}",
+                address
+            );
+        }
+    }
+
+    #[test]
+    fn builder_rejects_invalid_address() {
+        for address in ["ftp://host", "", "https://", "host/path", "user@host:2333"] {
+            assert!(
+                matches!(
+                    RestBuilder::new(address, "password").build(),
+                    Err(Error::InvalidAddress(_))
+                ),
+                "{}",
+                address
+            );
+        }
+    }
+
+    /// Start a server answering every request with a player for the guild in the path, returning its address, how many
+    /// requests it received and the most requests it handled at the same time.
+    async fn player_server() -> (String, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap().to_string();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let max_active = Arc::new(AtomicUsize::new(0));
+        let active = Arc::new(AtomicUsize::new(0));
+
+        let server_requests = requests.clone();
+        let server_max_active = max_active.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let requests = server_requests.clone();
+                let max_active = server_max_active.clone();
+                let active = active.clone();
+
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut buffer = [0; 1024];
+
+                    while !request.windows(4).any(|v| v == b"\r\n\r\n") {
+                        let read = socket.read(&mut buffer).await.unwrap();
+
+                        if read == 0 {
+                            return;
+                        }
+
+                        request.extend_from_slice(&buffer[..read]);
+                    }
+
+                    requests.fetch_add(1, Ordering::SeqCst);
+                    let now_active = active.fetch_add(1, Ordering::SeqCst) + 1;
+                    max_active.fetch_max(now_active, Ordering::SeqCst);
+
+                    // Keeps the request open, so the concurrent requests overlap.
+                    sleep(Duration::from_millis(50)).await;
+
+                    let request = String::from_utf8_lossy(&request);
+                    let guild_id = request
+                        .split(['/', '?', ' '])
+                        .skip_while(|v| *v != "players")
+                        .nth(1)
+                        .unwrap_or_default()
+                        .to_owned();
+
+                    let body = serde_json::json!({
+                        "guildId": guild_id,
+                        "track": null,
+                        "volume": 100,
+                        "paused": true,
+                        "state": { "time": 0, "position": 0, "connected": true, "ping": 0 },
+                        "voice": { "token": "", "endpoint": "", "sessionId": "" },
+                        "filters": {}
+                    })
+                    .to_string();
+
+                    active.fetch_sub(1, Ordering::SeqCst);
+
+                    socket
+                        .write_all(
+                            format!(
+                                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                                body.len(),
+                                body
+                            )
+                            .as_bytes(),
+                        )
+                        .await
+                        .unwrap();
+                });
+            }
+        });
+
+        (address, requests, max_active)
+    }
+
+    #[tokio::test]
+    async fn update_players_is_bounded() {
+        let (address, requests, max_active) = player_server().await;
+        let rest = RestBuilder::new(&address, "password").build().unwrap();
+
+        let updates = (1..=20)
+            .map(|guild_id| {
+                (
+                    guild_id.to_string(),
+                    UpdatePlayer::default().set_paused(true),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let mut results = rest.update_players("session", &updates, false).await;
+        results.sort_by_key(|(guild_id, _)| guild_id.parse::<u64>().unwrap());
+
+        assert_eq!(requests.load(Ordering::SeqCst), updates.len());
+        assert!((2..=MAX_CONCURRENT_UPDATES).contains(&max_active.load(Ordering::SeqCst)));
+        assert_eq!(results.len(), updates.len());
+
+        for ((guild_id, result), (expected, _)) in results.iter().zip(&updates) {
+            assert_eq!(guild_id, expected);
+            assert_eq!(&result.as_ref().unwrap().guild_id, expected);
+        }
+
+        assert!(rest.update_players("session", &[], false).await.is_empty());
+        assert_eq!(requests.load(Ordering::SeqCst), updates.len());
+    }
+}
@@ -1,45 +1,113 @@
-use tokio_tungstenite::{
-    connect_async,
-    tungstenite::{ClientRequestBuilder, Message as WsMessage, Result as WsResult},
+use std::cmp::Ordering;
+#[cfg(feature = "websocket-rustls")]
+use std::sync::Arc;
+
+use bytes::Bytes;
+
+#[cfg(feature = "websocket-rustls")]
+use rustls::{ClientConfig, RootCertStore};
+#[cfg(not(feature = "websocket-rustls"))]
+use tokio_tungstenite::connect_async;
+use tokio_tungstenite::tungstenite::{
+    ClientRequestBuilder, Message as WsMessage, Result as WsResult,
 };
+#[cfg(feature = "websocket-rustls")]
+use tokio_tungstenite::{Connector, connect_async_tls_with_config};
 
 use super::{Error, LAVALINK_CLIENT_NAME, LavalinkConnection, Message, Rest, Result};
 
-/// Connect to a Lavalink server.
-pub async fn connect(rest: &Rest, user_id: &str) -> Result<LavalinkConnection> {
-    let request = ClientRequestBuilder::new(rest.websocket_uri().clone())
-        .with_header("Authorization", rest.password())
-        .with_header("User-Id", user_id)
-        .with_header("Client-Name", LAVALINK_CLIENT_NAME);
-
-    let (connection, _) = connect_async(request).await.map_err(Error::from)?;
-
-    Ok(connection)
-}
-
-/// Reconnect to a Lavalink server, resuming a previous session.
-pub async fn resume_session(
+/// Connect to a Lavalink server, resuming a previous session if its ID is provided.
+pub async fn connect(
     rest: &Rest,
     user_id: &str,
-    session_id: &str,
+    resume_session_id: Option<&str>,
 ) -> Result<LavalinkConnection> {
-    let request = ClientRequestBuilder::new(rest.websocket_uri().clone())
+    let mut request = ClientRequestBuilder::new(rest.websocket_uri().clone())
         .with_header("Authorization", rest.password())
         .with_header("User-Id", user_id)
-        .with_header("Client-Name", LAVALINK_CLIENT_NAME)
-        .with_header("Session-Id", session_id);
+        .with_header("Client-Name", LAVALINK_CLIENT_NAME);
+
+    if let Some(session_id) = resume_session_id {
+        request = request.with_header("Session-Id", session_id);
+    }
 
+    // tokio-tungstenite prefers native-tls when both backends are compiled, so rustls is selected explicitly.
+    #[cfg(feature = "websocket-rustls")]
+    let (connection, _) =
+        connect_async_tls_with_config(request, None, false, Some(rustls_connector()))
+            .await
+            .map_err(Error::from)?;
+
+    #[cfg(not(feature = "websocket-rustls"))]
     let (connection, _) = connect_async(request).await.map_err(Error::from)?;
 
     Ok(connection)
 }
 
+#[cfg(feature = "websocket-rustls")]
+/// Create the `rustls` connector with the webpki roots, used by the WebSocket connections.
+fn rustls_connector() -> Connector {
+    let mut root_store = RootCertStore::empty();
+    root_store.extend(webpki_roots::TLS_SERVER_ROOTS.iter().cloned());
+
+    Connector::Rustls(Arc::new(
+        ClientConfig::builder()
+            .with_root_certificates(root_store)
+            .with_no_client_auth(),
+    ))
+}
+
 /// Parse a message from the WebSocket connection.
 pub fn parse_message(message: WsResult<WsMessage>) -> Result<Message> {
     let msg = message.map_err(Error::from)?;
     serde_json::from_slice(&msg.into_data()).map_err(Error::from)
 }
 
+#[cfg(feature = "simd-json")]
+/// Deserialize a message from the Lavalink server using the selected JSON library.
+pub fn deserialize_message(data: Bytes) -> Result<Message> {
+    let mut data_mutable = data.try_into_mut().unwrap_or_else(bytes::BytesMut::from);
+    simd_json::from_slice(data_mutable.as_mut()).map_err(|_| Error::InvalidMessage)
+}
+
+#[cfg(not(feature = "simd-json"))]
+/// Deserialize a message from the Lavalink server using the selected JSON library.
+pub fn deserialize_message(data: Bytes) -> Result<Message> {
+    serde_json::from_slice(data.as_ref()).map_err(|_| Error::InvalidMessage)
+}
+
+/// Compare two versions, like `1.2.0` and `1.10`, ignoring pre-release and build metadata.
+///
+/// Missing or non-numeric components are considered as `0`.
+pub fn compare_versions(a: &str, b: &str) -> Ordering {
+    let parse = |version: &str| -> Vec<u64> {
+        version
+            .split(['-', '+'])
+            .next()
+            .unwrap_or_default()
+            .split('.')
+            .map(|v| v.parse().unwrap_or(0))
+            .collect()
+    };
+
+    let a = parse(a);
+    let b = parse(b);
+
+    for i in 0..a.len().max(b.len()) {
+        let ordering = a
+            .get(i)
+            .copied()
+            .unwrap_or(0)
+            .cmp(&b.get(i).copied().unwrap_or(0));
+
+        if ordering != Ordering::Equal {
+            return ordering;
+        }
+    }
+
+    Ordering::Equal
+}
+
 #[cfg(feature = "parking-lot")]
 /// A wrapper around `RwLock` to provide a consistent API.
 #[derive(Debug)]
@@ -85,3 +153,47 @@ impl<T> RwLock<T> {
         self.0.write().unwrap()
     }
 }
+
+#[cfg(all(test, any(feature = "native-tls", feature = "websocket-rustls")))]
+mod tests {
+    use tokio::{io::AsyncReadExt, net::TcpListener};
+
+    use super::*;
+    use crate::RestBuilder;
+
+    #[tokio::test]
+    async fn connect_starts_tls_with_the_host_as_sni() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        // Reads the first TLS record sent by the client and closes the connection.
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut header = [0; 5];
+            socket.read_exact(&mut header).await.unwrap();
+
+            let mut record = vec![0; u16::from_be_bytes([header[3], header[4]]) as usize];
+            socket.read_exact(&mut record).await.unwrap();
+
+            (header, record)
+        });
+
+        let rest = RestBuilder::new(&format!("wss://localhost:{}", port), "password")
+            .build()
+            .unwrap();
+
+        assert!(connect(&rest, "0", None).await.is_err());
+
+        let (header, record) = server.await.unwrap();
+
+        // A handshake record starting with a ClientHello.
+        assert_eq!(header[0], 0x16);
+        assert_eq!(record[0], 0x01);
+        assert!(
+            record
+                .windows(b"localhost".len())
+                .any(|v| v == b"localhost")
+        );
+    }
+}
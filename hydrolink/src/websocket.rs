@@ -1,7 +1,15 @@
-use std::{borrow::Borrow, ops::Deref};
+use std::{
+    borrow::Borrow,
+    ops::Deref,
+    pin::Pin,
+    sync::atomic::{AtomicBool, AtomicU32, Ordering},
+    task::{Context, Poll},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
-use futures::StreamExt;
-use tokio::sync::Mutex as AsyncMutex;
+use futures::{Stream, StreamExt};
+use tokio::{sync::Mutex as AsyncMutex, time::sleep};
+use tokio_tungstenite::tungstenite::Message as WsMessage;
 
 #[cfg(feature = "parking-lot")]
 use parking_lot::RwLock;
@@ -12,9 +20,53 @@ use std::sync::RwLock;
 use super::{
     Error, LavalinkConnection, Rest, Result,
     model::*,
-    utils::{connect, parse_message, resume_session},
+    utils::{RwLock as PolicyLock, connect, deserialize_message, parse_message},
 };
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How the connection to a Lavalink server is re-established when it's lost.
+pub struct ReconnectPolicy {
+    /// The delay before the first attempt, doubled for each failed attempt.
+    pub base_delay: Duration,
+    /// The maximum delay between the attempts.
+    pub max_delay: Duration,
+    /// The maximum number of attempts, or [None] to retry forever.
+    pub max_attempts: Option<u32>,
+}
+
+impl ReconnectPolicy {
+    /// Create a new reconnection policy.
+    pub fn new(base_delay: Duration, max_delay: Duration, max_attempts: Option<u32>) -> Self {
+        Self {
+            base_delay,
+            max_delay,
+            max_attempts,
+        }
+    }
+
+    /// Get the delay before an attempt, starting from `1`, with up to 25% of jitter.
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let delay = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+
+        // The jitter only needs to spread the nodes reconnecting at the same time, not to be random.
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|v| v.subsec_nanos())
+            .unwrap_or_default();
+
+        delay + delay.mul_f64(f64::from(nanos % 1000) / 4000.0)
+    }
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(1), Duration::from_secs(60), None)
+    }
+}
+
 #[derive(Debug)]
 /// A connection to a Lavalink server.
 pub struct Lavalink {
@@ -26,6 +78,14 @@ pub struct Lavalink {
     client: Rest,
     /// Bot's user ID.
     user_id: String,
+    /// The policy used to reconnect when the connection is lost, or [None] if it's disabled.
+    reconnect_policy: PolicyLock<Option<ReconnectPolicy>>,
+    /// The number of the failed reconnection attempts, `0` if the connection isn't lost.
+    attempt: AtomicU32,
+    /// If the connection was closed by [Lavalink::close], preventing the reconnection.
+    closed: AtomicBool,
+    /// If the session was resumed, from the last ready message.
+    resumed: AtomicBool,
 }
 
 impl Lavalink {
@@ -36,18 +96,36 @@ impl Lavalink {
             connection: AsyncMutex::new(connection),
             client,
             user_id: user_id.to_owned(),
+            reconnect_policy: PolicyLock::new(Some(ReconnectPolicy::default())),
+            attempt: AtomicU32::new(0),
+            closed: AtomicBool::new(false),
+            resumed: AtomicBool::new(false),
         }
     }
 
+    /// Set the policy used to reconnect when the connection is lost, or [None] to disable the reconnection.
+    pub fn set_reconnect_policy(&self, policy: Option<ReconnectPolicy>) {
+        *self.reconnect_policy.write() = policy;
+    }
+
+    /// Get the policy used to reconnect when the connection is lost.
+    pub fn reconnect_policy(&self) -> Option<ReconnectPolicy> {
+        *self.reconnect_policy.read()
+    }
+
     /// Connect to a Lavalink server.
     pub async fn connect_from(rest: Rest, user_id: &str) -> Result<Self> {
-        Ok(Self::new(connect(&rest, user_id).await?, rest, user_id))
+        Ok(Self::new(
+            connect(&rest, user_id, None).await?,
+            rest,
+            user_id,
+        ))
     }
 
     /// Reconnect to a Lavalink server, resuming a previous session.
     pub async fn resume_from(rest: Rest, user_id: &str, session_id: &str) -> Result<Self> {
         Ok(Self::new(
-            resume_session(&rest, user_id, session_id).await?,
+            connect(&rest, user_id, Some(session_id)).await?,
             rest,
             user_id,
         ))
@@ -57,7 +135,9 @@ impl Lavalink {
     ///
     /// WARNING: This method locks the internal connection mutex.
     pub async fn connect(&self) -> Result<()> {
-        *self.connection.lock().await = connect(self, &self.user_id).await?;
+        *self.connection.lock().await = connect(self, &self.user_id, None).await?;
+        self.attempt.store(0, Ordering::Release);
+        self.closed.store(false, Ordering::Release);
 
         Ok(())
     }
@@ -66,12 +146,14 @@ impl Lavalink {
     ///
     /// WARNING: This method locks the internal connection mutex.
     pub async fn resume(&self) -> Result<()> {
-        *self.connection.lock().await = resume_session(
+        *self.connection.lock().await = connect(
             self,
             &self.user_id,
-            self.session_id().as_ref().ok_or(Error::NoSessionId)?,
+            Some(&self.session_id().ok_or(Error::NoSessionId)?),
         )
         .await?;
+        self.attempt.store(0, Ordering::Release);
+        self.closed.store(false, Ordering::Release);
 
         Ok(())
     }
@@ -97,6 +179,20 @@ impl Lavalink {
         self.session_id.read().unwrap().clone()
     }
 
+    /// Check if the session was resumed when the connection was last established.
+    ///
+    /// If it wasn't, the players from the previous session are lost and have to be created again.
+    pub fn resumed(&self) -> bool {
+        self.resumed.load(Ordering::Acquire)
+    }
+
+    /// Enable resuming for the session, keeping the players for `timeout` seconds after the connection is lost.
+    pub async fn enable_resuming(&self, timeout: u32) -> Result<UpdateSessionResponse> {
+        self.client
+            .enable_resuming(&self.session_id().ok_or(Error::NoSessionId)?, timeout)
+            .await
+    }
+
     /// Get the REST client.
     pub fn client(&self) -> &Rest {
         &self.client
@@ -150,38 +246,86 @@ impl Lavalink {
             .await
     }
 
-    #[cfg(feature = "parking-lot")]
     /// Receive the next message from the Lavalink server.
     ///
+    /// When the connection is lost, it's re-established following the [ReconnectPolicy], resuming the session if there's
+    /// one. Each failed attempt is returned as [Error::ReconnectFailed], and [None] is returned when the policy gives up.
+    ///
     /// WARNING: This method locks the internal connection mutex.
     pub async fn next(&self) -> Option<Result<Message>> {
-        let data = parse_message(self.connection.lock().await.next().await?);
+        let mut connection = self.connection.lock().await;
 
-        if let Some(msg) = data.as_ref().ok().and_then(|v| v.as_ready()) {
-            *self.session_id.write() = Some(msg.session_id.clone());
-        }
+        loop {
+            if self.closed.load(Ordering::Acquire) {
+                return None;
+            }
+
+            if self.attempt.load(Ordering::Acquire) > 0 {
+                if let Err(e) = self.reconnect(&mut connection).await? {
+                    return Some(Err(e));
+                }
+            }
+
+            let Some(message) = connection.next().await else {
+                self.attempt.store(1, Ordering::Release);
+                continue;
+            };
 
-        Some(data)
+            let data = parse_message(message);
+
+            if let Some(msg) = data.as_ref().ok().and_then(|v| v.as_ready()) {
+                self.set_session_id(msg.session_id.clone());
+                self.resumed.store(msg.resumed, Ordering::Release);
+            }
+
+            return Some(data);
+        }
     }
 
-    #[cfg(not(feature = "parking-lot"))]
-    /// Receive the next message from the Lavalink server.
-    ///
-    /// WARNING: This method locks the internal connection mutex.
-    pub async fn next(&self) -> Option<Result<Message>> {
-        let data = parse_message(self.connection.lock().await.next().await?);
+    /// Try to re-establish the lost connection, returning [None] if the policy doesn't allow more attempts.
+    async fn reconnect(&self, connection: &mut LavalinkConnection) -> Option<Result<()>> {
+        let policy = self.reconnect_policy()?;
+        let attempt = self.attempt.load(Ordering::Acquire);
 
-        if let Some(msg) = data.as_ref().ok().and_then(|v| v.as_ready()) {
-            *self.session_id.write().unwrap() = Some(msg.session_id.clone());
+        if policy.max_attempts.is_some_and(|max| attempt > max) {
+            return None;
         }
 
-        Some(data)
+        sleep(policy.delay(attempt)).await;
+
+        let result = connect(self, &self.user_id, self.session_id().as_deref()).await;
+
+        Some(match result {
+            Ok(new_connection) => {
+                *connection = new_connection;
+                self.attempt.store(0, Ordering::Release);
+                Ok(())
+            }
+            Err(e) => {
+                self.attempt.store(attempt + 1, Ordering::Release);
+                Err(Error::ReconnectFailed(attempt, Box::new(e)))
+            }
+        })
+    }
+
+    #[cfg(feature = "parking-lot")]
+    /// Set the session ID.
+    fn set_session_id(&self, session_id: String) {
+        *self.session_id.write() = Some(session_id);
+    }
+
+    #[cfg(not(feature = "parking-lot"))]
+    /// Set the session ID.
+    fn set_session_id(&self, session_id: String) {
+        *self.session_id.write().unwrap() = Some(session_id);
     }
 
     /// Close the connection to the Lavalink server.
     ///
     /// WARNING: This method locks the internal connection mutex.
     pub async fn close(&self) -> Result<()> {
+        self.closed.store(true, Ordering::Release);
+
         self.connection
             .lock()
             .await
@@ -191,6 +335,62 @@ impl Lavalink {
     }
 }
 
+#[derive(Debug)]
+/// A stream of the messages received from a Lavalink server, skipping the ping and pong frames.
+///
+/// Frames that can't be parsed are returned as [Error::InvalidMessage], and the stream ends when the connection is
+/// closed.
+pub struct MessageStream {
+    /// WebSocket stream.
+    connection: LavalinkConnection,
+}
+
+impl MessageStream {
+    /// Create a new message stream from a connection.
+    pub fn new(connection: LavalinkConnection) -> Self {
+        Self { connection }
+    }
+
+    /// Connect to a Lavalink server, returning the stream of its messages.
+    pub async fn connect(rest: &Rest, user_id: &str) -> Result<Self> {
+        Ok(Self::new(connect(rest, user_id, None).await?))
+    }
+
+    /// Get the underlying connection.
+    pub fn into_inner(self) -> LavalinkConnection {
+        self.connection
+    }
+}
+
+impl From<LavalinkConnection> for MessageStream {
+    fn from(connection: LavalinkConnection) -> Self {
+        Self::new(connection)
+    }
+}
+
+impl Stream for MessageStream {
+    type Item = Result<Message>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        loop {
+            let message = match self.connection.poll_next_unpin(cx) {
+                Poll::Ready(Some(Ok(message))) => message,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(Error::from(e)))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            return Poll::Ready(match message {
+                WsMessage::Text(_) | WsMessage::Binary(_) => {
+                    Some(deserialize_message(message.into_data()))
+                }
+                WsMessage::Close(_) => None,
+                WsMessage::Ping(_) | WsMessage::Pong(_) | WsMessage::Frame(_) => continue,
+            });
+        }
+    }
+}
+
 impl AsRef<Rest> for Lavalink {
     fn as_ref(&self) -> &Rest {
         &self.client